@@ -0,0 +1,140 @@
+use crate::{FusedTryNext, TryNext, TryPeek};
+
+/// A source with up to `N` items of lookahead, buffered inline.
+///
+/// Unlike [`Peekable`](crate::adapters::Peekable), which holds a single
+/// item, this adapter can look `N` items ahead through
+/// [`try_peek_nth`](Self::try_peek_nth). The lookahead lives in a
+/// fixed-size ring inside the adapter, so peeking never allocates.
+///
+/// Created by [`TryNextExt::array_peekable`](crate::TryNextExt::array_peekable).
+#[derive(Debug, Clone)]
+pub struct ArrayPeekable<S: TryNext, const N: usize> {
+    source: S,
+    ring: [Option<S::Item>; N],
+    head: usize,
+    len: usize,
+    ended: bool,
+}
+
+impl<S: TryNext, const N: usize> ArrayPeekable<S, N> {
+    pub(crate) fn new(source: S) -> Self {
+        const { assert!(N > 0, "lookahead must be at least one item") };
+        Self {
+            source,
+            ring: std::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+            ended: false,
+        }
+    }
+
+    /// Returns a reference to the item `n` positions ahead without consuming
+    /// anything; `try_peek_nth(0)` is the next item.
+    ///
+    /// Returns `Ok(None)` if the source ends before that item. If pulling
+    /// fails, the error is returned and the items pulled so far stay
+    /// buffered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n >= N`.
+    pub fn try_peek_nth(&mut self, n: usize) -> Result<Option<&S::Item>, S::Error> {
+        assert!(n < N, "cannot peek {n} items ahead with a lookahead of {N}");
+        while self.len <= n && !self.ended {
+            match self.source.try_next()? {
+                Some(item) => {
+                    self.ring[(self.head + self.len) % N] = Some(item);
+                    self.len += 1;
+                }
+                None => self.ended = true,
+            }
+        }
+        if n < self.len {
+            Ok(self.ring[(self.head + n) % N].as_ref())
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the underlying source, dropping any buffered items.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: TryNext, const N: usize> TryNext for ArrayPeekable<S, N> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.len > 0 {
+            let item = self.ring[self.head].take();
+            self.head = (self.head + 1) % N;
+            self.len -= 1;
+            return Ok(item);
+        }
+        if self.ended {
+            self.ended = false;
+            return Ok(None);
+        }
+        self.source.try_next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.ended {
+            return (self.len, Some(self.len));
+        }
+        let (lower, upper) = self.source.size_hint();
+        (
+            lower.saturating_add(self.len),
+            upper.and_then(|upper| upper.checked_add(self.len)),
+        )
+    }
+}
+
+impl<S: TryNext, const N: usize> TryPeek for ArrayPeekable<S, N> {
+    fn try_peek(&mut self) -> Result<Option<&Self::Item>, Self::Error> {
+        self.try_peek_nth(0)
+    }
+}
+
+impl<S: FusedTryNext, const N: usize> FusedTryNext for ArrayPeekable<S, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, TryPeek, from_items, from_iter};
+
+    #[test]
+    fn peeks_several_items_ahead() {
+        let mut src = from_items(1..=5).array_peekable::<3>();
+
+        assert_eq!(src.try_peek_nth(2), Ok(Some(&3)));
+        assert_eq!(src.try_peek(), Ok(Some(&1)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_peek_nth(2), Ok(Some(&4)));
+        assert_eq!(src.size_hint(), (4, Some(4)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+        assert_eq!(src.try_peek_nth(2), Ok(None));
+        assert_eq!(src.try_next(), Ok(Some(4)));
+        assert_eq!(src.try_next(), Ok(Some(5)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn keeps_buffered_items_across_errors() {
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2)]).array_peekable::<2>();
+
+        assert_eq!(src.try_peek_nth(1), Err('x'));
+        assert_eq!(src.try_peek_nth(1), Ok(Some(&2)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot peek 2 items ahead")]
+    fn rejects_peeking_past_capacity() {
+        let _ = from_items([1]).array_peekable::<2>().try_peek_nth(2);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{SnapshotContext, TryNextWithContext};
+
+/// A source that rolls the context back whenever the inner source fails.
+///
+/// Before each pull the context is snapshotted; if the pull returns an
+/// error, the context is restored before the error is passed on, so the
+/// failed attempt leaves no trace in it. Only the context is rolled back:
+/// any state held by the source itself is left as the failed pull left it.
+///
+/// The snapshot is taken before every pull, successful or not, so its cost
+/// is paid once per item. The [`SnapshotContext`] implementations for `Vec`
+/// and `String` clone the whole context: if the context grows with the
+/// input, a pass over `n` items costs O(n²). For such contexts, wrap the
+/// collection in a type whose snapshot records only what a rollback needs,
+/// such as a length for an append-only log.
+///
+/// Created by [`TryNextWithContextExt::backtrack`](crate::TryNextWithContextExt::backtrack).
+#[derive(Debug, Clone)]
+pub struct Backtrack<S> {
+    source: S,
+}
+
+impl<S> Backtrack<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S> TryNextWithContext for Backtrack<S>
+where
+    S: TryNextWithContext<Context: SnapshotContext>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let snapshot = context.snapshot();
+        self.source
+            .try_next_with_context(context)
+            .inspect_err(|_| context.restore(snapshot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNextWithContext, TryNextWithContextExt};
+
+    /// Declares each name in the context; a name already declared is an
+    /// error, reported after the declaration was (wrongly) recorded.
+    struct Declare(std::vec::IntoIter<&'static str>);
+
+    impl TryNextWithContext for Declare {
+        type Item = &'static str;
+        type Error = &'static str;
+        type Context = Vec<&'static str>;
+
+        fn try_next_with_context(
+            &mut self,
+            scope: &mut Vec<&'static str>,
+        ) -> Result<Option<Self::Item>, Self::Error> {
+            let Some(name) = self.0.next() else {
+                return Ok(None);
+            };
+            let duplicate = scope.contains(&name);
+            scope.push(name);
+            if duplicate { Err(name) } else { Ok(Some(name)) }
+        }
+    }
+
+    #[test]
+    fn failed_pull_leaves_context_untouched() {
+        let mut src = Declare(vec!["a", "a", "b"].into_iter()).backtrack();
+        let mut scope = Vec::new();
+
+        assert_eq!(src.try_next_with_context(&mut scope), Ok(Some("a")));
+        assert_eq!(src.try_next_with_context(&mut scope), Err("a"));
+        assert_eq!(scope, ["a"]);
+        assert_eq!(src.try_next_with_context(&mut scope), Ok(Some("b")));
+        assert_eq!(scope, ["a", "b"]);
+    }
+}
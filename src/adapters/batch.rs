@@ -0,0 +1,125 @@
+use std::mem;
+use std::time::{Duration, Instant};
+
+use super::Prefetch;
+use crate::{FusedTryNext, TryNext};
+
+/// A source that groups items into batches released by size or by time.
+///
+/// The inner source runs on a background thread, as with
+/// [`prefetch`](crate::TryNextExt::prefetch). A batch is released as soon as
+/// it holds `max_items` items, or once `max_wait` has passed since its first
+/// item arrived, whichever comes first, so a slowly trickling source still
+/// delivers its items promptly. Batches are never empty: the adapter waits
+/// as long as it takes for a batch's first item.
+///
+/// Errors are returned as soon as they arrive; the items gathered so far
+/// stay in the current batch. At the end of input, any partial batch is
+/// released before `Ok(None)`.
+///
+/// Created by [`TryNextExt::batch`](crate::TryNextExt::batch).
+#[derive(Debug)]
+pub struct Batch<T, E> {
+    source: Prefetch<T, E>,
+    max_items: usize,
+    max_wait: Duration,
+    batch: Vec<T>,
+    deadline: Option<Instant>,
+    done: bool,
+}
+
+impl<T, E> Batch<T, E> {
+    pub(crate) fn new<S>(source: S, max_items: usize, max_wait: Duration) -> Self
+    where
+        S: TryNext<Item = T, Error = E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        assert!(max_items > 0, "batch size must be non-zero");
+        Self {
+            source: Prefetch::new(source, max_items),
+            max_items,
+            max_wait,
+            batch: Vec::new(),
+            deadline: None,
+            done: false,
+        }
+    }
+
+    fn release(&mut self) -> Vec<T> {
+        self.deadline = None;
+        mem::take(&mut self.batch)
+    }
+}
+
+impl<T, E> TryNext for Batch<T, E> {
+    type Item = Vec<T>;
+    type Error = E;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        while !self.done {
+            let next = match self.deadline {
+                None => Some(self.source.try_next()),
+                Some(deadline) => self.source.try_next_until(deadline),
+            };
+            match next {
+                None => return Ok(Some(self.release())),
+                Some(Err(err)) => return Err(err),
+                Some(Ok(None)) => self.done = true,
+                Some(Ok(Some(item))) => {
+                    if self.batch.is_empty() {
+                        self.deadline = Some(Instant::now() + self.max_wait);
+                    }
+                    self.batch.push(item);
+                    if self.batch.len() == self.max_items {
+                        return Ok(Some(self.release()));
+                    }
+                }
+            }
+        }
+        if self.batch.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(self.release()))
+        }
+    }
+}
+
+impl<T, E> FusedTryNext for Batch<T, E> {}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    const LONG: Duration = Duration::from_secs(3600);
+
+    #[test]
+    fn releases_full_batches_and_the_remainder() {
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2), Ok(3), Ok(4), Ok(5)]).batch(2, LONG);
+
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(vec![1, 2])));
+        assert_eq!(src.try_next(), Ok(Some(vec![3, 4])));
+        assert_eq!(src.try_next(), Ok(Some(vec![5])));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn releases_partial_batches_when_the_source_trickles() {
+        let mut src = from_iter((0..3).map(Ok::<_, ()>))
+            .inspect(|&n| {
+                if n == 2 {
+                    thread::sleep(Duration::from_millis(200));
+                }
+            })
+            .batch(10, Duration::from_millis(20));
+
+        assert_eq!(src.try_next(), Ok(Some(vec![0, 1])));
+        assert_eq!(src.try_next(), Ok(Some(vec![2])));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+
+use crate::{TryNext, TryNextWithContext};
+
+/// A [`TryNext`] view of a borrowed [`TryNextWithContext`] and a borrowed
+/// context.
+///
+/// Unlike [`WithContext`](super::WithContext), this adapter does not take
+/// ownership of either the source or the context. It lets a context-aware
+/// source flow through the context-free adapters within a scope; when the
+/// borrow ends, both are available to the caller again.
+///
+/// Created by
+/// [`TryNextWithContextExt::bind_context`](crate::TryNextWithContextExt::bind_context).
+pub struct BindContext<'a, S: TryNextWithContext + ?Sized> {
+    source: &'a mut S,
+    context: &'a mut S::Context,
+}
+
+impl<'a, S: TryNextWithContext + ?Sized> BindContext<'a, S> {
+    pub(crate) fn new(source: &'a mut S, context: &'a mut S::Context) -> Self {
+        Self { source, context }
+    }
+
+    /// Returns a mutable reference to the bound context.
+    pub fn context_mut(&mut self) -> &mut S::Context {
+        self.context
+    }
+}
+
+impl<S> fmt::Debug for BindContext<'_, S>
+where
+    S: TryNextWithContext + fmt::Debug + ?Sized,
+    S::Context: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BindContext")
+            .field("source", &self.source)
+            .field("context", &self.context)
+            .finish()
+    }
+}
+
+impl<S: TryNextWithContext + ?Sized> TryNext for BindContext<'_, S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next_with_context(self.context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::fixtures::Pop;
+    use crate::{TryNext, TryNextExt, TryNextWithContext, TryNextWithContextExt};
+
+    #[test]
+    fn binding_is_scoped() {
+        let mut src = Pop;
+        let mut stack = vec![1, 2, 3];
+
+        {
+            let mut bound = src.bind_context(&mut stack).peekable();
+            assert_eq!(bound.try_next(), Ok(Some(3)));
+            assert_eq!(bound.try_next(), Ok(Some(2)));
+        }
+
+        // Both the source and the context are usable again.
+        stack.push(9);
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(Some(9)));
+        assert_eq!(stack, [1]);
+    }
+}
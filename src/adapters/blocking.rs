@@ -0,0 +1,176 @@
+use std::hint;
+use std::thread;
+use std::time::Duration;
+
+use crate::{Readiness, TryNext, TryNextReady};
+
+/// How a [`Blocking`] adapter waits after its source reports
+/// [`Readiness::Pending`].
+///
+/// `wait` is called with the number of pending polls in a row so far,
+/// starting at zero, and returns once the source should be polled again.
+/// The count resets whenever the source is ready. [`Wait`] covers the usual
+/// strategies, and any `FnMut(u32)` closure can be used as a custom one.
+pub trait WaitStrategy {
+    /// Waits before poll number `attempt + 1` of a pending run.
+    fn wait(&mut self, attempt: u32);
+}
+
+impl<F: FnMut(u32)> WaitStrategy for F {
+    fn wait(&mut self, attempt: u32) {
+        self(attempt)
+    }
+}
+
+/// The built-in [`WaitStrategy`] choices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wait {
+    /// Polls again immediately, with a spin-loop hint. Lowest latency, but
+    /// keeps a core busy.
+    Spin,
+    /// Yields the thread to the scheduler before polling again.
+    Yield,
+    /// Parks the thread for `initial`, doubling the time after each pending
+    /// poll up to `max`. An [`unpark`](std::thread::Thread::unpark) from a
+    /// producer cuts the wait short.
+    Park {
+        /// The first wait.
+        initial: Duration,
+        /// The longest wait.
+        max: Duration,
+    },
+}
+
+impl WaitStrategy for Wait {
+    fn wait(&mut self, attempt: u32) {
+        match *self {
+            Self::Spin => hint::spin_loop(),
+            Self::Yield => thread::yield_now(),
+            Self::Park { initial, max } => {
+                let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+                thread::park_timeout(initial.saturating_mul(factor).min(max));
+            }
+        }
+    }
+}
+
+/// A [`TryNext`] that waits out a [`TryNextReady`] source's pending polls.
+///
+/// Each call polls the source until it is ready, calling the wait strategy
+/// between polls, so a source written for an event loop can also feed a
+/// thread-per-pipeline consumer.
+///
+/// ```rust
+/// use std::sync::mpsc;
+/// use std::thread;
+/// use std::time::Duration;
+/// use try_next::adapters::Wait;
+/// use try_next::sources::ChannelSource;
+/// use try_next::{TryNext, TryNextReady};
+///
+/// let (tx, rx) = mpsc::channel();
+/// let mut src = ChannelSource::new(rx).blocking(Wait::Park {
+///     initial: Duration::from_micros(50),
+///     max: Duration::from_millis(5),
+/// });
+///
+/// thread::spawn(move || tx.send(42).unwrap());
+/// assert_eq!(src.try_next(), Ok(Some(42)));
+/// assert_eq!(src.try_next(), Ok(None));
+/// ```
+///
+/// Created by [`TryNextReady::blocking`].
+#[derive(Debug, Clone)]
+pub struct Blocking<S, W> {
+    source: S,
+    wait: W,
+}
+
+impl<S, W> Blocking<S, W> {
+    pub(crate) fn new(source: S, wait: W) -> Self {
+        Self { source, wait }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: TryNextReady, W: WaitStrategy> TryNext for Blocking<S, W> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let mut attempt = 0;
+        loop {
+            match self.source.try_next_ready()? {
+                Readiness::Ready(item) => return Ok(item),
+                Readiness::Pending => {
+                    self.wait.wait(attempt);
+                    attempt = attempt.saturating_add(1);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Wait;
+    use crate::{Readiness, TryNext, TryNextExt, TryNextReady};
+
+    /// Is pending `n` times before each item.
+    struct Slow {
+        items: Vec<u32>,
+        pending: u32,
+        left: u32,
+    }
+
+    impl TryNextReady for Slow {
+        type Item = u32;
+        type Error = char;
+
+        fn try_next_ready(&mut self) -> Result<Readiness<u32>, char> {
+            if self.left > 0 {
+                self.left -= 1;
+                return Ok(Readiness::Pending);
+            }
+            self.left = self.pending;
+            Ok(Readiness::Ready(self.items.pop()))
+        }
+    }
+
+    fn slow(pending: u32) -> Slow {
+        Slow {
+            items: vec![2, 1],
+            pending,
+            left: pending,
+        }
+    }
+
+    #[test]
+    fn callback_sees_the_attempt_count_reset_per_item() {
+        let mut attempts = Vec::new();
+        let mut src = slow(3).blocking(|attempt| attempts.push(attempt));
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        drop(src);
+        assert_eq!(attempts, [0, 1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn built_in_strategies_wait_until_ready() {
+        let park = Wait::Park {
+            initial: Duration::from_micros(10),
+            max: Duration::from_micros(100),
+        };
+        for wait in [Wait::Spin, Wait::Yield, park] {
+            let mut src = slow(5).blocking(wait);
+            assert_eq!(src.try_collect::<Vec<_>>(), Ok(vec![1, 2]));
+        }
+    }
+}
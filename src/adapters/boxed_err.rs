@@ -0,0 +1,112 @@
+use std::num::NonZeroUsize;
+
+use crate::{
+    BoxError, DoubleEndedTryNext, ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext,
+};
+
+/// A source that converts its errors into a [`BoxError`].
+///
+/// Created by [`TryNextExt::boxed_err`](crate::TryNextExt::boxed_err) and
+/// [`TryNextWithContextExt::boxed_err`](crate::TryNextWithContextExt::boxed_err).
+#[derive(Debug, Clone)]
+pub struct BoxedErr<S> {
+    source: S,
+}
+
+impl<S> BoxedErr<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S> TryNext for BoxedErr<S>
+where
+    S: TryNext<Error: Into<BoxError>>,
+{
+    type Item = S::Item;
+    type Error = BoxError;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next().map_err(Into::into)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        self.source.try_next_chunk(max).map_err(Into::into)
+    }
+
+    fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        self.source.try_advance_by(n).map_err(Into::into)
+    }
+}
+
+impl<S> DoubleEndedTryNext for BoxedErr<S>
+where
+    S: DoubleEndedTryNext<Error: Into<BoxError>>,
+{
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next_back().map_err(Into::into)
+    }
+}
+
+impl<S> ExactSizeTryNext for BoxedErr<S> where S: ExactSizeTryNext<Error: Into<BoxError>> {}
+
+impl<S> FusedTryNext for BoxedErr<S> where S: FusedTryNext<Error: Into<BoxError>> {}
+
+impl<S> TryNextWithContext for BoxedErr<S>
+where
+    S: TryNextWithContext<Error: Into<BoxError>>,
+{
+    type Item = S::Item;
+    type Error = BoxError;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.source
+            .try_next_with_context(context)
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxError, BoxTryNext, TryNextExt, from_iter};
+    use std::fmt;
+    use std::io;
+
+    #[derive(Debug)]
+    struct ParseError;
+
+    impl fmt::Display for ParseError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("parse error")
+        }
+    }
+
+    impl std::error::Error for ParseError {}
+
+    #[test]
+    fn stores_sources_with_different_errors_together() {
+        let mut sources: Vec<BoxTryNext<'_, u8, BoxError>> = vec![
+            from_iter([Err(io::Error::other("offline"))])
+                .boxed_err()
+                .boxed(),
+            from_iter([Ok(1), Err(ParseError)]).boxed_err().boxed(),
+        ];
+
+        assert_eq!(sources[0].try_next().unwrap_err().to_string(), "offline");
+        assert_eq!(sources[1].try_next().unwrap(), Some(1));
+        assert!(sources[1].try_next().unwrap_err().is::<ParseError>());
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+
+use crate::{FusedTryNext, TryNext};
+
+/// What a [`Broadcast`] does when one handle's buffer is full and another
+/// handle needs the next result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SlowConsumer {
+    /// Wait for the slow handle to catch up before pulling.
+    ///
+    /// Only useful when the handles are consumed on different threads; a
+    /// single thread pulling ahead on one handle would wait forever.
+    #[default]
+    Block,
+    /// Discard the slow handle's oldest buffered result to make room.
+    DropOldest,
+    /// Discard the slow handle's buffered results and report a
+    /// [`BroadcastError::Lagged`] to it in their place.
+    Error,
+}
+
+/// The error type of a [`Broadcast`] handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BroadcastError<E> {
+    /// The inner source failed.
+    Source(E),
+    /// This handle fell behind and `skipped` results were discarded.
+    Lagged {
+        /// The number of results this handle will never see.
+        skipped: usize,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for BroadcastError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(e) => write!(f, "source error: {e}"),
+            Self::Lagged { skipped } => write!(f, "consumer lagged, skipped {skipped} results"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for BroadcastError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Source(e) => Some(e),
+            Self::Lagged { .. } => None,
+        }
+    }
+}
+
+type Queue<T, E> = VecDeque<Result<T, BroadcastError<E>>>;
+
+struct State<T, E> {
+    // `None` once the handle has been dropped.
+    queues: Vec<Option<Queue<T, E>>>,
+    dropped: Vec<usize>,
+    done: bool,
+}
+
+struct Shared<S: TryNext> {
+    source: Mutex<S>,
+    state: Mutex<State<S::Item, S::Error>>,
+    changed: Condvar,
+    capacity: usize,
+    policy: SlowConsumer,
+}
+
+impl<S: TryNext> Shared<S> {
+    fn state(&self) -> MutexGuard<'_, State<S::Item, S::Error>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn must_wait(&self, state: &State<S::Item, S::Error>) -> bool {
+        self.policy == SlowConsumer::Block
+            && state
+                .queues
+                .iter()
+                .flatten()
+                .any(|queue| queue.len() >= self.capacity)
+    }
+}
+
+/// One of several handles sharing a source, each seeing every result.
+///
+/// Whichever handle runs out of buffered results first pulls the next one
+/// from the inner source and appends a clone to every other live handle's
+/// buffer, so the source is polled once per result no matter how many
+/// handles there are. Handles are `Send` when the source and its results
+/// are, so each can be driven from its own thread, or all can be pulled in
+/// turn from one.
+///
+/// Each buffer holds up to `capacity` results; when one is full, the
+/// [`SlowConsumer`] policy decides whether the puller waits, the oldest
+/// result is discarded, or the slow handle gets a
+/// [`BroadcastError::Lagged`]. Dropped handles stop receiving results.
+///
+/// Created by [`TryNextExt::broadcast`](crate::TryNextExt::broadcast) and
+/// [`TryNextExt::broadcast_with`](crate::TryNextExt::broadcast_with).
+pub struct Broadcast<S: TryNext> {
+    shared: Arc<Shared<S>>,
+    index: usize,
+}
+
+impl<S: TryNext> Broadcast<S> {
+    pub(crate) fn split(source: S, n: usize, capacity: usize, policy: SlowConsumer) -> Vec<Self> {
+        assert!(capacity > 0, "broadcast capacity must be non-zero");
+        let shared = Arc::new(Shared {
+            source: Mutex::new(source),
+            state: Mutex::new(State {
+                queues: (0..n).map(|_| Some(VecDeque::new())).collect(),
+                dropped: vec![0; n],
+                done: false,
+            }),
+            changed: Condvar::new(),
+            capacity,
+            policy,
+        });
+        (0..n)
+            .map(|index| Self {
+                shared: Arc::clone(&shared),
+                index,
+            })
+            .collect()
+    }
+
+    /// Returns how many results this handle has lost to
+    /// [`SlowConsumer::DropOldest`] or [`SlowConsumer::Error`].
+    pub fn dropped(&self) -> usize {
+        self.shared.state().dropped[self.index]
+    }
+
+    /// Returns the number of results buffered for this handle.
+    pub fn buffered(&self) -> usize {
+        self.shared.state().queues[self.index]
+            .as_ref()
+            .map_or(0, VecDeque::len)
+    }
+
+    fn pop(
+        &self,
+        state: &mut State<S::Item, S::Error>,
+    ) -> Option<Result<S::Item, BroadcastError<S::Error>>> {
+        let result = state.queues[self.index].as_mut()?.pop_front()?;
+        self.shared.changed.notify_all();
+        Some(result)
+    }
+}
+
+impl<S> TryNext for Broadcast<S>
+where
+    S: TryNext,
+    S::Item: Clone,
+    S::Error: Clone,
+{
+    type Item = S::Item;
+    type Error = BroadcastError<S::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let shared = &*self.shared;
+        loop {
+            let mut state = shared.state();
+            if let Some(result) = self.pop(&mut state) {
+                return result.map(Some);
+            }
+            if state.done {
+                return Ok(None);
+            }
+            if shared.must_wait(&state) {
+                drop(shared.changed.wait(state));
+                continue;
+            }
+            drop(state);
+
+            let mut source = shared.source.lock().unwrap_or_else(PoisonError::into_inner);
+            {
+                // Another handle may have pulled while we waited for the source.
+                let state = shared.state();
+                let ready = state.queues[self.index]
+                    .as_ref()
+                    .is_some_and(|queue| !queue.is_empty());
+                if ready || state.done || shared.must_wait(&state) {
+                    continue;
+                }
+            }
+            let result = source.try_next();
+
+            let mut state = shared.state();
+            let result = match result {
+                Ok(Some(item)) => Ok(item),
+                Ok(None) => {
+                    state.done = true;
+                    shared.changed.notify_all();
+                    return Ok(None);
+                }
+                Err(err) => Err(BroadcastError::Source(err)),
+            };
+            let State {
+                queues, dropped, ..
+            } = &mut *state;
+            for (index, (queue, dropped)) in queues.iter_mut().zip(dropped).enumerate() {
+                let Some(queue) = queue else {
+                    continue;
+                };
+                if index == self.index {
+                    continue;
+                }
+                if queue.len() >= shared.capacity {
+                    match shared.policy {
+                        SlowConsumer::Block => {}
+                        SlowConsumer::DropOldest => {
+                            queue.pop_front();
+                            *dropped += 1;
+                        }
+                        SlowConsumer::Error => {
+                            let skipped = queue.len();
+                            queue.clear();
+                            *dropped += skipped;
+                            queue.push_back(Err(BroadcastError::Lagged { skipped }));
+                        }
+                    }
+                }
+                queue.push_back(result.clone());
+            }
+            shared.changed.notify_all();
+            return result.map(Some);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffered();
+        if self.shared.state().done {
+            return (buffered, Some(buffered));
+        }
+        // Another handle may be holding the source for a pull.
+        match self.shared.source.try_lock() {
+            Ok(source) => {
+                let (lower, upper) = source.size_hint();
+                (
+                    lower.saturating_add(buffered),
+                    upper.and_then(|upper| upper.checked_add(buffered)),
+                )
+            }
+            Err(_) => (buffered, None),
+        }
+    }
+}
+
+impl<S> FusedTryNext for Broadcast<S>
+where
+    S: TryNext,
+    S::Item: Clone,
+    S::Error: Clone,
+{
+}
+
+impl<S: TryNext> Drop for Broadcast<S> {
+    fn drop(&mut self) {
+        self.shared.state().queues[self.index] = None;
+        self.shared.changed.notify_all();
+    }
+}
+
+impl<S: TryNext> fmt::Debug for Broadcast<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Broadcast")
+            .field("index", &self.index)
+            .field("buffered", &self.buffered())
+            .field("capacity", &self.shared.capacity)
+            .field("policy", &self.shared.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::{BroadcastError, SlowConsumer};
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn every_handle_sees_every_result() {
+        let mut handles = from_iter([Ok(1), Err('x'), Ok(2)]).broadcast(2);
+        let mut b = handles.pop().unwrap();
+        let mut a = handles.pop().unwrap();
+
+        assert_eq!(a.try_next(), Ok(Some(1)));
+        assert_eq!(a.try_next(), Err(BroadcastError::Source('x')));
+        assert_eq!(b.try_next(), Ok(Some(1)));
+        assert_eq!(b.try_next(), Err(BroadcastError::Source('x')));
+        assert_eq!(b.try_next(), Ok(Some(2)));
+        assert_eq!(b.try_next(), Ok(None));
+        assert_eq!(a.try_next(), Ok(Some(2)));
+        assert_eq!(a.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn slow_handles_drop_or_lag() {
+        let mut handles = from_items(1..=5).broadcast_with(2, 2, SlowConsumer::DropOldest);
+        let mut slow = handles.pop().unwrap();
+        let mut fast = handles.pop().unwrap();
+        for n in 1..=5 {
+            assert_eq!(fast.try_next(), Ok(Some(n)));
+        }
+        assert_eq!(slow.dropped(), 3);
+        assert_eq!(slow.try_collect::<Vec<_>>(), Ok(vec![4, 5]));
+
+        let mut handles = from_items(1..=4).broadcast_with(2, 2, SlowConsumer::Error);
+        let mut slow = handles.pop().unwrap();
+        let mut fast = handles.pop().unwrap();
+        for n in 1..=3 {
+            assert_eq!(fast.try_next(), Ok(Some(n)));
+        }
+        assert_eq!(slow.try_next(), Err(BroadcastError::Lagged { skipped: 2 }));
+        assert_eq!(slow.try_next(), Ok(Some(3)));
+        assert_eq!(fast.try_next(), Ok(Some(4)));
+        assert_eq!(slow.try_next(), Ok(Some(4)));
+        assert_eq!(slow.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn blocking_handles_on_threads_see_everything() {
+        let handles = from_items(0..1000u32).broadcast_with(3, 4, SlowConsumer::Block);
+        let sums: Vec<u32> = handles
+            .into_iter()
+            .map(|mut handle| {
+                thread::spawn(move || {
+                    let mut sum = 0;
+                    while let Some(n) = handle.try_next().unwrap() {
+                        sum += n;
+                    }
+                    sum
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|worker| worker.join().unwrap())
+            .collect();
+        assert_eq!(sums, [499_500; 3]);
+    }
+
+    #[test]
+    fn dropped_handles_do_not_block_the_rest() {
+        let mut handles = from_items(0..10).broadcast_with(2, 1, SlowConsumer::Block);
+        drop(handles.pop());
+        let mut only = handles.pop().unwrap();
+        assert_eq!(only.try_collect::<Vec<_>>(), Ok((0..10).collect()));
+    }
+}
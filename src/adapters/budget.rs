@@ -0,0 +1,287 @@
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// Resource ceilings for a [`Budget`] adapter.
+///
+/// Every limit is unset by default; set the ones that matter with the
+/// builder methods.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use try_next::adapters::BudgetLimits;
+///
+/// let limits = BudgetLimits::new()
+///     .max_items(10_000)
+///     .max_errors(10)
+///     .max_elapsed(Duration::from_secs(30));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BudgetLimits {
+    items: Option<usize>,
+    errors: Option<usize>,
+    elapsed: Option<Duration>,
+}
+
+impl BudgetLimits {
+    /// Creates limits with nothing bounded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows at most `n` items.
+    pub fn max_items(mut self, n: usize) -> Self {
+        self.items = Some(n);
+        self
+    }
+
+    /// Allows at most `n` errors.
+    pub fn max_errors(mut self, n: usize) -> Self {
+        self.errors = Some(n);
+        self
+    }
+
+    /// Allows pulls to start only within `elapsed` of the first one.
+    pub fn max_elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = Some(elapsed);
+        self
+    }
+}
+
+/// The budget a [`Budget`] adapter ran out of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Exhausted {
+    /// The maximum number of items was yielded.
+    Items,
+    /// The maximum number of errors was returned.
+    Errors,
+    /// The maximum elapsed time passed.
+    Elapsed,
+}
+
+impl fmt::Display for Exhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Items => "item budget exhausted",
+            Self::Errors => "error budget exhausted",
+            Self::Elapsed => "time budget exhausted",
+        })
+    }
+}
+
+/// The error type of a [`Budget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetError<E> {
+    /// The inner source failed.
+    Source(E),
+    /// A budget ran out; the inner source was not polled.
+    Exhausted(Exhausted),
+}
+
+impl<E: fmt::Display> fmt::Display for BudgetError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(e) => write!(f, "source error: {e}"),
+            Self::Exhausted(reason) => reason.fmt(f),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for BudgetError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Source(e) => Some(e),
+            Self::Exhausted(_) => None,
+        }
+    }
+}
+
+/// A source that stops once it has used up any of its [`BudgetLimits`].
+///
+/// Items and errors are passed on (errors as [`BudgetError::Source`]) and
+/// counted. Before each pull, the adapter checks the limits; once one is
+/// reached, it returns [`BudgetError::Exhausted`] naming it, without
+/// polling the inner source, and `Ok(None)` on every call after that. The
+/// clock starts at the first pull, and a pull that has started is not
+/// interrupted.
+///
+/// Created by [`TryNextExt::budget`](crate::TryNextExt::budget) and
+/// [`TryNextWithContextExt::budget`](crate::TryNextWithContextExt::budget).
+#[derive(Debug, Clone)]
+pub struct Budget<S> {
+    source: S,
+    limits: BudgetLimits,
+    items: usize,
+    errors: usize,
+    started: Option<Instant>,
+    exhausted: Option<Exhausted>,
+}
+
+impl<S> Budget<S> {
+    pub(crate) fn new(source: S, limits: BudgetLimits) -> Self {
+        Self {
+            source,
+            limits,
+            items: 0,
+            errors: 0,
+            started: None,
+            exhausted: None,
+        }
+    }
+
+    /// Returns the number of items yielded so far.
+    pub fn items(&self) -> usize {
+        self.items
+    }
+
+    /// Returns the number of errors returned so far.
+    pub fn errors(&self) -> usize {
+        self.errors
+    }
+
+    /// Returns the budget that ran out, if any.
+    pub fn exhausted(&self) -> Option<Exhausted> {
+        self.exhausted
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn check(&mut self) -> Option<Exhausted> {
+        let started = *self.started.get_or_insert_with(Instant::now);
+        if self
+            .limits
+            .elapsed
+            .is_some_and(|limit| started.elapsed() >= limit)
+        {
+            Some(Exhausted::Elapsed)
+        } else if self.limits.items.is_some_and(|limit| self.items >= limit) {
+            Some(Exhausted::Items)
+        } else if self.limits.errors.is_some_and(|limit| self.errors >= limit) {
+            Some(Exhausted::Errors)
+        } else {
+            None
+        }
+    }
+
+    fn pull<T, E>(
+        &mut self,
+        pull: impl FnOnce(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, BudgetError<E>> {
+        if self.exhausted.is_some() {
+            return Ok(None);
+        }
+        if let Some(reason) = self.check() {
+            self.exhausted = Some(reason);
+            return Err(BudgetError::Exhausted(reason));
+        }
+        match pull(&mut self.source) {
+            Ok(Some(item)) => {
+                self.items += 1;
+                Ok(Some(item))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => {
+                self.errors += 1;
+                Err(BudgetError::Source(e))
+            }
+        }
+    }
+}
+
+impl<S: TryNext> TryNext for Budget<S> {
+    type Item = S::Item;
+    type Error = BudgetError<S::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.exhausted.is_some() {
+            return (0, Some(0));
+        }
+        let (_, upper) = self.source.size_hint();
+        let left = self
+            .limits
+            .items
+            .map(|limit| limit.saturating_sub(self.items));
+        let upper = match (upper, left) {
+            (Some(upper), Some(left)) => Some(upper.min(left)),
+            (upper, left) => upper.or(left),
+        };
+        (0, upper)
+    }
+}
+
+impl<S: FusedTryNext> FusedTryNext for Budget<S> {}
+
+impl<S: TryNextWithContext> TryNextWithContext for Budget<S> {
+    type Item = S::Item;
+    type Error = BudgetError<S::Error>;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::{BudgetError, BudgetLimits, Exhausted};
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn stops_after_the_item_budget() {
+        let mut src = from_items(0..10).budget(BudgetLimits::new().max_items(2));
+
+        assert_eq!(src.size_hint(), (0, Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(
+            src.try_next(),
+            Err(BudgetError::Exhausted(Exhausted::Items))
+        );
+        assert_eq!(src.exhausted(), Some(Exhausted::Items));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn stops_after_the_error_budget() {
+        let items = [Ok(1), Err('x'), Ok(2), Err('y'), Ok(3)];
+        let mut src = from_iter(items).budget(BudgetLimits::new().max_errors(2));
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err(BudgetError::Source('x')));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Err(BudgetError::Source('y')));
+        assert_eq!(
+            src.try_next(),
+            Err(BudgetError::Exhausted(Exhausted::Errors))
+        );
+        assert_eq!((src.items(), src.errors()), (2, 2));
+    }
+
+    #[test]
+    fn stops_after_the_time_budget() {
+        let limits = BudgetLimits::new().max_elapsed(Duration::from_millis(10));
+        let mut src = from_items(0..).budget(limits);
+
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        thread::sleep(Duration::from_millis(15));
+        assert_eq!(
+            src.try_next(),
+            Err(BudgetError::Exhausted(Exhausted::Elapsed))
+        );
+    }
+}
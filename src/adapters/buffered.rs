@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that pulls up to `n` results ahead into an internal buffer.
+///
+/// Whenever the buffer is empty and an item is requested, the inner source
+/// is pulled up to `n` times in a row; the batch stops early at `Ok(None)`
+/// or an error. Results are then handed out in their original order, so the
+/// adapter yields exactly the sequence the source produced.
+///
+/// Created by [`TryNextExt::buffered`](crate::TryNextExt::buffered) and
+/// [`TryNextWithContextExt::buffered`](crate::TryNextWithContextExt::buffered).
+#[derive(Debug, Clone)]
+pub struct Buffered<S, T, E> {
+    source: S,
+    size: usize,
+    buf: VecDeque<Result<Option<T>, E>>,
+}
+
+impl<S, T, E> Buffered<S, T, E> {
+    pub(crate) fn new(source: S, size: usize) -> Self {
+        assert!(size > 0, "buffer size must be non-zero");
+        Self {
+            source,
+            size,
+            buf: VecDeque::with_capacity(size),
+        }
+    }
+
+    /// Returns the number of results currently buffered.
+    pub fn buffered_len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the underlying source, dropping any buffered results.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn next(
+        &mut self,
+        mut pull: impl FnMut(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, E> {
+        if self.buf.is_empty() {
+            while self.buf.len() < self.size {
+                let result = pull(&mut self.source);
+                let last = !matches!(result, Ok(Some(_)));
+                self.buf.push_back(result);
+                if last {
+                    break;
+                }
+            }
+        }
+        self.buf.pop_front().unwrap_or(Ok(None))
+    }
+}
+
+impl<S: TryNext> TryNext for Buffered<S, S::Item, S::Error> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.next(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.buf.iter().filter(|r| matches!(r, Ok(Some(_)))).count();
+        if self.buf.back().is_some_and(|r| matches!(r, Ok(None))) {
+            return (pending, Some(pending));
+        }
+        let (lower, upper) = self.source.size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+impl<S: FusedTryNext> FusedTryNext for Buffered<S, S::Item, S::Error> {}
+
+impl<S: TryNextWithContext> TryNextWithContext for Buffered<S, S::Item, S::Error> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.next(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt};
+
+    /// Counts how often it is polled.
+    struct Polled {
+        items: std::vec::IntoIter<Result<u32, char>>,
+        polls: usize,
+    }
+
+    impl TryNext for Polled {
+        type Item = u32;
+        type Error = char;
+
+        fn try_next(&mut self) -> Result<Option<u32>, char> {
+            self.polls += 1;
+            self.items.next().transpose()
+        }
+    }
+
+    #[test]
+    fn pulls_in_batches_and_keeps_order() {
+        let items = vec![Ok(1), Ok(2), Ok(3), Err('x'), Ok(4)];
+        let mut src = Polled {
+            items: items.into_iter(),
+            polls: 0,
+        }
+        .buffered(2);
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.buffered_len(), 1);
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+        assert_eq!(src.buffered_len(), 1);
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(4)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.into_inner().polls, 6);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{EitherError, TryNextWithContext};
+
+/// A source that yields all items of `A`, then all items of `B`.
+///
+/// As a [`TryNextWithContext`], each side keeps its own context type and the
+/// combined context is the pair `(A::Context, B::Context)`. Likewise each
+/// side keeps its own error type, combined as
+/// [`EitherError<A::Error, B::Error>`](EitherError).
+///
+/// Created by [`TryNextWithContextExt::chain`](crate::TryNextWithContextExt::chain).
+#[derive(Debug, Clone)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+    first_done: bool,
+}
+
+impl<A, B> Chain<A, B> {
+    pub(crate) fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            first_done: false,
+        }
+    }
+
+    /// Returns the two underlying sources.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A, B> TryNextWithContext for Chain<A, B>
+where
+    A: TryNextWithContext<Context: Sized>,
+    B: TryNextWithContext<Item = A::Item, Context: Sized>,
+{
+    type Item = A::Item;
+    type Error = EitherError<A::Error, B::Error>;
+    type Context = (A::Context, B::Context);
+
+    fn try_next_with_context(
+        &mut self,
+        (first, second): &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        if !self.first_done {
+            match self
+                .first
+                .try_next_with_context(first)
+                .map_err(EitherError::Left)?
+            {
+                Some(item) => return Ok(Some(item)),
+                None => self.first_done = true,
+            }
+        }
+        self.second
+            .try_next_with_context(second)
+            .map_err(EitherError::Right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::fixtures::{Pop, Upto, drain};
+    use crate::{EitherError, TryNextExt, TryNextWithContext, TryNextWithContextExt, from_iter};
+
+    #[test]
+    fn chains_sources_with_different_contexts() {
+        let mut src = Pop.chain(Upto(0));
+        let mut ctx = (vec![10, 20], 2);
+
+        assert_eq!(drain(&mut src, &mut ctx), [20, 10, 1, 2]);
+        assert!(ctx.0.is_empty());
+    }
+
+    #[test]
+    fn reports_which_side_failed() {
+        let first = from_iter([Ok(1), Err("bad row")]).ignore_context::<()>();
+        let second = from_iter([Err('x'), Ok(2)]).ignore_context::<()>();
+        let mut src = first.chain(second);
+        let ctx = &mut ((), ());
+
+        assert_eq!(src.try_next_with_context(ctx), Ok(Some(1)));
+        assert_eq!(
+            src.try_next_with_context(ctx),
+            Err(EitherError::Left("bad row"))
+        );
+        assert_eq!(src.try_next_with_context(ctx), Err(EitherError::Right('x')));
+        assert_eq!(src.try_next_with_context(ctx), Ok(Some(2)));
+        assert_eq!(src.try_next_with_context(ctx), Ok(None));
+    }
+}
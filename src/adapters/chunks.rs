@@ -0,0 +1,118 @@
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that groups items into `Vec`s of a fixed size.
+///
+/// The last chunk may be shorter. If the inner source fails mid-chunk, the
+/// error is returned and the items gathered so far are kept, so the next
+/// call continues filling the same chunk.
+///
+/// Created by [`TryNextExt::chunks`](crate::TryNextExt::chunks) and
+/// [`TryNextWithContextExt::chunks`](crate::TryNextWithContextExt::chunks).
+#[derive(Debug, Clone)]
+pub struct Chunks<S, T> {
+    source: S,
+    size: usize,
+    buf: Vec<T>,
+}
+
+impl<S, T> Chunks<S, T> {
+    pub(crate) fn new(source: S, size: usize) -> Self {
+        assert!(size > 0, "chunk size must be non-zero");
+        Self {
+            source,
+            size,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Returns the underlying source, dropping any partially gathered chunk.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn finish(&mut self, end: bool) -> Option<Vec<T>> {
+        if end && self.buf.is_empty() {
+            None
+        } else {
+            Some(std::mem::replace(
+                &mut self.buf,
+                Vec::with_capacity(self.size),
+            ))
+        }
+    }
+}
+
+impl<S: TryNext> TryNext for Chunks<S, S::Item> {
+    type Item = Vec<S::Item>;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        while self.buf.len() < self.size {
+            match self.source.try_next()? {
+                Some(item) => self.buf.push(item),
+                None => return Ok(self.finish(true)),
+            }
+        }
+        Ok(self.finish(false))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.source.size_hint();
+        let pending = self.buf.len();
+        let chunks = |n: usize| n.saturating_add(pending).div_ceil(self.size);
+        (chunks(lower), upper.map(chunks))
+    }
+}
+
+impl<S: FusedTryNext> FusedTryNext for Chunks<S, S::Item> {}
+
+impl<S: TryNextWithContext> TryNextWithContext for Chunks<S, S::Item> {
+    type Item = Vec<S::Item>;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        while self.buf.len() < self.size {
+            match self.source.try_next_with_context(context)? {
+                Some(item) => self.buf.push(item),
+                None => return Ok(self.finish(true)),
+            }
+        }
+        Ok(self.finish(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::fixtures::Pop;
+    use crate::{TryNext, TryNextExt, TryNextWithContext, TryNextWithContextExt, from_iter};
+
+    #[test]
+    fn keeps_partial_chunk_across_errors() {
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2), Ok(3)]).chunks(2);
+
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(vec![1, 2])));
+        assert_eq!(src.try_next(), Ok(Some(vec![3])));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn threads_the_context() {
+        let mut src = Pop.chunks(2);
+        let mut stack = vec![1, 2, 3];
+
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(Some(vec![3, 2])));
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(Some(vec![1])));
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(None));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn rejects_zero_size() {
+        let _ = Pop.chunks(0);
+    }
+}
@@ -0,0 +1,256 @@
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::{TryNext, TryNextWithContext};
+
+/// Marks a [`ChunksExact`] that keeps a short final chunk for
+/// [`into_remainder`](ChunksExact::into_remainder).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeepRemainder;
+
+/// Marks a [`ChunksExact`] that reports a short final chunk as
+/// [`ChunksExactError::Remainder`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DenyRemainder;
+
+/// The error type of a [`ChunksExact`] after
+/// [`deny_remainder`](ChunksExact::deny_remainder).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunksExactError<E, T> {
+    /// The inner source failed.
+    Source(E),
+    /// The source ended partway through a chunk, leaving these items.
+    Remainder(Vec<T>),
+}
+
+impl<E: fmt::Display, T> fmt::Display for ChunksExactError<E, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(e) => write!(f, "source error: {e}"),
+            Self::Remainder(items) => {
+                write!(
+                    f,
+                    "source ended mid-chunk with {} leftover items",
+                    items.len()
+                )
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static, T: fmt::Debug> Error for ChunksExactError<E, T> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Source(e) => Some(e),
+            Self::Remainder(_) => None,
+        }
+    }
+}
+
+/// A source that groups items into `Vec`s of exactly `size` items.
+///
+/// Unlike [`Chunks`](super::Chunks), a short final chunk is never yielded.
+/// By default the source simply ends, and the leftover items are available
+/// from [`remainder`](Self::remainder) and
+/// [`into_remainder`](Self::into_remainder). After
+/// [`deny_remainder`](Self::deny_remainder), they are returned once as
+/// [`ChunksExactError::Remainder`] instead, so a truncated input cannot go
+/// unnoticed. If the inner source fails mid-chunk, the error is returned and
+/// the items gathered so far are kept, so the next call continues filling
+/// the same chunk.
+///
+/// ```rust
+/// use try_next::adapters::ChunksExactError;
+/// use try_next::{TryNext, TryNextExt, from_items};
+///
+/// let mut blocks = from_items(0..7).chunks_exact(3);
+/// assert_eq!(blocks.try_next(), Ok(Some(vec![0, 1, 2])));
+/// assert_eq!(blocks.try_next(), Ok(Some(vec![3, 4, 5])));
+/// assert_eq!(blocks.try_next(), Ok(None));
+/// assert_eq!(blocks.into_remainder(), [6]);
+///
+/// let mut blocks = from_items(0..7).chunks_exact(3).deny_remainder();
+/// blocks.try_next().unwrap();
+/// blocks.try_next().unwrap();
+/// assert_eq!(blocks.try_next(), Err(ChunksExactError::Remainder(vec![6])));
+/// ```
+///
+/// Created by [`TryNextExt::chunks_exact`](crate::TryNextExt::chunks_exact)
+/// and [`TryNextWithContextExt::chunks_exact`](crate::TryNextWithContextExt::chunks_exact).
+#[derive(Debug, Clone)]
+pub struct ChunksExact<S, T, M = KeepRemainder> {
+    source: S,
+    size: usize,
+    buf: Vec<T>,
+    _mode: PhantomData<M>,
+}
+
+impl<S, T> ChunksExact<S, T> {
+    pub(crate) fn new(source: S, size: usize) -> Self {
+        assert!(size > 0, "chunk size must be non-zero");
+        Self {
+            source,
+            size,
+            buf: Vec::with_capacity(size),
+            _mode: PhantomData,
+        }
+    }
+
+    /// Returns a short final chunk as an error instead of keeping it.
+    pub fn deny_remainder(self) -> ChunksExact<S, T, DenyRemainder> {
+        ChunksExact {
+            source: self.source,
+            size: self.size,
+            buf: self.buf,
+            _mode: PhantomData,
+        }
+    }
+
+    /// Returns the items gathered toward the next chunk; after the end of
+    /// input, the leftover items.
+    pub fn remainder(&self) -> &[T] {
+        &self.buf
+    }
+
+    /// Returns the items gathered toward the next chunk, dropping the
+    /// source.
+    pub fn into_remainder(self) -> Vec<T> {
+        self.buf
+    }
+}
+
+impl<S, T, M> ChunksExact<S, T, M> {
+    /// Returns the underlying source, dropping any partially gathered chunk.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    /// Fills the current chunk, returning `None` if the source ends first.
+    fn fill<E>(
+        &mut self,
+        mut next: impl FnMut(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<Vec<T>>, E> {
+        while self.buf.len() < self.size {
+            match next(&mut self.source)? {
+                Some(item) => self.buf.push(item),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(std::mem::replace(
+            &mut self.buf,
+            Vec::with_capacity(self.size),
+        )))
+    }
+
+    fn hint(&self, (lower, upper): (usize, Option<usize>)) -> (usize, Option<usize>) {
+        let chunks = |n: usize| n.saturating_add(self.buf.len()) / self.size;
+        (chunks(lower), upper.map(chunks))
+    }
+}
+
+impl<S, T> ChunksExact<S, T, DenyRemainder> {
+    fn deny<E>(
+        &mut self,
+        filled: Result<Option<Vec<T>>, E>,
+    ) -> Result<Option<Vec<T>>, ChunksExactError<E, T>> {
+        match filled.map_err(ChunksExactError::Source)? {
+            None if !self.buf.is_empty() => {
+                Err(ChunksExactError::Remainder(std::mem::take(&mut self.buf)))
+            }
+            chunk => Ok(chunk),
+        }
+    }
+}
+
+impl<S: TryNext> TryNext for ChunksExact<S, S::Item> {
+    type Item = Vec<S::Item>;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.fill(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.hint(self.source.size_hint())
+    }
+}
+
+impl<S: TryNext> TryNext for ChunksExact<S, S::Item, DenyRemainder> {
+    type Item = Vec<S::Item>;
+    type Error = ChunksExactError<S::Error, S::Item>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let filled = self.fill(S::try_next);
+        self.deny(filled)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.hint(self.source.size_hint())
+    }
+}
+
+impl<S: TryNextWithContext> TryNextWithContext for ChunksExact<S, S::Item> {
+    type Item = Vec<S::Item>;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.fill(|source| source.try_next_with_context(context))
+    }
+}
+
+impl<S: TryNextWithContext> TryNextWithContext for ChunksExact<S, S::Item, DenyRemainder> {
+    type Item = Vec<S::Item>;
+    type Error = ChunksExactError<S::Error, S::Item>;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let filled = self.fill(|source| source.try_next_with_context(context));
+        self.deny(filled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChunksExactError;
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn errors_keep_the_partial_chunk() {
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2), Ok(3)]).chunks_exact(2);
+
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.remainder(), [1]);
+        assert_eq!(src.try_next(), Ok(Some(vec![1, 2])));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.into_remainder(), [3]);
+    }
+
+    #[test]
+    fn exact_input_has_no_remainder() {
+        let mut src = from_items(0..4).chunks_exact(2).deny_remainder();
+
+        assert_eq!(src.size_hint(), (2, Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(vec![0, 1])));
+        assert_eq!(src.try_next(), Ok(Some(vec![2, 3])));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn remainder_error_is_returned_once() {
+        let mut src = from_iter([Ok(1), Err('x')])
+            .chunks_exact(2)
+            .deny_remainder();
+
+        assert_eq!(src.try_next(), Err(ChunksExactError::Source('x')));
+        assert_eq!(src.try_next(), Err(ChunksExactError::Remainder(vec![1])));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
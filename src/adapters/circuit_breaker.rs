@@ -0,0 +1,206 @@
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::{TryNext, TryNextWithContext};
+
+/// The error type of a [`CircuitBreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakerError<E> {
+    /// The inner source failed.
+    Source(E),
+    /// The breaker is open; the inner source was not polled.
+    Open,
+}
+
+impl<E: fmt::Display> fmt::Display for BreakerError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(e) => write!(f, "source error: {e}"),
+            Self::Open => f.write_str("circuit breaker is open"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for BreakerError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Source(e) => Some(e),
+            Self::Open => None,
+        }
+    }
+}
+
+/// A source that stops polling after too many consecutive errors.
+///
+/// Each error is passed on as [`BreakerError::Source`]. Once `threshold`
+/// errors have occurred in a row, all within `window` of the first of them,
+/// the breaker opens: further calls return [`BreakerError::Open`] without
+/// polling the inner source. Any successful pull resets the count.
+///
+/// By default an open breaker stays open. With
+/// [`with_cooldown`](Self::with_cooldown), it closes again once the
+/// cool-down has elapsed and the next call polls the source afresh.
+///
+/// Created by [`TryNextExt::circuit_breaker`](crate::TryNextExt::circuit_breaker)
+/// and
+/// [`TryNextWithContextExt::circuit_breaker`](crate::TryNextWithContextExt::circuit_breaker).
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker<S> {
+    source: S,
+    threshold: usize,
+    window: Duration,
+    cooldown: Option<Duration>,
+    failures: usize,
+    first_failure: Option<Instant>,
+    opened_at: Option<Instant>,
+}
+
+impl<S> CircuitBreaker<S> {
+    pub(crate) fn new(source: S, threshold: usize, window: Duration) -> Self {
+        assert!(threshold > 0, "circuit breaker threshold must be non-zero");
+        Self {
+            source,
+            threshold,
+            window,
+            cooldown: None,
+            failures: 0,
+            first_failure: None,
+            opened_at: None,
+        }
+    }
+
+    /// Closes the breaker again once `cooldown` has passed since it opened.
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = Some(cooldown);
+        self
+    }
+
+    /// Returns `true` if the breaker is currently refusing to poll.
+    pub fn is_open(&self) -> bool {
+        match (self.opened_at, self.cooldown) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(opened_at), Some(cooldown)) => opened_at.elapsed() < cooldown,
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn guard<T, E>(
+        &mut self,
+        pull: impl FnOnce(&mut S) -> Result<T, E>,
+    ) -> Result<T, BreakerError<E>> {
+        if self.opened_at.is_some() {
+            if self.is_open() {
+                return Err(BreakerError::Open);
+            }
+            self.opened_at = None;
+            self.failures = 0;
+        }
+        match pull(&mut self.source) {
+            Ok(item) => {
+                self.failures = 0;
+                Ok(item)
+            }
+            Err(err) => {
+                let now = Instant::now();
+                match self.first_failure {
+                    Some(first) if self.failures > 0 && now - first <= self.window => {
+                        self.failures += 1;
+                    }
+                    _ => {
+                        self.failures = 1;
+                        self.first_failure = Some(now);
+                    }
+                }
+                if self.failures >= self.threshold {
+                    self.opened_at = Some(now);
+                }
+                Err(BreakerError::Source(err))
+            }
+        }
+    }
+}
+
+impl<S: TryNext> TryNext for CircuitBreaker<S> {
+    type Item = S::Item;
+    type Error = BreakerError<S::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.guard(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.is_open() {
+            (0, Some(0))
+        } else {
+            (0, self.source.size_hint().1)
+        }
+    }
+}
+
+impl<S: TryNextWithContext> TryNextWithContext for CircuitBreaker<S> {
+    type Item = S::Item;
+    type Error = BreakerError<S::Error>;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.guard(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BreakerError;
+    use crate::{TryNext, TryNextExt, from_iter};
+    use std::time::Duration;
+
+    const LONG: Duration = Duration::from_secs(3600);
+
+    #[test]
+    fn opens_after_consecutive_errors() {
+        let items = [Err('a'), Ok(1), Err('b'), Err('c'), Ok(2)];
+        let mut src = from_iter(items).circuit_breaker(2, LONG);
+
+        assert_eq!(src.try_next(), Err(BreakerError::Source('a')));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err(BreakerError::Source('b')));
+        assert_eq!(src.try_next(), Err(BreakerError::Source('c')));
+        assert!(src.is_open());
+        assert_eq!(src.try_next(), Err(BreakerError::Open));
+
+        // The remaining item was never pulled.
+        assert_eq!(src.into_inner().try_next(), Ok(Some(2)));
+    }
+
+    #[test]
+    fn closes_after_cooldown() {
+        let items = [Err('a'), Ok(1)];
+        let mut src = from_iter(items)
+            .circuit_breaker(1, LONG)
+            .with_cooldown(Duration::ZERO);
+
+        assert_eq!(src.try_next(), Err(BreakerError::Source('a')));
+        assert!(!src.is_open());
+        assert_eq!(src.try_next(), Ok(Some(1)));
+    }
+
+    #[test]
+    fn errors_outside_the_window_start_a_new_count() {
+        let items = [Err::<u32, _>('a'), Err('b'), Err('c')];
+        let mut src = from_iter(items).circuit_breaker(2, Duration::ZERO);
+
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(src.try_next(), Err(BreakerError::Source('a')));
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(src.try_next(), Err(BreakerError::Source('b')));
+        assert!(!src.is_open());
+    }
+}
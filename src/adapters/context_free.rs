@@ -0,0 +1,64 @@
+use crate::{TryNext, TryNextWithContext};
+
+/// A [`TryNext`] view of a [`TryNextWithContext`] whose context is `()`.
+///
+/// Library authors can implement only the context-aware trait with
+/// `Context = ()` and hand out this wrapper wherever a plain [`TryNext`] is
+/// expected. The wrapper is zero-cost.
+///
+/// Created by
+/// [`TryNextWithContextExt::context_free`](crate::TryNextWithContextExt::context_free).
+#[derive(Debug, Clone)]
+pub struct ContextFree<S> {
+    source: S,
+}
+
+impl<S> ContextFree<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S> TryNext for ContextFree<S>
+where
+    S: TryNextWithContext<Context = ()>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next_with_context(&mut ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, TryNextWithContext, TryNextWithContextExt};
+    use std::convert::Infallible;
+
+    struct Countdown(u8);
+
+    impl TryNextWithContext for Countdown {
+        type Item = u8;
+        type Error = Infallible;
+        type Context = ();
+
+        fn try_next_with_context(&mut self, _: &mut ()) -> Result<Option<u8>, Infallible> {
+            Ok(self.0.checked_sub(1).inspect(|&n| self.0 = n))
+        }
+    }
+
+    #[test]
+    fn unit_context_source_is_a_try_next() {
+        let mut src = Countdown(2).context_free().fuse();
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
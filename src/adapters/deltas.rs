@@ -0,0 +1,154 @@
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that yields `f(previous, current)` for each pair of consecutive
+/// items.
+///
+/// The first item has no predecessor, so by default it only becomes the
+/// previous item of the second. After [`with_first`](Self::with_first) it
+/// is also yielded, converted with the given function. Errors pass through
+/// and do not break the pairing: the item after an error is compared with
+/// the last item before it.
+///
+/// ```rust
+/// use try_next::{TryNextExt, from_items};
+///
+/// let timestamps = from_items([100u64, 130, 135, 200]);
+/// let gaps: Vec<u64> = timestamps.deltas(|a, b| b - a).try_collect().unwrap();
+/// assert_eq!(gaps, [30, 5, 65]);
+///
+/// let readings = from_items([10i32, 12, 9]);
+/// let diffs: Vec<i32> = readings
+///     .deltas(|a, b| b - a)
+///     .with_first(|first| *first)
+///     .try_collect()
+///     .unwrap();
+/// assert_eq!(diffs, [10, 2, -3]);
+/// ```
+///
+/// Created by [`TryNextExt::deltas`](crate::TryNextExt::deltas) and
+/// [`TryNextWithContextExt::deltas`](crate::TryNextWithContextExt::deltas).
+#[derive(Debug, Clone)]
+pub struct Deltas<S, T, U, F> {
+    source: S,
+    f: F,
+    prev: Option<T>,
+    first: Option<fn(&T) -> U>,
+}
+
+impl<S, T, U, F> Deltas<S, T, U, F> {
+    pub(crate) fn new(source: S, f: F) -> Self {
+        Self {
+            source,
+            f,
+            prev: None,
+            first: None,
+        }
+    }
+
+    /// Also yields the first item, converted with `first`.
+    pub fn with_first(mut self, first: fn(&T) -> U) -> Self {
+        self.first = Some(first);
+        self
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn pull<E>(
+        &mut self,
+        mut next: impl FnMut(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<U>, E>
+    where
+        F: FnMut(&T, &T) -> U,
+    {
+        loop {
+            let Some(item) = next(&mut self.source)? else {
+                return Ok(None);
+            };
+            let delta = match &self.prev {
+                Some(prev) => Some((self.f)(prev, &item)),
+                None => self.first.map(|first| first(&item)),
+            };
+            self.prev = Some(item);
+            if delta.is_some() {
+                return Ok(delta);
+            }
+        }
+    }
+}
+
+impl<S, U, F> TryNext for Deltas<S, S::Item, U, F>
+where
+    S: TryNext,
+    F: FnMut(&S::Item, &S::Item) -> U,
+{
+    type Item = U;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.source.size_hint();
+        if self.prev.is_some() || self.first.is_some() {
+            (lower, upper)
+        } else {
+            (lower.saturating_sub(1), upper.map(|n| n.saturating_sub(1)))
+        }
+    }
+}
+
+impl<S, U, F> FusedTryNext for Deltas<S, S::Item, U, F>
+where
+    S: FusedTryNext,
+    F: FnMut(&S::Item, &S::Item) -> U,
+{
+}
+
+impl<S, U, F> TryNextWithContext for Deltas<S, S::Item, U, F>
+where
+    S: TryNextWithContext,
+    F: FnMut(&S::Item, &S::Item) -> U,
+{
+    type Item = U;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn pairs_across_errors() {
+        let mut src = from_iter([Ok(1), Err('x'), Ok(4), Ok(9)]).deltas(|a, b| b - a);
+
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+        assert_eq!(src.try_next(), Ok(Some(5)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn size_hint_accounts_for_the_first_item() {
+        let mut src = from_items([1, 2, 3]).deltas(|a, b| a + b);
+        assert_eq!(src.size_hint(), (2, Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+        assert_eq!(src.size_hint(), (1, Some(1)));
+
+        let src = from_items([1, 2, 3])
+            .deltas(|a, b| a + b)
+            .with_first(|x| *x);
+        assert_eq!(src.size_hint(), (3, Some(3)));
+    }
+}
@@ -0,0 +1,164 @@
+use std::fmt::Display;
+
+use crate::{Contextualized, TryNext, TryNextWithContext};
+
+/// A source that wraps errors in [`Contextualized`] with a fixed message.
+///
+/// Created by [`TryNextExt::err_context`](crate::TryNextExt::err_context) and
+/// [`TryNextWithContextExt::err_context`](crate::TryNextWithContextExt::err_context).
+#[derive(Debug, Clone)]
+pub struct ErrContext<S, M> {
+    source: S,
+    message: M,
+}
+
+impl<S, M> ErrContext<S, M> {
+    pub(crate) fn new(source: S, message: M) -> Self {
+        Self { source, message }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, M> TryNext for ErrContext<S, M>
+where
+    S: TryNext,
+    M: Display,
+{
+    type Item = S::Item;
+    type Error = Contextualized<S::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next().map_err(|error| Contextualized {
+            context: self.message.to_string(),
+            error,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, M> TryNextWithContext for ErrContext<S, M>
+where
+    S: TryNextWithContext,
+    M: Display,
+{
+    type Item = S::Item;
+    type Error = Contextualized<S::Error>;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.source
+            .try_next_with_context(context)
+            .map_err(|error| Contextualized {
+                context: self.message.to_string(),
+                error,
+            })
+    }
+}
+
+/// A source that wraps errors in [`Contextualized`] with a message computed
+/// only when an error occurs.
+///
+/// Created by [`TryNextExt::err_context_with`](crate::TryNextExt::err_context_with)
+/// and
+/// [`TryNextWithContextExt::err_context_with`](crate::TryNextWithContextExt::err_context_with).
+#[derive(Debug, Clone)]
+pub struct ErrContextWith<S, F> {
+    source: S,
+    f: F,
+}
+
+impl<S, F> ErrContextWith<S, F> {
+    pub(crate) fn new(source: S, f: F) -> Self {
+        Self { source, f }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, F, M> TryNext for ErrContextWith<S, F>
+where
+    S: TryNext,
+    F: FnMut() -> M,
+    M: Display,
+{
+    type Item = S::Item;
+    type Error = Contextualized<S::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next().map_err(|error| Contextualized {
+            context: (self.f)().to_string(),
+            error,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, F, M> TryNextWithContext for ErrContextWith<S, F>
+where
+    S: TryNextWithContext,
+    F: FnMut() -> M,
+    M: Display,
+{
+    type Item = S::Item;
+    type Error = Contextualized<S::Error>;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.source
+            .try_next_with_context(context)
+            .map_err(|error| Contextualized {
+                context: (self.f)().to_string(),
+                error,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn annotates_each_stage() {
+        let mut src = from_iter([Ok(1), Err("bad digit")])
+            .err_context("parsing")
+            .err_context("stage 2");
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(
+            src.try_next().unwrap_err().to_string(),
+            "stage 2: parsing: bad digit"
+        );
+    }
+
+    #[test]
+    fn lazy_message_is_built_only_on_error() {
+        let mut built = 0;
+        let mut src = from_iter([Ok(1), Ok(2), Err('x')]).err_context_with(|| {
+            built += 1;
+            format!("attempt {built}")
+        });
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next().unwrap_err().context, "attempt 1");
+    }
+}
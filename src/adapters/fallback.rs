@@ -0,0 +1,141 @@
+use crate::TryNext;
+
+/// A source that switches to a secondary source when the primary fails.
+///
+/// Items come from the primary until it returns an error. That error is
+/// swallowed, the optional `prepare` closure is run on the secondary (for
+/// example to seek it to a checkpoint), and from then on every call is
+/// forwarded to the secondary. Errors from `prepare` or from the secondary
+/// are returned as usual.
+///
+/// Created by [`TryNextExt::or_fallback`](crate::TryNextExt::or_fallback) and
+/// [`TryNextExt::or_fallback_with`](crate::TryNextExt::or_fallback_with).
+#[derive(Debug, Clone)]
+pub struct Fallback<A, B, F> {
+    primary: A,
+    secondary: B,
+    prepare: Option<F>,
+    failed_over: bool,
+}
+
+impl<A, B, F> Fallback<A, B, F> {
+    pub(crate) fn new(primary: A, secondary: B, prepare: F) -> Self {
+        Self {
+            primary,
+            secondary,
+            prepare: Some(prepare),
+            failed_over: false,
+        }
+    }
+
+    /// Returns `true` once the primary has failed and the secondary is in
+    /// use.
+    pub fn is_failed_over(&self) -> bool {
+        self.failed_over
+    }
+
+    /// Returns the two underlying sources.
+    pub fn into_inner(self) -> (A, B) {
+        (self.primary, self.secondary)
+    }
+}
+
+impl<A, B, F> TryNext for Fallback<A, B, F>
+where
+    A: TryNext,
+    B: TryNext<Item = A::Item, Error = A::Error>,
+    F: FnOnce(&mut B) -> Result<(), B::Error>,
+{
+    type Item = A::Item;
+    type Error = A::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if !self.failed_over {
+            match self.primary.try_next() {
+                Ok(item) => return Ok(item),
+                Err(_) => self.failed_over = true,
+            }
+        }
+        if let Some(prepare) = self.prepare.take() {
+            prepare(&mut self.secondary)?;
+        }
+        self.secondary.try_next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.failed_over {
+            self.secondary.size_hint()
+        } else {
+            (self.primary.size_hint().0, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, TrySeek, from_iter};
+
+    struct Snapshot {
+        data: Vec<u32>,
+        pos: usize,
+    }
+
+    impl TryNext for Snapshot {
+        type Item = u32;
+        type Error = &'static str;
+
+        fn try_next(&mut self) -> Result<Option<u32>, &'static str> {
+            let item = self.data.get(self.pos).copied();
+            self.pos += item.is_some() as usize;
+            Ok(item)
+        }
+    }
+
+    impl TrySeek for Snapshot {
+        type Position = usize;
+
+        fn try_position(&mut self) -> Result<usize, &'static str> {
+            Ok(self.pos)
+        }
+
+        fn try_seek(&mut self, position: usize) -> Result<(), &'static str> {
+            self.pos = position;
+            Ok(())
+        }
+
+        fn try_rewind(&mut self) -> Result<(), &'static str> {
+            self.try_seek(0)
+        }
+    }
+
+    #[test]
+    fn switches_on_first_error() {
+        let snapshot = Snapshot {
+            data: vec![7, 8],
+            pos: 0,
+        };
+        let mut src = from_iter([Ok(1), Err("replica down"), Ok(2)]).or_fallback(snapshot);
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert!(!src.is_failed_over());
+        assert_eq!(src.try_next(), Ok(Some(7)));
+        assert!(src.is_failed_over());
+        assert_eq!(src.try_next(), Ok(Some(8)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn resumes_from_a_checkpoint() {
+        let snapshot = Snapshot {
+            data: vec![1, 2, 3],
+            pos: 0,
+        };
+        let primary = from_iter([Ok(1), Err("replica down")]);
+        let mut src = primary.or_fallback_with(snapshot, |s| s.try_seek(1));
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
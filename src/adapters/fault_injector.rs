@@ -0,0 +1,171 @@
+use crate::{TryNext, TryNextWithContext};
+
+/// When a [`FaultInjector`] replaces a call with an error.
+///
+/// Calls are numbered from zero and include injected ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FaultPlan {
+    /// Fails every `n`th call: calls `n - 1`, `2n - 1`, and so on.
+    EveryNth(usize),
+    /// Fails the calls at the given indices.
+    At(Vec<usize>),
+    /// Fails each call with probability `1 / one_in`, using a deterministic
+    /// generator seeded with `seed`, so a failing test can be replayed.
+    Random {
+        /// The generator seed.
+        seed: u64,
+        /// The inverse of the failure probability.
+        one_in: u32,
+    },
+}
+
+/// A source that injects errors into another source according to a
+/// [`FaultPlan`].
+///
+/// On a call selected by the plan, the inner source is not polled; an error
+/// built by the factory closure is returned instead. Real items are
+/// therefore delayed, never lost, which is what a recovering consumer expects
+/// from a transient failure.
+///
+/// Available with the `test-util` feature.
+///
+/// Created by [`TryNextExt::inject_faults`](crate::TryNextExt::inject_faults)
+/// and
+/// [`TryNextWithContextExt::inject_faults`](crate::TryNextWithContextExt::inject_faults).
+#[derive(Debug, Clone)]
+pub struct FaultInjector<S, F> {
+    source: S,
+    plan: FaultPlan,
+    make_error: F,
+    calls: usize,
+    rng: u64,
+}
+
+impl<S, F> FaultInjector<S, F> {
+    pub(crate) fn new(source: S, plan: FaultPlan, make_error: F) -> Self {
+        let rng = match plan {
+            FaultPlan::Random { seed, .. } => seed | 1,
+            _ => 0,
+        };
+        Self {
+            source,
+            plan,
+            make_error,
+            calls: 0,
+            rng,
+        }
+    }
+
+    /// Returns the number of calls made so far, including injected failures.
+    pub fn calls(&self) -> usize {
+        self.calls
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn inject(&mut self) -> bool {
+        let call = self.calls;
+        self.calls += 1;
+        match &self.plan {
+            FaultPlan::EveryNth(n) => *n > 0 && (call + 1).is_multiple_of(*n),
+            FaultPlan::At(indices) => indices.contains(&call),
+            FaultPlan::Random { one_in, .. } => {
+                // xorshift64; only determinism matters here.
+                self.rng ^= self.rng << 13;
+                self.rng ^= self.rng >> 7;
+                self.rng ^= self.rng << 17;
+                *one_in > 0 && self.rng.is_multiple_of(u64::from(*one_in))
+            }
+        }
+    }
+}
+
+impl<S, F> TryNext for FaultInjector<S, F>
+where
+    S: TryNext,
+    F: FnMut() -> S::Error,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.inject() {
+            return Err((self.make_error)());
+        }
+        self.source.try_next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, F> TryNextWithContext for FaultInjector<S, F>
+where
+    S: TryNextWithContext,
+    F: FnMut() -> S::Error,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        if self.inject() {
+            return Err((self.make_error)());
+        }
+        self.source.try_next_with_context(context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FaultPlan;
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    fn outcomes<S: TryNext>(mut src: S, calls: usize) -> Vec<Result<Option<S::Item>, S::Error>> {
+        (0..calls).map(|_| src.try_next()).collect()
+    }
+
+    #[test]
+    fn every_nth_delays_real_items() {
+        let src = from_iter([Ok(1), Ok(2), Ok(3)]).inject_faults(FaultPlan::EveryNth(2), || 'x');
+
+        assert_eq!(
+            outcomes(src, 5),
+            [Ok(Some(1)), Err('x'), Ok(Some(2)), Err('x'), Ok(Some(3))]
+        );
+    }
+
+    #[test]
+    fn fails_at_given_indices() {
+        let src = from_iter([Ok(1), Ok(2)]).inject_faults(FaultPlan::At(vec![0, 2]), || 'x');
+
+        assert_eq!(
+            outcomes(src, 4),
+            [Err('x'), Ok(Some(1)), Err('x'), Ok(Some(2))]
+        );
+    }
+
+    #[test]
+    fn random_plan_is_deterministic() {
+        let plan = FaultPlan::Random {
+            seed: 42,
+            one_in: 3,
+        };
+        let run = || {
+            let src = from_iter((0..100).map(Ok)).inject_faults(plan.clone(), || ());
+            outcomes(src, 100)
+        };
+
+        let first = run();
+        assert_eq!(first, run());
+        assert!(first.iter().any(Result::is_err));
+        assert!(first.iter().any(Result::is_ok));
+    }
+}
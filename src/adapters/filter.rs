@@ -0,0 +1,116 @@
+use crate::{DoubleEndedTryNext, FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that yields only the items matching a predicate.
+///
+/// Errors are always passed through.
+///
+/// Created by [`TryNextExt::filter`](crate::TryNextExt::filter) and
+/// [`TryNextWithContextExt::filter`](crate::TryNextWithContextExt::filter).
+#[derive(Debug, Clone)]
+pub struct Filter<S, P> {
+    source: S,
+    pred: P,
+}
+
+impl<S, P> Filter<S, P> {
+    pub(crate) fn new(source: S, pred: P) -> Self {
+        Self { source, pred }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, P> TryNext for Filter<S, P>
+where
+    S: TryNext,
+    P: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        while let Some(item) = self.source.try_next()? {
+            if (self.pred)(&item) {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.source.size_hint().1)
+    }
+}
+
+impl<S, P> DoubleEndedTryNext for Filter<S, P>
+where
+    S: DoubleEndedTryNext,
+    P: FnMut(&S::Item) -> bool,
+{
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        while let Some(item) = self.source.try_next_back()? {
+            if (self.pred)(&item) {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<S, P> FusedTryNext for Filter<S, P>
+where
+    S: FusedTryNext,
+    P: FnMut(&S::Item) -> bool,
+{
+}
+
+impl<S, P> TryNextWithContext for Filter<S, P>
+where
+    S: TryNextWithContext,
+    P: FnMut(&S::Item) -> bool,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        while let Some(item) = self.source.try_next_with_context(context)? {
+            if (self.pred)(&item) {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::fixtures::Pop;
+    use crate::{TryNext, TryNextExt, TryNextWithContext, TryNextWithContextExt, from_iter};
+
+    #[test]
+    fn passes_errors_through() {
+        let mut src = from_iter([Ok(1), Ok(2), Err('x'), Ok(4)]).filter(|n| n % 2 == 0);
+
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(4)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn threads_the_context() {
+        let mut src = Pop.filter(|n| n % 2 == 1);
+        let mut stack = vec![1, 2, 3, 4];
+
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(Some(3)));
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(Some(1)));
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(None));
+    }
+}
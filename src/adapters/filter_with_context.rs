@@ -0,0 +1,67 @@
+use crate::TryNextWithContext;
+
+/// A source that yields only the items matching a predicate that also
+/// receives the context.
+///
+/// Errors are always passed through.
+///
+/// Created by [`TryNextWithContextExt::filter_with_context`](crate::TryNextWithContextExt::filter_with_context).
+#[derive(Debug, Clone)]
+pub struct FilterWithContext<S, P> {
+    source: S,
+    pred: P,
+}
+
+impl<S, P> FilterWithContext<S, P> {
+    pub(crate) fn new(source: S, pred: P) -> Self {
+        Self { source, pred }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, P> TryNextWithContext for FilterWithContext<S, P>
+where
+    S: TryNextWithContext,
+    P: FnMut(&mut S::Context, &S::Item) -> bool,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        while let Some(item) = self.source.try_next_with_context(context)? {
+            if (self.pred)(context, &item) {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNextExt, TryNextWithContext, TryNextWithContextExt, from_items};
+    use std::collections::HashSet;
+
+    #[test]
+    fn deduplicates_through_the_context() {
+        let mut src = from_items([3, 1, 3, 2, 1])
+            .ignore_context::<HashSet<u32>>()
+            .filter_with_context(|seen, n| seen.insert(*n));
+        let mut seen = HashSet::new();
+
+        let mut out = Vec::new();
+        while let Some(n) = src.try_next_with_context(&mut seen).unwrap() {
+            out.push(n);
+        }
+        assert_eq!(out, [3, 1, 2]);
+        assert_eq!(seen.len(), 3);
+    }
+}
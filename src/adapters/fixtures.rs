@@ -0,0 +1,47 @@
+//! Context-aware sources shared by the adapter tests.
+
+use std::convert::Infallible;
+
+use crate::TryNextWithContext;
+
+/// Pops numbers off a stack context.
+pub(crate) struct Pop;
+
+impl TryNextWithContext for Pop {
+    type Item = u32;
+    type Error = Infallible;
+    type Context = Vec<u32>;
+
+    fn try_next_with_context(&mut self, stack: &mut Vec<u32>) -> Result<Option<u32>, Infallible> {
+        Ok(stack.pop())
+    }
+}
+
+/// Counts up to a limit kept in the context.
+pub(crate) struct Upto(pub(crate) u32);
+
+impl TryNextWithContext for Upto {
+    type Item = u32;
+    type Error = Infallible;
+    type Context = u32;
+
+    fn try_next_with_context(&mut self, limit: &mut u32) -> Result<Option<u32>, Infallible> {
+        if self.0 == *limit {
+            return Ok(None);
+        }
+        self.0 += 1;
+        Ok(Some(self.0))
+    }
+}
+
+/// Pulls every item out of `src`, panicking on errors.
+pub(crate) fn drain<S: TryNextWithContext>(src: &mut S, ctx: &mut S::Context) -> Vec<S::Item>
+where
+    S::Error: std::fmt::Debug,
+{
+    let mut out = Vec::new();
+    while let Some(item) = src.try_next_with_context(ctx).unwrap() {
+        out.push(item);
+    }
+    out
+}
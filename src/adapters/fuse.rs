@@ -0,0 +1,201 @@
+use std::num::NonZeroUsize;
+
+use crate::{DoubleEndedTryNext, ExactSizeTryNext, FusedTryNext, TryNext, TrySeek};
+
+/// A source that returns `Ok(None)` forever after the first `Ok(None)`.
+///
+/// Errors do not end the sequence; only the first `Ok(None)` does. After that
+/// point the inner source is never polled again. For
+/// [`try_next_chunk`](TryNext::try_next_chunk) and
+/// [`try_fill`](TryNext::try_fill), the end is an empty result: a short one
+/// may come from a source that simply had no more items ready.
+///
+/// Created by [`TryNextExt::fuse`](crate::TryNextExt::fuse).
+#[derive(Debug, Clone)]
+pub struct Fuse<S> {
+    source: S,
+    done: bool,
+}
+
+impl<S> Fuse<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            source,
+            done: false,
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: TryNext> TryNext for Fuse<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.done {
+            return Ok(None);
+        }
+        let item = self.source.try_next()?;
+        self.done = item.is_none();
+        Ok(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.source.size_hint()
+        }
+    }
+
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        if self.done {
+            return Ok(Vec::new());
+        }
+        let chunk = self.source.try_next_chunk(max)?;
+        self.done = max > 0 && chunk.is_empty();
+        Ok(chunk)
+    }
+
+    fn try_fill(&mut self, buf: &mut [Self::Item]) -> Result<usize, Self::Error>
+    where
+        Self::Item: Copy,
+    {
+        if self.done {
+            return Ok(0);
+        }
+        let filled = self.source.try_fill(buf)?;
+        self.done = filled == 0 && !buf.is_empty();
+        Ok(filled)
+    }
+
+    fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        if self.done {
+            return Ok(NonZeroUsize::new(n).map_or(Ok(()), Err));
+        }
+        let advanced = self.source.try_advance_by(n)?;
+        self.done = advanced.is_err();
+        Ok(advanced)
+    }
+}
+
+impl<S: DoubleEndedTryNext> DoubleEndedTryNext for Fuse<S> {
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.done {
+            return Ok(None);
+        }
+        let item = self.source.try_next_back()?;
+        self.done = item.is_none();
+        Ok(item)
+    }
+}
+
+/// Seeking clears the exhausted state, so a rewound source yields again.
+impl<S: TrySeek> TrySeek for Fuse<S> {
+    type Position = S::Position;
+
+    fn try_position(&mut self) -> Result<Self::Position, Self::Error> {
+        self.source.try_position()
+    }
+
+    fn try_seek(&mut self, position: Self::Position) -> Result<(), Self::Error> {
+        self.source.try_seek(position)?;
+        self.done = false;
+        Ok(())
+    }
+
+    fn try_rewind(&mut self) -> Result<(), Self::Error> {
+        self.source.try_rewind()?;
+        self.done = false;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S: crate::Checkpoint> crate::Checkpoint for Fuse<S> {
+    type State = (S::State, bool);
+
+    fn save_state(&self) -> Self::State {
+        (self.source.save_state(), self.done)
+    }
+
+    fn restore_state(&mut self, (state, done): Self::State) -> Result<(), Self::Error> {
+        self.source.restore_state(state)?;
+        self.done = done;
+        Ok(())
+    }
+}
+
+impl<S: ExactSizeTryNext> ExactSizeTryNext for Fuse<S> {}
+
+impl<S: TryNext> FusedTryNext for Fuse<S> {}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+    use std::sync::mpsc;
+
+    use crate::sources::ChannelSource;
+    use crate::{TryNext, TryNextExt};
+
+    /// Yields `Some(0)`, `None`, `Some(1)`, `None`, ... forever.
+    struct Flicker(u32);
+
+    impl TryNext for Flicker {
+        type Item = u32;
+        type Error = ();
+
+        fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            self.0 += 1;
+            Ok((self.0 % 2 == 1).then_some(self.0 / 2))
+        }
+    }
+
+    #[test]
+    fn stays_exhausted_after_none() {
+        let mut src = Flicker(0).fuse();
+
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.size_hint(), (0, Some(0)));
+
+        // The inner source was not polled after the end.
+        assert_eq!(src.into_inner().0, 2);
+    }
+
+    #[test]
+    fn advancing_past_the_end_fuses() {
+        let mut src = Flicker(0).fuse();
+
+        assert_eq!(
+            src.try_advance_by(3),
+            Ok(Err(NonZeroUsize::new(2).unwrap()))
+        );
+        assert_eq!(
+            src.try_advance_by(1),
+            Ok(Err(NonZeroUsize::new(1).unwrap()))
+        );
+        assert_eq!(src.into_inner().0, 2);
+    }
+
+    #[test]
+    fn short_chunks_do_not_end_a_live_source() {
+        let (tx, rx) = mpsc::channel();
+        let mut src = ChannelSource::new(rx).fuse();
+
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        assert_eq!(src.try_next_chunk(3), Ok(vec![1, 2]));
+        tx.send(3).unwrap();
+        assert_eq!(src.try_next(), Ok(Some(3)));
+
+        drop(tx);
+        assert_eq!(src.try_next_chunk(3), Ok(vec![]));
+        assert_eq!(src.size_hint(), (0, Some(0)));
+    }
+}
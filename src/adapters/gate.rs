@@ -0,0 +1,247 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A switch that pauses and resumes the [`Gate`] and [`TryGate`] adapters
+/// given a clone of it.
+///
+/// Clones share the switch, so a handle kept by an operator or a signal
+/// handler controls every gate created from it. A new handle is open.
+#[derive(Debug, Clone, Default)]
+pub struct GateHandle {
+    shared: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl GateHandle {
+    /// Creates an open handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops gated sources from pulling until [`resume`](Self::resume) is
+    /// called.
+    pub fn pause(&self) {
+        *self.lock() = true;
+    }
+
+    /// Lets gated sources pull again, waking any blocked in [`Gate`].
+    pub fn resume(&self) {
+        *self.lock() = false;
+        self.shared.1.notify_all();
+    }
+
+    /// Returns `true` while paused.
+    pub fn is_paused(&self) -> bool {
+        *self.lock()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, bool> {
+        self.shared.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn wait(&self) {
+        let (paused, resumed) = &*self.shared;
+        let paused = paused.lock().unwrap_or_else(PoisonError::into_inner);
+        drop(
+            resumed
+                .wait_while(paused, |paused| *paused)
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+    }
+}
+
+/// A source whose pulls block while its [`GateHandle`] is paused.
+///
+/// Each call waits until the handle is open and then pulls the inner
+/// source; a pull already under way when the handle is paused completes
+/// normally.
+///
+/// Created by [`TryNextExt::gate`](crate::TryNextExt::gate) and
+/// [`TryNextWithContextExt::gate`](crate::TryNextWithContextExt::gate).
+#[derive(Debug, Clone)]
+pub struct Gate<S> {
+    source: S,
+    handle: GateHandle,
+}
+
+impl<S> Gate<S> {
+    pub(crate) fn new(source: S, handle: GateHandle) -> Self {
+        Self { source, handle }
+    }
+
+    /// Returns the handle controlling this gate.
+    pub fn handle(&self) -> &GateHandle {
+        &self.handle
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: TryNext> TryNext for Gate<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.handle.wait();
+        self.source.try_next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S: FusedTryNext> FusedTryNext for Gate<S> {}
+
+impl<S: TryNextWithContext> TryNextWithContext for Gate<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.handle.wait();
+        self.source.try_next_with_context(context)
+    }
+}
+
+/// The error type of a [`TryGate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GateError<E> {
+    /// The inner source failed.
+    Source(E),
+    /// The gate is paused; the inner source was not polled.
+    Paused,
+}
+
+impl<E: fmt::Display> fmt::Display for GateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(e) => write!(f, "source error: {e}"),
+            Self::Paused => f.write_str("gate is paused"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for GateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Source(e) => Some(e),
+            Self::Paused => None,
+        }
+    }
+}
+
+/// A source that reports [`GateError::Paused`] instead of pulling while its
+/// [`GateHandle`] is paused.
+///
+/// The non-blocking counterpart of [`Gate`], for consumers that poll
+/// several sources or have other work to do; calling again after the handle
+/// resumes pulls as usual.
+///
+/// Created by [`TryNextExt::try_gate`](crate::TryNextExt::try_gate) and
+/// [`TryNextWithContextExt::try_gate`](crate::TryNextWithContextExt::try_gate).
+#[derive(Debug, Clone)]
+pub struct TryGate<S> {
+    source: S,
+    handle: GateHandle,
+}
+
+impl<S> TryGate<S> {
+    pub(crate) fn new(source: S, handle: GateHandle) -> Self {
+        Self { source, handle }
+    }
+
+    /// Returns the handle controlling this gate.
+    pub fn handle(&self) -> &GateHandle {
+        &self.handle
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn pull<T, E>(
+        &mut self,
+        pull: impl FnOnce(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, GateError<E>> {
+        if self.handle.is_paused() {
+            return Err(GateError::Paused);
+        }
+        pull(&mut self.source).map_err(GateError::Source)
+    }
+}
+
+impl<S: TryNext> TryNext for TryGate<S> {
+    type Item = S::Item;
+    type Error = GateError<S::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S: TryNextWithContext> TryNextWithContext for TryGate<S> {
+    type Item = S::Item;
+    type Error = GateError<S::Error>;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::{GateError, GateHandle};
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn paused_gate_blocks_until_resumed() {
+        let handle = GateHandle::new();
+        let mut src = from_items(1..=2).gate(handle.clone());
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        handle.pause();
+        let resumer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            handle.resume();
+        });
+        let started = Instant::now();
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert!(started.elapsed() >= Duration::from_millis(20));
+        resumer.join().unwrap();
+    }
+
+    #[test]
+    fn try_gate_reports_paused_without_pulling() {
+        let handle = GateHandle::new();
+        let mut src = from_iter([Ok(1), Err('x')]).try_gate(handle.clone());
+
+        handle.pause();
+        assert!(handle.is_paused());
+        assert_eq!(src.try_next(), Err(GateError::Paused));
+        handle.resume();
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err(GateError::Source('x')));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
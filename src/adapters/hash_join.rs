@@ -0,0 +1,215 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::{EitherError, TryNext};
+
+/// Marks a [`HashJoin`] that yields only items with a match.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InnerJoin;
+
+/// Marks a [`HashJoin`] that also yields items without a match, paired with
+/// `None`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LeftJoin;
+
+/// A hash join of a streamed source against a buffered build source.
+///
+/// On the first pull, the build source is drained into a `HashMap` keyed by
+/// `build_key`, so it should be the smaller side. The source is then
+/// streamed, and each item is paired with every build item whose key equals
+/// its `probe_key`, in build order. By default items without a match are
+/// dropped; after [`left_join`](Self::left_join) they are yielded once,
+/// paired with `None`.
+///
+/// Errors are wrapped in [`EitherError`]: `Left` from the streamed source,
+/// `Right` from the build source. A build error leaves the rows read so far
+/// in the table, and the next pull resumes draining.
+///
+/// ```rust
+/// use try_next::{TryNextExt, from_items};
+///
+/// let orders = from_items([(1, "tea"), (2, "cake"), (1, "jam")]);
+/// let customers = from_items([(1, "ann")]);
+///
+/// let joined: Vec<_> = orders
+///     .hash_join(customers, (|o: &(u32, &str)| o.0, |c: &(u32, &str)| c.0))
+///     .left_join()
+///     .try_collect()
+///     .unwrap();
+/// assert_eq!(
+///     joined,
+///     [
+///         ((1, "tea"), Some((1, "ann"))),
+///         ((2, "cake"), None),
+///         ((1, "jam"), Some((1, "ann"))),
+///     ]
+/// );
+/// ```
+///
+/// Created by [`TryNextExt::hash_join`](crate::TryNextExt::hash_join).
+#[derive(Debug, Clone)]
+pub struct HashJoin<S: TryNext, B: TryNext, K, F, G, J = InnerJoin> {
+    source: S,
+    build: Option<B>,
+    table: HashMap<K, Vec<B::Item>>,
+    probe_key: F,
+    build_key: G,
+    pending: VecDeque<(S::Item, B::Item)>,
+    _join: PhantomData<J>,
+}
+
+impl<S: TryNext, B: TryNext, K, F, G> HashJoin<S, B, K, F, G> {
+    pub(crate) fn new(source: S, build: B, probe_key: F, build_key: G) -> Self {
+        Self {
+            source,
+            build: Some(build),
+            table: HashMap::new(),
+            probe_key,
+            build_key,
+            pending: VecDeque::new(),
+            _join: PhantomData,
+        }
+    }
+
+    /// Also yields items without a match, as `(item, None)`.
+    pub fn left_join(self) -> HashJoin<S, B, K, F, G, LeftJoin> {
+        HashJoin {
+            source: self.source,
+            build: self.build,
+            table: self.table,
+            probe_key: self.probe_key,
+            build_key: self.build_key,
+            pending: self.pending,
+            _join: PhantomData,
+        }
+    }
+}
+
+impl<S: TryNext, B: TryNext, K, F, G, J> HashJoin<S, B, K, F, G, J> {
+    /// Returns the streamed source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: TryNext, B: TryNext, K, F, G, J> HashJoin<S, B, K, F, G, J>
+where
+    S::Item: Clone,
+    B::Item: Clone,
+    K: Hash + Eq,
+    F: FnMut(&S::Item) -> K,
+    G: FnMut(&B::Item) -> K,
+{
+    /// Yields the next match, or an item with no match as `(item, None)`.
+    #[allow(clippy::type_complexity)]
+    fn pull(
+        &mut self,
+    ) -> Result<Option<(S::Item, Option<B::Item>)>, EitherError<S::Error, B::Error>> {
+        if let Some(build) = &mut self.build {
+            while let Some(item) = build.try_next().map_err(EitherError::Right)? {
+                let key = (self.build_key)(&item);
+                self.table.entry(key).or_default().push(item);
+            }
+            self.build = None;
+        }
+        if let Some((item, matched)) = self.pending.pop_front() {
+            return Ok(Some((item, Some(matched))));
+        }
+        let Some(item) = self.source.try_next().map_err(EitherError::Left)? else {
+            return Ok(None);
+        };
+        let Some((first, rest)) = self
+            .table
+            .get(&(self.probe_key)(&item))
+            .and_then(|matches| matches.split_first())
+        else {
+            return Ok(Some((item, None)));
+        };
+        self.pending
+            .extend(rest.iter().map(|matched| (item.clone(), matched.clone())));
+        Ok(Some((item, Some(first.clone()))))
+    }
+}
+
+impl<S: TryNext, B: TryNext, K, F, G> TryNext for HashJoin<S, B, K, F, G, InnerJoin>
+where
+    S::Item: Clone,
+    B::Item: Clone,
+    K: Hash + Eq,
+    F: FnMut(&S::Item) -> K,
+    G: FnMut(&B::Item) -> K,
+{
+    type Item = (S::Item, B::Item);
+    type Error = EitherError<S::Error, B::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        loop {
+            match self.pull()? {
+                Some((item, Some(matched))) => return Ok(Some((item, matched))),
+                Some((_, None)) => {}
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+impl<S: TryNext, B: TryNext, K, F, G> TryNext for HashJoin<S, B, K, F, G, LeftJoin>
+where
+    S::Item: Clone,
+    B::Item: Clone,
+    K: Hash + Eq,
+    F: FnMut(&S::Item) -> K,
+    G: FnMut(&B::Item) -> K,
+{
+    type Item = (S::Item, Option<B::Item>);
+    type Error = EitherError<S::Error, B::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every streamed item is yielded at least once.
+        let (lower, _) = self.source.size_hint();
+        (lower.saturating_add(self.pending.len()), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EitherError, TryNext, TryNextExt, from_items, from_iter};
+
+    fn key(pair: &(u32, char)) -> u32 {
+        pair.0
+    }
+
+    #[test]
+    fn inner_join_pairs_every_match_in_build_order() {
+        let probe = from_items([(1, 'a'), (2, 'b'), (3, 'c')]);
+        let build = from_items([(3, 'x'), (1, 'y'), (3, 'z')]);
+        let out: Vec<_> = probe.hash_join(build, (key, key)).try_collect().unwrap();
+
+        assert_eq!(
+            out,
+            [
+                ((1, 'a'), (1, 'y')),
+                ((3, 'c'), (3, 'x')),
+                ((3, 'c'), (3, 'z'))
+            ]
+        );
+    }
+
+    #[test]
+    fn build_errors_resume_draining() {
+        let probe = from_iter([Ok((1, 'a')), Err("probe"), Ok((2, 'b'))]);
+        let build = from_iter([Ok((2, 'x')), Err("build"), Ok((1, 'y'))]);
+        let mut src = probe.hash_join(build, (key, key)).left_join();
+
+        assert_eq!(src.try_next(), Err(EitherError::Right("build")));
+        assert_eq!(src.try_next(), Ok(Some(((1, 'a'), Some((1, 'y'))))));
+        assert_eq!(src.try_next(), Err(EitherError::Left("probe")));
+        assert_eq!(src.try_next(), Ok(Some(((2, 'b'), Some((2, 'x'))))));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
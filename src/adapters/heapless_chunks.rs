@@ -0,0 +1,109 @@
+use heapless::Vec;
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that groups items into fixed-capacity [`heapless::Vec`]s of `N`
+/// items.
+///
+/// This is the allocation-free counterpart of
+/// [`Chunks`](crate::adapters::Chunks), with the same behavior: the last
+/// chunk may be shorter, and if the inner source fails mid-chunk, the error
+/// is returned and the items gathered so far are kept for the next call.
+///
+/// Available with the `heapless` feature.
+///
+/// Created by [`TryNextExt::heapless_chunks`](crate::TryNextExt::heapless_chunks)
+/// and
+/// [`TryNextWithContextExt::heapless_chunks`](crate::TryNextWithContextExt::heapless_chunks).
+#[derive(Debug, Clone)]
+pub struct HeaplessChunks<S, T, const N: usize> {
+    source: S,
+    buf: Vec<T, N>,
+}
+
+impl<S, T, const N: usize> HeaplessChunks<S, T, N> {
+    pub(crate) fn new(source: S) -> Self {
+        const { assert!(N > 0, "chunk size must be non-zero") };
+        Self {
+            source,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Returns the underlying source, dropping any partially gathered chunk.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn gather(&mut self, item: T) {
+        if self.buf.push(item).is_err() {
+            unreachable!("chunk is emptied once full");
+        }
+    }
+
+    fn finish(&mut self, end: bool) -> Option<Vec<T, N>> {
+        if end && self.buf.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(&mut self.buf))
+        }
+    }
+}
+
+impl<S: TryNext, const N: usize> TryNext for HeaplessChunks<S, S::Item, N> {
+    type Item = Vec<S::Item, N>;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        while !self.buf.is_full() {
+            match self.source.try_next()? {
+                Some(item) => self.gather(item),
+                None => return Ok(self.finish(true)),
+            }
+        }
+        Ok(self.finish(false))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.source.size_hint();
+        let pending = self.buf.len();
+        let chunks = |n: usize| n.saturating_add(pending).div_ceil(N);
+        (chunks(lower), upper.map(chunks))
+    }
+}
+
+impl<S: FusedTryNext, const N: usize> FusedTryNext for HeaplessChunks<S, S::Item, N> {}
+
+impl<S: TryNextWithContext, const N: usize> TryNextWithContext for HeaplessChunks<S, S::Item, N> {
+    type Item = Vec<S::Item, N>;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        while !self.buf.is_full() {
+            match self.source.try_next_with_context(context)? {
+                Some(item) => self.gather(item),
+                None => return Ok(self.finish(true)),
+            }
+        }
+        Ok(self.finish(false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn keeps_partial_chunk_across_errors() {
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2), Ok(3)]).heapless_chunks::<2>();
+
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next().unwrap().unwrap(), [1, 2]);
+        assert_eq!(src.try_next().unwrap().unwrap(), [3]);
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
@@ -0,0 +1,112 @@
+use heapless::Deque;
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that yields only the last `N` items of another source, kept in
+/// a fixed-capacity [`heapless::Deque`].
+///
+/// The first call drains the inner source, keeping a sliding window of its
+/// last `N` items; later calls hand them out in order. If the inner source
+/// fails while being drained, the error is returned and the window is kept,
+/// so the next call resumes draining.
+///
+/// Available with the `heapless` feature.
+///
+/// Created by [`TryNextExt::heapless_tail`](crate::TryNextExt::heapless_tail)
+/// and
+/// [`TryNextWithContextExt::heapless_tail`](crate::TryNextWithContextExt::heapless_tail).
+#[derive(Debug, Clone)]
+pub struct HeaplessTail<S, T, const N: usize> {
+    source: S,
+    window: Deque<T, N>,
+    drained: bool,
+}
+
+impl<S, T, const N: usize> HeaplessTail<S, T, N> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            source,
+            window: Deque::new(),
+            drained: false,
+        }
+    }
+
+    /// Returns the underlying source, dropping any buffered items.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn keep(&mut self, item: T) {
+        if self.window.is_full() {
+            self.window.pop_front();
+        }
+        // N == 0 keeps nothing.
+        let _ = self.window.push_back(item);
+    }
+}
+
+impl<S: TryNext, const N: usize> TryNext for HeaplessTail<S, S::Item, N> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        while !self.drained {
+            match self.source.try_next()? {
+                Some(item) => self.keep(item),
+                None => self.drained = true,
+            }
+        }
+        Ok(self.window.pop_front())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let kept = self.window.len();
+        if self.drained {
+            return (kept, Some(kept));
+        }
+        let (lower, upper) = self.source.size_hint();
+        let window = |n: usize| n.saturating_add(kept).min(N);
+        (window(lower), Some(upper.map_or(N, window)))
+    }
+}
+
+impl<S: TryNext, const N: usize> FusedTryNext for HeaplessTail<S, S::Item, N> {}
+
+impl<S: TryNextWithContext, const N: usize> TryNextWithContext for HeaplessTail<S, S::Item, N> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        while !self.drained {
+            match self.source.try_next_with_context(context)? {
+                Some(item) => self.keep(item),
+                None => self.drained = true,
+            }
+        }
+        Ok(self.window.pop_front())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn yields_the_last_items_after_errors() {
+        let mut src = from_iter([Ok(1), Ok(2), Err('x'), Ok(3)]).heapless_tail::<2>();
+
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.size_hint(), (2, Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+        assert_eq!(src.try_next(), Ok(None));
+
+        let mut short = from_items([1]).heapless_tail::<4>();
+        assert_eq!(short.size_hint(), (1, Some(1)));
+        assert_eq!(short.try_next(), Ok(Some(1)));
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::{TryNext, TryNextWithContext};
+
+/// A [`TryNextWithContext`] that ignores its context and delegates to a
+/// context-free source.
+///
+/// Lets a plain [`TryNext`] be used with APIs that require a context-aware
+/// source, for any context type `C`.
+///
+/// Created by [`TryNextExt::ignore_context`](crate::TryNextExt::ignore_context).
+pub struct IgnoreContext<S, C: ?Sized> {
+    source: S,
+    _context: PhantomData<fn(&mut C)>,
+}
+
+impl<S, C: ?Sized> IgnoreContext<S, C> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            source,
+            _context: PhantomData,
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: Clone, C: ?Sized> Clone for IgnoreContext<S, C> {
+    fn clone(&self) -> Self {
+        Self::new(self.source.clone())
+    }
+}
+
+impl<S: fmt::Debug, C: ?Sized> fmt::Debug for IgnoreContext<S, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IgnoreContext")
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl<S: TryNext, C: ?Sized> TryNextWithContext for IgnoreContext<S, C> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = C;
+
+    fn try_next_with_context(
+        &mut self,
+        _context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNextExt, TryNextWithContext, from_iter};
+
+    fn drain<S>(src: &mut S, ctx: &mut S::Context) -> Result<Vec<S::Item>, S::Error>
+    where
+        S: TryNextWithContext,
+    {
+        let mut out = Vec::new();
+        while let Some(item) = src.try_next_with_context(ctx)? {
+            out.push(item);
+        }
+        Ok(out)
+    }
+
+    #[test]
+    fn context_is_untouched() {
+        let mut src = from_iter([Ok::<_, ()>(1), Ok(2)]).ignore_context::<String>();
+        let mut ctx = String::from("unchanged");
+
+        assert_eq!(drain(&mut src, &mut ctx), Ok(vec![1, 2]));
+        assert_eq!(ctx, "unchanged");
+    }
+
+    #[test]
+    fn works_with_unsized_contexts() {
+        let mut src = from_iter([Err::<u8, _>('e')]).ignore_context::<[u8]>();
+        let ctx: &mut [u8] = &mut [0, 1];
+
+        assert_eq!(src.try_next_with_context(ctx), Err('e'));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{DoubleEndedTryNext, ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that calls a closure with a reference to each item before
+/// yielding it.
+///
+/// Created by [`TryNextExt::inspect`](crate::TryNextExt::inspect) and
+/// [`TryNextWithContextExt::inspect`](crate::TryNextWithContextExt::inspect).
+#[derive(Debug, Clone)]
+pub struct Inspect<S, F> {
+    source: S,
+    f: F,
+}
+
+impl<S, F> Inspect<S, F> {
+    pub(crate) fn new(source: S, f: F) -> Self {
+        Self { source, f }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, F> TryNext for Inspect<S, F>
+where
+    S: TryNext,
+    F: FnMut(&S::Item),
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.source.try_next()?.inspect(&mut self.f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, F> DoubleEndedTryNext for Inspect<S, F>
+where
+    S: DoubleEndedTryNext,
+    F: FnMut(&S::Item),
+{
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.source.try_next_back()?.inspect(&mut self.f))
+    }
+}
+
+impl<S, F> ExactSizeTryNext for Inspect<S, F>
+where
+    S: ExactSizeTryNext,
+    F: FnMut(&S::Item),
+{
+}
+
+impl<S, F> FusedTryNext for Inspect<S, F>
+where
+    S: FusedTryNext,
+    F: FnMut(&S::Item),
+{
+}
+
+impl<S, F> TryNextWithContext for Inspect<S, F>
+where
+    S: TryNextWithContext,
+    F: FnMut(&S::Item),
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self
+            .source
+            .try_next_with_context(context)?
+            .inspect(&mut self.f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::fixtures::Pop;
+    use crate::{TryNext, TryNextExt, TryNextWithContext, TryNextWithContextExt, from_items};
+
+    #[test]
+    fn sees_every_item() {
+        let mut seen = Vec::new();
+        let mut src = from_items([1, 2]).inspect(|n| seen.push(*n));
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(seen, [1, 2]);
+    }
+
+    #[test]
+    fn threads_the_context() {
+        let mut seen = Vec::new();
+        let mut src = Pop.inspect(|n| seen.push(*n));
+        let mut stack = vec![1, 2];
+
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(Some(2)));
+        assert_eq!(seen, [2]);
+        assert_eq!(stack, [1]);
+    }
+}
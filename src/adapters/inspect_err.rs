@@ -0,0 +1,110 @@
+use std::num::NonZeroUsize;
+
+use crate::{DoubleEndedTryNext, ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that calls a closure with a reference to each error before
+/// returning it.
+///
+/// Created by [`TryNextExt::inspect_err`](crate::TryNextExt::inspect_err) and
+/// [`TryNextWithContextExt::inspect_err`](crate::TryNextWithContextExt::inspect_err).
+#[derive(Debug, Clone)]
+pub struct InspectErr<S, F> {
+    source: S,
+    f: F,
+}
+
+impl<S, F> InspectErr<S, F> {
+    pub(crate) fn new(source: S, f: F) -> Self {
+        Self { source, f }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, F> TryNext for InspectErr<S, F>
+where
+    S: TryNext,
+    F: FnMut(&S::Error),
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next().inspect_err(&mut self.f)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        self.source.try_next_chunk(max).inspect_err(&mut self.f)
+    }
+
+    fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        self.source.try_advance_by(n).inspect_err(&mut self.f)
+    }
+}
+
+impl<S, F> DoubleEndedTryNext for InspectErr<S, F>
+where
+    S: DoubleEndedTryNext,
+    F: FnMut(&S::Error),
+{
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next_back().inspect_err(&mut self.f)
+    }
+}
+
+impl<S, F> ExactSizeTryNext for InspectErr<S, F>
+where
+    S: ExactSizeTryNext,
+    F: FnMut(&S::Error),
+{
+}
+
+impl<S, F> FusedTryNext for InspectErr<S, F>
+where
+    S: FusedTryNext,
+    F: FnMut(&S::Error),
+{
+}
+
+impl<S, F> TryNextWithContext for InspectErr<S, F>
+where
+    S: TryNextWithContext,
+    F: FnMut(&S::Error),
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.source
+            .try_next_with_context(context)
+            .inspect_err(&mut self.f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn sees_errors_and_passes_them_on() {
+        let mut failures = 0;
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2)]).inspect_err(|_| failures += 1);
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(failures, 1);
+    }
+}
@@ -0,0 +1,250 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::{ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext};
+
+/// One latency bucket per power of two of nanoseconds, plus one for zero.
+const BUCKETS: usize = 65;
+
+/// Counters and latency statistics collected by an [`Instrumented`] source.
+///
+/// Obtained from [`Instrumented::stats`] or [`StatsHandle::snapshot`].
+/// Latencies are those of the inner source's calls, including failed ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+    /// The number of items yielded.
+    pub items: u64,
+    /// The number of errors returned.
+    pub errors: u64,
+    /// The number of `Ok(None)` results returned.
+    pub ends: u64,
+    /// The total time spent in the inner source.
+    pub total_latency: Duration,
+    /// The slowest single call.
+    pub max_latency: Duration,
+    buckets: [u64; BUCKETS],
+}
+
+impl Stats {
+    /// Returns the number of calls made, i.e. items, errors, and ends.
+    pub fn calls(&self) -> u64 {
+        self.items + self.errors + self.ends
+    }
+
+    /// Returns the mean latency of a call, or `None` before the first call.
+    pub fn mean_latency(&self) -> Option<Duration> {
+        let calls = u32::try_from(self.calls()).unwrap_or(u32::MAX);
+        (calls > 0).then(|| self.total_latency / calls)
+    }
+
+    /// Returns an upper bound on the `p`th percentile latency, or `None`
+    /// before the first call.
+    ///
+    /// Latencies are kept in power-of-two buckets, so the result is at most
+    /// twice the true percentile; it never exceeds
+    /// [`max_latency`](Self::max_latency).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is outside `0.0..=100.0`.
+    pub fn latency_percentile(&self, p: f64) -> Option<Duration> {
+        assert!(
+            (0.0..=100.0).contains(&p),
+            "percentile {p} is outside 0.0..=100.0"
+        );
+        let calls = self.calls();
+        if calls == 0 {
+            return None;
+        }
+        let rank = ((p / 100.0 * calls as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        let bucket = self
+            .buckets
+            .iter()
+            .position(|&count| {
+                seen += count;
+                seen >= rank
+            })
+            .unwrap_or(BUCKETS - 1);
+        let upper = Duration::from_nanos(1u64.checked_shl(bucket as u32).unwrap_or(u64::MAX));
+        Some(upper.min(self.max_latency))
+    }
+
+    fn record<T, E>(&mut self, result: &Result<Option<T>, E>, latency: Duration) {
+        match result {
+            Ok(Some(_)) => self.items += 1,
+            Ok(None) => self.ends += 1,
+            Err(_) => self.errors += 1,
+        }
+        self.total_latency += latency;
+        self.max_latency = self.max_latency.max(latency);
+        let nanos = u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX);
+        self.buckets[(u64::BITS - nanos.leading_zeros()) as usize] += 1;
+    }
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            items: 0,
+            errors: 0,
+            ends: 0,
+            total_latency: Duration::ZERO,
+            max_latency: Duration::ZERO,
+            buckets: [0; BUCKETS],
+        }
+    }
+}
+
+/// A shared handle to the statistics of an [`Instrumented`] source.
+///
+/// Handles are cheap to clone and can be read from any thread while the
+/// source is in use, e.g. by a metrics exporter.
+#[derive(Debug, Clone, Default)]
+pub struct StatsHandle {
+    stats: Arc<Mutex<Stats>>,
+}
+
+impl StatsHandle {
+    /// Returns a copy of the statistics collected so far.
+    pub fn snapshot(&self) -> Stats {
+        self.lock().clone()
+    }
+
+    /// Returns the statistics collected so far and resets them.
+    pub fn reset(&self) -> Stats {
+        std::mem::take(&mut *self.lock())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Stats> {
+        // Stats are updated in single steps, so a poisoned lock still holds
+        // consistent counters.
+        self.stats.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// A source that counts items, errors, and ends, and times every call to
+/// its inner source.
+///
+/// Bulk methods fall back to per-item calls, so each pull is timed
+/// individually.
+///
+/// ```rust
+/// use try_next::{TryNext, TryNextExt, from_iter};
+///
+/// let mut src = from_iter([Ok(1), Err("bad"), Ok(2)]).instrument();
+/// let handle = src.handle();
+/// while src.try_next() != Ok(None) {}
+///
+/// let stats = handle.snapshot();
+/// assert_eq!((stats.items, stats.errors, stats.ends), (2, 1, 1));
+/// assert!(stats.latency_percentile(99.0) <= Some(stats.max_latency));
+/// ```
+///
+/// Created by [`TryNextExt::instrument`](crate::TryNextExt::instrument) and
+/// [`TryNextWithContextExt::instrument`](crate::TryNextWithContextExt::instrument).
+#[derive(Debug, Clone)]
+pub struct Instrumented<S> {
+    source: S,
+    handle: StatsHandle,
+}
+
+impl<S> Instrumented<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            source,
+            handle: StatsHandle::default(),
+        }
+    }
+
+    /// Returns a copy of the statistics collected so far.
+    pub fn stats(&self) -> Stats {
+        self.handle.snapshot()
+    }
+
+    /// Returns a shared handle to the statistics, which stays readable after
+    /// the source has been moved or dropped.
+    pub fn handle(&self) -> StatsHandle {
+        self.handle.clone()
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: TryNext> TryNext for Instrumented<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let start = Instant::now();
+        let result = self.source.try_next();
+        self.handle.lock().record(&result, start.elapsed());
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S: ExactSizeTryNext> ExactSizeTryNext for Instrumented<S> {}
+
+impl<S: FusedTryNext> FusedTryNext for Instrumented<S> {}
+
+impl<S: TryNextWithContext> TryNextWithContext for Instrumented<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let start = Instant::now();
+        let result = self.source.try_next_with_context(context);
+        self.handle.lock().record(&result, start.elapsed());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use super::Stats;
+    use crate::{TryNext, TryNextExt, from_items};
+
+    #[test]
+    fn percentiles_are_bucket_upper_bounds() {
+        let mut stats = Stats::default();
+        for micros in [1, 1, 1, 1, 1, 1, 1, 1, 1, 500] {
+            stats.record(&Ok::<_, ()>(Some(())), Duration::from_micros(micros));
+        }
+
+        assert_eq!(
+            stats.latency_percentile(50.0),
+            Some(Duration::from_nanos(1024))
+        );
+        assert_eq!(
+            stats.latency_percentile(100.0),
+            Some(Duration::from_micros(500))
+        );
+        assert_eq!(Stats::default().latency_percentile(50.0), None);
+    }
+
+    #[test]
+    fn handle_outlives_the_source_and_resets() {
+        let mut src = from_items([1, 2]).instrument();
+        let handle = src.handle();
+        thread::spawn(move || while src.try_next().unwrap().is_some() {})
+            .join()
+            .unwrap();
+
+        assert_eq!(handle.reset().calls(), 3);
+        assert_eq!(handle.snapshot(), Stats::default());
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{EitherError, TryNextWithContext};
+
+/// A source that alternates between the items of `A` and `B`.
+///
+/// Starts with `A`. Once either side ends, the remaining items of the other
+/// side are yielded in order.
+///
+/// As a [`TryNextWithContext`], the combined context is the pair
+/// `(A::Context, B::Context)` and the combined error is
+/// [`EitherError<A::Error, B::Error>`](EitherError).
+///
+/// Created by
+/// [`TryNextWithContextExt::interleave`](crate::TryNextWithContextExt::interleave).
+#[derive(Debug, Clone)]
+pub struct Interleave<A, B> {
+    first: A,
+    second: B,
+    first_done: bool,
+    second_done: bool,
+    second_next: bool,
+}
+
+impl<A, B> Interleave<A, B> {
+    pub(crate) fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            first_done: false,
+            second_done: false,
+            second_next: false,
+        }
+    }
+
+    /// Returns the two underlying sources.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A, B> TryNextWithContext for Interleave<A, B>
+where
+    A: TryNextWithContext<Context: Sized>,
+    B: TryNextWithContext<Item = A::Item, Context: Sized>,
+{
+    type Item = A::Item;
+    type Error = EitherError<A::Error, B::Error>;
+    type Context = (A::Context, B::Context);
+
+    fn try_next_with_context(
+        &mut self,
+        (first, second): &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        for _ in 0..2 {
+            let use_second = (self.second_next || self.first_done) && !self.second_done;
+            if use_second {
+                self.second_next = false;
+                match self
+                    .second
+                    .try_next_with_context(second)
+                    .map_err(EitherError::Right)?
+                {
+                    Some(item) => return Ok(Some(item)),
+                    None => self.second_done = true,
+                }
+            } else if !self.first_done {
+                self.second_next = true;
+                match self
+                    .first
+                    .try_next_with_context(first)
+                    .map_err(EitherError::Left)?
+                {
+                    Some(item) => return Ok(Some(item)),
+                    None => self.first_done = true,
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TryNextWithContextExt;
+    use crate::adapters::fixtures::{Pop, Upto, drain};
+
+    #[test]
+    fn alternates_then_drains_the_longer_side() {
+        let mut src = Pop.interleave(Upto(0));
+        let mut ctx = (vec![10], 3);
+
+        assert_eq!(drain(&mut src, &mut ctx), [10, 1, 2, 3]);
+
+        let mut src = Upto(0).interleave(Pop);
+        let mut ctx = (1, vec![30, 20, 10]);
+
+        assert_eq!(drain(&mut src, &mut ctx), [1, 10, 20, 30]);
+    }
+}
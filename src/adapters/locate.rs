@@ -0,0 +1,146 @@
+use crate::{Located, TryNext, TryNextWithContext};
+
+/// A source that wraps errors in [`Located`], recording how many items were
+/// produced before each one.
+///
+/// Errors do not advance the index, so a source that recovers after an error
+/// reports the next failure at the index of the item it is still trying to
+/// produce.
+///
+/// Created by [`TryNextExt::locate`](crate::TryNextExt::locate),
+/// [`TryNextExt::locate_bytes`](crate::TryNextExt::locate_bytes), and their
+/// [`TryNextWithContextExt`](crate::TryNextWithContextExt) counterparts.
+#[derive(Debug, Clone)]
+pub struct Locate<S> {
+    source: S,
+    index: u64,
+    offset: fn(&S) -> Option<u64>,
+}
+
+impl<S> Locate<S> {
+    pub(crate) fn new(source: S, offset: fn(&S) -> Option<u64>) -> Self {
+        Self {
+            source,
+            index: 0,
+            offset,
+        }
+    }
+
+    /// Returns the number of items produced so far.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn locate<T, E>(&mut self, result: Result<Option<T>, E>) -> Result<Option<T>, Located<E>> {
+        match result {
+            Ok(item) => {
+                self.index += item.is_some() as u64;
+                Ok(item)
+            }
+            Err(error) => Err(Located {
+                index: self.index,
+                byte_offset: (self.offset)(&self.source),
+                error,
+            }),
+        }
+    }
+}
+
+impl<S: TryNext> TryNext for Locate<S> {
+    type Item = S::Item;
+    type Error = Located<S::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next();
+        self.locate(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S: TryNextWithContext> TryNextWithContext for Locate<S> {
+    type Item = S::Item;
+    type Error = Located<S::Error>;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next_with_context(context);
+        self.locate(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ByteOffset, Located, TryNext, TryNextExt};
+
+    /// Splits its input into comma-separated numbers.
+    struct Numbers {
+        input: &'static str,
+        pos: usize,
+    }
+
+    impl TryNext for Numbers {
+        type Item = u32;
+        type Error = &'static str;
+
+        fn try_next(&mut self) -> Result<Option<u32>, &'static str> {
+            let rest = &self.input[self.pos..];
+            if rest.is_empty() {
+                return Ok(None);
+            }
+            let field = rest.split(',').next().unwrap();
+            self.pos += field.len() + (field.len() < rest.len()) as usize;
+            field.parse().map(Some).map_err(|_| "not a number")
+        }
+    }
+
+    impl ByteOffset for Numbers {
+        fn byte_offset(&self) -> u64 {
+            self.pos as u64
+        }
+    }
+
+    #[test]
+    fn counts_items_before_the_error() {
+        let mut src = Numbers {
+            input: "1,2,x,4",
+            pos: 0,
+        }
+        .locate();
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(
+            src.try_next(),
+            Err(Located {
+                index: 2,
+                byte_offset: None,
+                error: "not a number"
+            })
+        );
+        assert_eq!(src.try_next(), Ok(Some(4)));
+        assert_eq!(src.index(), 3);
+    }
+
+    #[test]
+    fn reports_byte_offsets() {
+        let mut src = Numbers {
+            input: "10,x",
+            pos: 0,
+        }
+        .locate_bytes();
+
+        assert_eq!(src.try_next(), Ok(Some(10)));
+        assert_eq!(src.try_next().unwrap_err().byte_offset, Some(4));
+    }
+}
@@ -0,0 +1,205 @@
+use std::fmt::{Debug, Display};
+
+use log::Level;
+
+use crate::{TryNext, TryNextWithContext};
+
+/// A source that logs errors, termination, and optionally items through the
+/// [`log`] facade.
+///
+/// Messages are prefixed with the name given at creation and carry the
+/// zero-based call index. By default errors are logged at `Warn`, since a
+/// downstream adapter may still recover from them, and the end of the
+/// sequence at `Debug`. Items are not logged unless
+/// [`with_items`](Self::with_items) supplies a formatter.
+///
+/// Available with the `log` feature.
+///
+/// ```rust
+/// use log::Level;
+/// use try_next::{TryNext, TryNextExt, from_iter};
+///
+/// let mut src = from_iter([Ok((7, "a")), Err("bad")])
+///     .logged("ingest")
+///     .with_error_level(Level::Error)
+///     .with_items(Level::Debug, |&(id, _)| id);
+///
+/// assert_eq!(src.try_next(), Ok(Some((7, "a"))));
+/// assert_eq!(src.try_next(), Err("bad"));
+/// ```
+///
+/// Created by [`TryNextExt::logged`](crate::TryNextExt::logged) and
+/// [`TryNextWithContextExt::logged`](crate::TryNextWithContextExt::logged).
+#[derive(Debug, Clone)]
+pub struct Logged<S, F> {
+    source: S,
+    name: &'static str,
+    format: Option<F>,
+    item_level: Level,
+    error_level: Level,
+    end_level: Level,
+    calls: u64,
+}
+
+impl<S, F> Logged<S, F> {
+    pub(crate) fn new(source: S, name: &'static str) -> Self {
+        Self {
+            source,
+            name,
+            format: None,
+            item_level: Level::Trace,
+            error_level: Level::Warn,
+            end_level: Level::Debug,
+            calls: 0,
+        }
+    }
+
+    /// Sets the level errors are logged at.
+    pub fn with_error_level(mut self, level: Level) -> Self {
+        self.error_level = level;
+        self
+    }
+
+    /// Sets the level each `Ok(None)` is logged at.
+    pub fn with_end_level(mut self, level: Level) -> Self {
+        self.end_level = level;
+        self
+    }
+
+    /// Logs each item at `level`, formatted by `format`.
+    pub fn with_items<G, T, D>(self, level: Level, format: G) -> Logged<S, G>
+    where
+        G: FnMut(&T) -> D,
+    {
+        Logged {
+            source: self.source,
+            name: self.name,
+            format: Some(format),
+            item_level: level,
+            error_level: self.error_level,
+            end_level: self.end_level,
+            calls: self.calls,
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn log<T, E, D>(&mut self, result: &Result<Option<T>, E>)
+    where
+        E: Debug,
+        F: FnMut(&T) -> D,
+        D: Display,
+    {
+        let call = self.calls;
+        self.calls += 1;
+        let name = self.name;
+        match result {
+            Ok(Some(item)) => {
+                if let Some(format) = &mut self.format {
+                    log::log!(
+                        self.item_level,
+                        "{name}: item at call {call}: {}",
+                        format(item)
+                    );
+                }
+            }
+            Ok(None) => log::log!(self.end_level, "{name}: ended at call {call}"),
+            Err(error) => log::log!(self.error_level, "{name}: failed at call {call}: {error:?}"),
+        }
+    }
+}
+
+impl<S, F, D> TryNext for Logged<S, F>
+where
+    S: TryNext,
+    S::Error: Debug,
+    F: FnMut(&S::Item) -> D,
+    D: Display,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next();
+        self.log(&result);
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, F, D> TryNextWithContext for Logged<S, F>
+where
+    S: TryNextWithContext,
+    S::Error: Debug,
+    F: FnMut(&S::Item) -> D,
+    D: Display,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next_with_context(context);
+        self.log(&result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    /// Keeps the records of the `logged-test` source.
+    struct Capture(Mutex<Vec<String>>);
+
+    impl Log for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            let message = record.args().to_string();
+            if message.starts_with("logged-test:") {
+                let line = format!("{} {message}", record.level());
+                self.0.lock().unwrap().push(line);
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+
+    #[test]
+    fn logs_items_errors_and_end() {
+        log::set_logger(&CAPTURE).unwrap();
+        log::set_max_level(LevelFilter::Trace);
+
+        let mut src = from_iter([Ok(3), Err('x')])
+            .logged("logged-test")
+            .with_items(Level::Info, |n| n * 2);
+        while src.try_next() != Ok(None) {}
+
+        assert_eq!(
+            *CAPTURE.0.lock().unwrap(),
+            [
+                "INFO logged-test: item at call 0: 6",
+                "WARN logged-test: failed at call 1: 'x'",
+                "DEBUG logged-test: ended at call 2",
+            ]
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{DoubleEndedTryNext, ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that transforms each item with a closure.
+///
+/// Created by [`TryNextExt::map`](crate::TryNextExt::map) and
+/// [`TryNextWithContextExt::map`](crate::TryNextWithContextExt::map).
+#[derive(Debug, Clone)]
+pub struct Map<S, F> {
+    source: S,
+    f: F,
+}
+
+impl<S, F> Map<S, F> {
+    pub(crate) fn new(source: S, f: F) -> Self {
+        Self { source, f }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, F, T> TryNext for Map<S, F>
+where
+    S: TryNext,
+    F: FnMut(S::Item) -> T,
+{
+    type Item = T;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.source.try_next()?.map(&mut self.f))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, F, T> DoubleEndedTryNext for Map<S, F>
+where
+    S: DoubleEndedTryNext,
+    F: FnMut(S::Item) -> T,
+{
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.source.try_next_back()?.map(&mut self.f))
+    }
+}
+
+impl<S, F, T> ExactSizeTryNext for Map<S, F>
+where
+    S: ExactSizeTryNext,
+    F: FnMut(S::Item) -> T,
+{
+}
+
+impl<S, F, T> FusedTryNext for Map<S, F>
+where
+    S: FusedTryNext,
+    F: FnMut(S::Item) -> T,
+{
+}
+
+impl<S, F, T> TryNextWithContext for Map<S, F>
+where
+    S: TryNextWithContext,
+    F: FnMut(S::Item) -> T,
+{
+    type Item = T;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.source.try_next_with_context(context)?.map(&mut self.f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::fixtures::Pop;
+    use crate::{TryNext, TryNextExt, TryNextWithContext, TryNextWithContextExt, from_items};
+
+    #[test]
+    fn maps_both_ends() {
+        let mut src = from_items([1, 2, 3]).map(|n| n * 2);
+
+        assert_eq!(src.size_hint(), (3, Some(3)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(
+            crate::DoubleEndedTryNext::try_next_back(&mut src),
+            Ok(Some(6))
+        );
+        assert_eq!(src.try_next(), Ok(Some(4)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn threads_the_context() {
+        let mut src = Pop.map(|n| n.to_string());
+        let mut stack = vec![1, 2];
+
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(Some("2".into())));
+        assert_eq!(stack, [1]);
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::TryNextWithContext;
+
+/// A [`TryNextWithContext`] that runs a source under a larger context.
+///
+/// A projection closure `FnMut(&mut B) -> &mut S::Context` picks the part of
+/// the outer context `B` that the inner source needs. This lets sources with
+/// different context requirements be composed under one application context
+/// without the sources knowing about it.
+///
+/// Created by
+/// [`TryNextWithContextExt::map_context`](crate::TryNextWithContextExt::map_context).
+pub struct MapContext<S, F, B: ?Sized> {
+    source: S,
+    project: F,
+    _context: PhantomData<fn(&mut B)>,
+}
+
+impl<S, F, B: ?Sized> MapContext<S, F, B> {
+    pub(crate) fn new(source: S, project: F) -> Self {
+        Self {
+            source,
+            project,
+            _context: PhantomData,
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: Clone, F: Clone, B: ?Sized> Clone for MapContext<S, F, B> {
+    fn clone(&self) -> Self {
+        Self::new(self.source.clone(), self.project.clone())
+    }
+}
+
+impl<S: fmt::Debug, F, B: ?Sized> fmt::Debug for MapContext<S, F, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapContext")
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, F, B> TryNextWithContext for MapContext<S, F, B>
+where
+    S: TryNextWithContext,
+    F: FnMut(&mut B) -> &mut S::Context,
+    B: ?Sized,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = B;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next_with_context((self.project)(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNextWithContext, TryNextWithContextExt};
+    use std::convert::Infallible;
+
+    struct App {
+        counter: u32,
+        names: Vec<&'static str>,
+    }
+
+    struct Count;
+
+    impl TryNextWithContext for Count {
+        type Item = u32;
+        type Error = Infallible;
+        type Context = u32;
+
+        fn try_next_with_context(&mut self, n: &mut u32) -> Result<Option<u32>, Infallible> {
+            *n += 1;
+            Ok(Some(*n))
+        }
+    }
+
+    struct Names;
+
+    impl TryNextWithContext for Names {
+        type Item = &'static str;
+        type Error = Infallible;
+        type Context = Vec<&'static str>;
+
+        fn try_next_with_context(
+            &mut self,
+            names: &mut Vec<&'static str>,
+        ) -> Result<Option<Self::Item>, Infallible> {
+            Ok(names.pop())
+        }
+    }
+
+    #[test]
+    fn sources_with_different_contexts_share_one_app_context() {
+        let mut count = Count.map_context(|app: &mut App| &mut app.counter);
+        let mut names = Names.map_context(|app: &mut App| &mut app.names);
+        let mut app = App {
+            counter: 0,
+            names: vec!["b", "a"],
+        };
+
+        assert_eq!(count.try_next_with_context(&mut app), Ok(Some(1)));
+        assert_eq!(names.try_next_with_context(&mut app), Ok(Some("a")));
+        assert_eq!(count.try_next_with_context(&mut app), Ok(Some(2)));
+        assert_eq!(app.counter, 2);
+        assert_eq!(app.names, ["b"]);
+    }
+}
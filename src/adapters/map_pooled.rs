@@ -0,0 +1,90 @@
+use std::marker::PhantomData;
+
+use crate::{Pool, Pooled, Recycle, TryNextWithContext};
+
+/// A source that writes each item into a buffer taken from a [`Pool`] in the
+/// context.
+///
+/// For every item, a recycled buffer is taken from the pool found through
+/// the context's [`AsRef<Pool<T>>`] implementation, the closure fills it,
+/// and the buffer is yielded as a [`Pooled`] guard that goes back to the
+/// pool once the consumer drops it.
+///
+/// Created by [`TryNextWithContextExt::map_pooled`](crate::TryNextWithContextExt::map_pooled).
+#[derive(Debug, Clone)]
+pub struct MapPooled<S, F, T> {
+    source: S,
+    f: F,
+    _buffer: PhantomData<fn() -> T>,
+}
+
+impl<S, F, T> MapPooled<S, F, T> {
+    pub(crate) fn new(source: S, f: F) -> Self {
+        Self {
+            source,
+            f,
+            _buffer: PhantomData,
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, F, T> TryNextWithContext for MapPooled<S, F, T>
+where
+    S: TryNextWithContext<Context: AsRef<Pool<T>>>,
+    F: FnMut(&mut T, S::Item),
+    T: Default + Recycle,
+{
+    type Item = Pooled<T>;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(item) = self.source.try_next_with_context(context)? else {
+            return Ok(None);
+        };
+        let mut buf = (*context).as_ref().take();
+        (self.f)(&mut buf, item);
+        Ok(Some(buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Pool, TryNextExt, TryNextWithContext, TryNextWithContextExt, from_items};
+
+    struct Decoder {
+        pool: Pool<Vec<u8>>,
+    }
+
+    impl AsRef<Pool<Vec<u8>>> for Decoder {
+        fn as_ref(&self) -> &Pool<Vec<u8>> {
+            &self.pool
+        }
+    }
+
+    #[test]
+    fn reuses_buffers_dropped_by_the_consumer() {
+        let mut src = from_items(["abc", "de"])
+            .ignore_context::<Decoder>()
+            .map_pooled(|buf: &mut Vec<u8>, s: &str| buf.extend_from_slice(s.as_bytes()));
+        let mut ctx = Decoder { pool: Pool::new(4) };
+
+        let first = src.try_next_with_context(&mut ctx).unwrap().unwrap();
+        assert_eq!(*first, b"abc");
+        let ptr = first.as_ptr();
+        drop(first);
+        assert_eq!(ctx.pool.idle(), 1);
+
+        let second = src.try_next_with_context(&mut ctx).unwrap().unwrap();
+        assert_eq!(*second, b"de");
+        assert_eq!(second.as_ptr(), ptr);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::TryNextWithContext;
+
+/// A source that transforms each item with a closure that also receives the
+/// context.
+///
+/// The context is lent to the inner source first and then to the closure, so
+/// the closure may consult or update the same state the source uses.
+///
+/// Created by [`TryNextWithContextExt::map_with_context`](crate::TryNextWithContextExt::map_with_context).
+#[derive(Debug, Clone)]
+pub struct MapWithContext<S, F> {
+    source: S,
+    f: F,
+}
+
+impl<S, F> MapWithContext<S, F> {
+    pub(crate) fn new(source: S, f: F) -> Self {
+        Self { source, f }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, F, T> TryNextWithContext for MapWithContext<S, F>
+where
+    S: TryNextWithContext,
+    F: FnMut(&mut S::Context, S::Item) -> T,
+{
+    type Item = T;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self
+            .source
+            .try_next_with_context(context)?
+            .map(|item| (self.f)(context, item)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNextExt, TryNextWithContext, TryNextWithContextExt, from_items};
+    use std::collections::HashMap;
+
+    #[test]
+    fn interns_through_the_context() {
+        let mut src = from_items(["a", "b", "a"])
+            .ignore_context::<HashMap<&str, usize>>()
+            .map_with_context(|symbols, name| {
+                let next = symbols.len();
+                *symbols.entry(name).or_insert(next)
+            });
+        let mut symbols = HashMap::new();
+
+        assert_eq!(src.try_next_with_context(&mut symbols), Ok(Some(0)));
+        assert_eq!(src.try_next_with_context(&mut symbols), Ok(Some(1)));
+        assert_eq!(src.try_next_with_context(&mut symbols), Ok(Some(0)));
+        assert_eq!(src.try_next_with_context(&mut symbols), Ok(None));
+        assert_eq!(symbols.len(), 2);
+    }
+}
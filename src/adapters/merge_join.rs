@@ -0,0 +1,256 @@
+use std::cmp::Ordering;
+
+use crate::{EitherError, TryNext, TryNextWithContext};
+
+/// An item of a [`MergeJoinBy`]: a key present on the left, the right, or
+/// both sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EitherOrBoth<L, R> {
+    /// Only the left source had this key.
+    Left(L),
+    /// Only the right source had this key.
+    Right(R),
+    /// Both sources had this key.
+    Both(L, R),
+}
+
+impl<L, R> EitherOrBoth<L, R> {
+    /// Returns the left item, if there is one.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Self::Left(l) | Self::Both(l, _) => Some(l),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns the right item, if there is one.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Self::Right(r) | Self::Both(_, r) => Some(r),
+            Self::Left(_) => None,
+        }
+    }
+
+    /// Returns both items if both sides matched.
+    pub fn both(self) -> Option<(L, R)> {
+        match self {
+            Self::Both(l, r) => Some((l, r)),
+            _ => None,
+        }
+    }
+}
+
+/// A sort-merge join of two sources ordered by the same key.
+///
+/// Each call compares the next unmatched item of each side with `cmp`: the
+/// smaller one is yielded alone as [`Left`](EitherOrBoth::Left) or
+/// [`Right`](EitherOrBoth::Right), and equal items are yielded together as
+/// [`Both`](EitherOrBoth::Both). Once one side ends, the rest of the other
+/// follows alone. Items are matched one-to-one, so a key repeated on both
+/// sides pairs up in order and any extra repeats come out alone.
+///
+/// Both sources must be sorted ascending by `cmp`; this is not checked.
+/// Errors are wrapped in [`EitherError`] to tell the sides apart, and an
+/// item already pulled from the other side is kept for the next call.
+///
+/// ```rust
+/// use try_next::adapters::EitherOrBoth::{Both, Left, Right};
+/// use try_next::{TryNextExt, from_items};
+///
+/// let ledger = from_items([(1, 100), (2, 250), (4, 75)]);
+/// let bank = from_items([(2, 250), (3, 10), (4, 80)]);
+/// let report: Vec<_> = ledger
+///     .merge_join_by(bank, |a, b| a.0.cmp(&b.0))
+///     .try_collect()
+///     .unwrap();
+///
+/// assert_eq!(
+///     report,
+///     [
+///         Left((1, 100)),
+///         Both((2, 250), (2, 250)),
+///         Right((3, 10)),
+///         Both((4, 75), (4, 80)),
+///     ]
+/// );
+/// ```
+///
+/// As a [`TryNextWithContext`], the combined context is the pair
+/// `(A::Context, B::Context)`.
+///
+/// Created by [`TryNextExt::merge_join_by`](crate::TryNextExt::merge_join_by)
+/// and [`TryNextWithContextExt::merge_join_by`](crate::TryNextWithContextExt::merge_join_by).
+#[derive(Debug, Clone)]
+pub struct MergeJoinBy<A, B, L, R, F> {
+    first: A,
+    second: B,
+    cmp: F,
+    left: Option<L>,
+    right: Option<R>,
+    first_done: bool,
+    second_done: bool,
+}
+
+impl<A, B, L, R, F> MergeJoinBy<A, B, L, R, F> {
+    pub(crate) fn new(first: A, second: B, cmp: F) -> Self {
+        Self {
+            first,
+            second,
+            cmp,
+            left: None,
+            right: None,
+            first_done: false,
+            second_done: false,
+        }
+    }
+
+    /// Returns the two underlying sources.
+    ///
+    /// Items pulled but not yet yielded are discarded.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+
+    fn pull<EA, EB>(
+        &mut self,
+        next_first: impl FnOnce(&mut A) -> Result<Option<L>, EA>,
+        next_second: impl FnOnce(&mut B) -> Result<Option<R>, EB>,
+    ) -> Result<Option<EitherOrBoth<L, R>>, EitherError<EA, EB>>
+    where
+        F: FnMut(&L, &R) -> Ordering,
+    {
+        if self.left.is_none() && !self.first_done {
+            self.left = next_first(&mut self.first).map_err(EitherError::Left)?;
+            self.first_done = self.left.is_none();
+        }
+        if self.right.is_none() && !self.second_done {
+            self.right = next_second(&mut self.second).map_err(EitherError::Right)?;
+            self.second_done = self.right.is_none();
+        }
+        Ok(match (self.left.take(), self.right.take()) {
+            (Some(l), Some(r)) => match (self.cmp)(&l, &r) {
+                Ordering::Less => {
+                    self.right = Some(r);
+                    Some(EitherOrBoth::Left(l))
+                }
+                Ordering::Greater => {
+                    self.left = Some(l);
+                    Some(EitherOrBoth::Right(r))
+                }
+                Ordering::Equal => Some(EitherOrBoth::Both(l, r)),
+            },
+            (Some(l), None) => Some(EitherOrBoth::Left(l)),
+            (None, Some(r)) => Some(EitherOrBoth::Right(r)),
+            (None, None) => None,
+        })
+    }
+}
+
+impl<A, B, F> TryNext for MergeJoinBy<A, B, A::Item, B::Item, F>
+where
+    A: TryNext,
+    B: TryNext,
+    F: FnMut(&A::Item, &B::Item) -> Ordering,
+{
+    type Item = EitherOrBoth<A::Item, B::Item>;
+    type Error = EitherError<A::Error, B::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(A::try_next, B::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let side = |done: bool, buffered: bool, (lo, hi): (usize, Option<usize>)| {
+            if done {
+                (0, Some(0))
+            } else {
+                let extra = usize::from(buffered);
+                (
+                    lo.saturating_add(extra),
+                    hi.and_then(|n| n.checked_add(extra)),
+                )
+            }
+        };
+        let (a_lo, a_hi) = side(self.first_done, self.left.is_some(), self.first.size_hint());
+        let (b_lo, b_hi) = side(
+            self.second_done,
+            self.right.is_some(),
+            self.second.size_hint(),
+        );
+        let hi = match (a_hi, b_hi) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lo.max(b_lo), hi)
+    }
+}
+
+impl<A, B, F> TryNextWithContext for MergeJoinBy<A, B, A::Item, B::Item, F>
+where
+    A: TryNextWithContext<Context: Sized>,
+    B: TryNextWithContext<Context: Sized>,
+    F: FnMut(&A::Item, &B::Item) -> Ordering,
+{
+    type Item = EitherOrBoth<A::Item, B::Item>;
+    type Error = EitherError<A::Error, B::Error>;
+    type Context = (A::Context, B::Context);
+
+    fn try_next_with_context(
+        &mut self,
+        (first, second): &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(
+            |a| a.try_next_with_context(first),
+            |b| b.try_next_with_context(second),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EitherOrBoth::{Both, Left, Right};
+    use crate::{EitherError, TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn repeated_keys_pair_up_in_order() {
+        let a = from_items([1, 1, 1, 3]);
+        let b = from_items([1, 1, 2, 3, 3]);
+        let out: Vec<_> = a.merge_join_by(b, |a, b| a.cmp(b)).try_collect().unwrap();
+
+        assert_eq!(
+            out,
+            [
+                Both(1, 1),
+                Both(1, 1),
+                Left(1),
+                Right(2),
+                Both(3, 3),
+                Right(3)
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_keep_the_other_sides_item() {
+        let a = from_iter([Ok(1), Err('a'), Ok(2)]);
+        let b = from_iter([Ok(2), Err("b")]);
+        let mut src = a.merge_join_by(b, |a, b| a.cmp(b));
+
+        assert_eq!(src.size_hint(), (0, Some(5)));
+        assert_eq!(src.try_next(), Ok(Some(Left(1))));
+        assert_eq!(src.try_next(), Err(EitherError::Left('a')));
+        assert_eq!(src.try_next(), Ok(Some(Both(2, 2))));
+        assert_eq!(src.try_next(), Err(EitherError::Right("b")));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn size_hint_counts_buffered_items() {
+        let mut src = from_items([1, 2])
+            .merge_join_by(from_items(["x"; 3]), |_, _| std::cmp::Ordering::Greater);
+
+        assert_eq!(src.size_hint(), (3, Some(5)));
+        assert_eq!(src.try_next(), Ok(Some(Right("x"))));
+        assert_eq!(src.size_hint(), (2, Some(4)));
+    }
+}
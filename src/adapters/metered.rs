@@ -0,0 +1,161 @@
+use std::time::{Duration, Instant};
+
+use crate::metrics::{self, MetricsRecorder};
+use crate::{ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that reports item, error, and end counts, call latency, and
+/// throughput to a [`MetricsRecorder`].
+///
+/// Every call updates one of the [`ITEMS`](metrics::ITEMS),
+/// [`ERRORS`](metrics::ERRORS), or [`ENDS`](metrics::ENDS) counters and
+/// records its latency in the [`LATENCY`](metrics::LATENCY) histogram.
+/// The [`THROUGHPUT`](metrics::THROUGHPUT) gauge is refreshed on the
+/// first call after each rate interval, one second by default.
+///
+/// Created by [`TryNextExt::metered`](crate::TryNextExt::metered) and
+/// [`TryNextWithContextExt::metered`](crate::TryNextWithContextExt::metered).
+#[derive(Debug, Clone)]
+pub struct Metered<S, R> {
+    source: S,
+    recorder: R,
+    stage: &'static str,
+    interval: Duration,
+    window_start: Instant,
+    window_items: u64,
+}
+
+impl<S, R> Metered<S, R> {
+    pub(crate) fn new(source: S, recorder: R, stage: &'static str) -> Self {
+        Self {
+            source,
+            recorder,
+            stage,
+            interval: Duration::from_secs(1),
+            window_start: Instant::now(),
+            window_items: 0,
+        }
+    }
+
+    /// Sets how often the throughput gauge is refreshed.
+    pub fn with_rate_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the recorder.
+    pub fn recorder(&self) -> &R {
+        &self.recorder
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, R: MetricsRecorder> Metered<S, R> {
+    fn report<T, E>(&mut self, result: &Result<Option<T>, E>, start: Instant) {
+        let now = Instant::now();
+        let name = match result {
+            Ok(Some(_)) => {
+                self.window_items += 1;
+                metrics::ITEMS
+            }
+            Ok(None) => metrics::ENDS,
+            Err(_) => metrics::ERRORS,
+        };
+        self.recorder.increment_counter(name, self.stage, 1);
+        self.recorder
+            .record_histogram(metrics::LATENCY, self.stage, (now - start).as_secs_f64());
+
+        let elapsed = now - self.window_start;
+        if elapsed >= self.interval {
+            let rate = self.window_items as f64 / elapsed.as_secs_f64();
+            self.recorder
+                .set_gauge(metrics::THROUGHPUT, self.stage, rate);
+            self.window_start = now;
+            self.window_items = 0;
+        }
+    }
+}
+
+impl<S: TryNext, R: MetricsRecorder> TryNext for Metered<S, R> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let start = Instant::now();
+        let result = self.source.try_next();
+        self.report(&result, start);
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S: ExactSizeTryNext, R: MetricsRecorder> ExactSizeTryNext for Metered<S, R> {}
+
+impl<S: FusedTryNext, R: MetricsRecorder> FusedTryNext for Metered<S, R> {}
+
+impl<S: TryNextWithContext, R: MetricsRecorder> TryNextWithContext for Metered<S, R> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let start = Instant::now();
+        let result = self.source.try_next_with_context(context);
+        self.report(&result, start);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    use crate::metrics::{self, MetricsRecorder};
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[derive(Default)]
+    struct Log(RefCell<Vec<(&'static str, &'static str)>>);
+
+    impl MetricsRecorder for Log {
+        fn increment_counter(&self, name: &'static str, stage: &'static str, _: u64) {
+            self.0.borrow_mut().push((name, stage));
+        }
+
+        fn set_gauge(&self, name: &'static str, stage: &'static str, _: f64) {
+            self.0.borrow_mut().push((name, stage));
+        }
+
+        fn record_histogram(&self, _: &'static str, _: &'static str, _: f64) {}
+    }
+
+    #[test]
+    fn reports_counters_and_throughput() {
+        let log = Log::default();
+        let mut src = from_iter([Ok(1), Err('x')])
+            .metered(&log, "parse")
+            .with_rate_interval(Duration::ZERO);
+        while src.try_next() != Ok(None) {}
+
+        assert_eq!(
+            *log.0.borrow(),
+            [
+                (metrics::ITEMS, "parse"),
+                (metrics::THROUGHPUT, "parse"),
+                (metrics::ERRORS, "parse"),
+                (metrics::THROUGHPUT, "parse"),
+                (metrics::ENDS, "parse"),
+                (metrics::THROUGHPUT, "parse"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Adapter types returned by the [`TryNextExt`](crate::TryNextExt) and
+//! [`TryNextWithContextExt`](crate::TryNextWithContextExt) methods.
+
+mod array_peekable;
+mod backtrack;
+mod batch;
+mod bind_context;
+mod blocking;
+mod boxed_err;
+mod broadcast;
+mod budget;
+mod buffered;
+mod chain;
+mod chunks;
+mod chunks_exact;
+mod circuit_breaker;
+mod context_free;
+mod deltas;
+mod err_context;
+mod fallback;
+#[cfg(feature = "test-util")]
+mod fault_injector;
+mod filter;
+mod filter_with_context;
+#[cfg(test)]
+pub(crate) mod fixtures;
+mod fuse;
+mod gate;
+mod hash_join;
+#[cfg(feature = "heapless")]
+mod heapless_chunks;
+#[cfg(feature = "heapless")]
+mod heapless_tail;
+mod ignore_context;
+mod inspect;
+mod inspect_err;
+mod instrument;
+mod interleave;
+mod locate;
+#[cfg(feature = "log")]
+mod logged;
+mod map;
+mod map_context;
+mod map_pooled;
+mod map_with_context;
+mod merge_join;
+mod metered;
+mod par_map;
+mod par_map_unordered;
+mod peekable;
+mod prefetch;
+mod printed;
+mod provided;
+#[cfg(feature = "test-util")]
+mod recorded;
+mod replayable;
+mod retry;
+mod rev;
+mod running;
+mod sample;
+#[cfg(feature = "rand")]
+mod sample_by;
+mod shared;
+mod shared_context;
+mod shared_handle;
+#[cfg(feature = "rand")]
+mod shuffle;
+mod skip_errors;
+mod strict;
+mod take;
+mod take_exact;
+mod throttle;
+#[cfg(feature = "tracing")]
+mod traced;
+mod windowed_fold;
+mod with_context;
+mod zip;
+
+pub use array_peekable::ArrayPeekable;
+pub use backtrack::Backtrack;
+pub use batch::Batch;
+pub use bind_context::BindContext;
+pub use blocking::{Blocking, Wait, WaitStrategy};
+pub use boxed_err::BoxedErr;
+pub use broadcast::{Broadcast, BroadcastError, SlowConsumer};
+pub use budget::{Budget, BudgetError, BudgetLimits, Exhausted};
+pub use buffered::Buffered;
+pub use chain::Chain;
+pub use chunks::Chunks;
+pub use chunks_exact::{ChunksExact, ChunksExactError, DenyRemainder, KeepRemainder};
+pub use circuit_breaker::{BreakerError, CircuitBreaker};
+pub use context_free::ContextFree;
+pub use deltas::Deltas;
+pub use err_context::{ErrContext, ErrContextWith};
+pub use fallback::Fallback;
+#[cfg(feature = "test-util")]
+pub use fault_injector::{FaultInjector, FaultPlan};
+pub use filter::Filter;
+pub use filter_with_context::FilterWithContext;
+pub use fuse::Fuse;
+pub use gate::{Gate, GateError, GateHandle, TryGate};
+pub use hash_join::{HashJoin, InnerJoin, LeftJoin};
+#[cfg(feature = "heapless")]
+pub use heapless_chunks::HeaplessChunks;
+#[cfg(feature = "heapless")]
+pub use heapless_tail::HeaplessTail;
+pub use ignore_context::IgnoreContext;
+pub use inspect::Inspect;
+pub use inspect_err::InspectErr;
+pub use instrument::{Instrumented, Stats, StatsHandle};
+pub use interleave::Interleave;
+pub use locate::Locate;
+#[cfg(feature = "log")]
+pub use logged::Logged;
+pub use map::Map;
+pub use map_context::MapContext;
+pub use map_pooled::MapPooled;
+pub use map_with_context::MapWithContext;
+pub use merge_join::{EitherOrBoth, MergeJoinBy};
+pub use metered::Metered;
+pub use par_map::ParMap;
+pub use par_map_unordered::ParMapUnordered;
+pub use peekable::Peekable;
+pub use prefetch::Prefetch;
+pub use printed::Printed;
+pub use provided::Provided;
+#[cfg(feature = "test-util")]
+pub use recorded::Recorded;
+pub use replayable::Replayable;
+pub use retry::Retry;
+pub use rev::Rev;
+pub use running::Running;
+pub use sample::{Sample, Sampling};
+#[cfg(feature = "rand")]
+pub use sample_by::SampleBy;
+pub use shared::Shared;
+pub use shared_context::{LockedContext, SharedContext};
+pub use shared_handle::SharedHandle;
+#[cfg(feature = "rand")]
+pub use shuffle::Shuffle;
+pub use skip_errors::SkipErrors;
+pub use strict::Strict;
+pub use take::Take;
+pub use take_exact::{TakeExact, TakeExactError};
+pub use throttle::Throttle;
+#[cfg(feature = "tracing")]
+pub use traced::Traced;
+pub use windowed_fold::{ByKey, WindowedFold, Windowing};
+pub use with_context::WithContext;
+pub use zip::Zip;
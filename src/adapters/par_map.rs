@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+
+use crate::{FusedTryNext, TryNext};
+
+type Outcome<U, E> = thread::Result<Result<U, E>>;
+
+/// A fixed set of worker threads applying one function to numbered jobs.
+///
+/// Panics in the function are caught and reported as the job's outcome, so a
+/// panicking job never leaves the consumer waiting on a result that will not
+/// arrive.
+pub(super) struct Workers<T, U, E> {
+    jobs: Option<Sender<(u64, T)>>,
+    results: Receiver<(u64, Outcome<U, E>)>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl<T, U, E> Workers<T, U, E>
+where
+    T: Send + 'static,
+    U: Send + 'static,
+    E: Send + 'static,
+{
+    pub(super) fn new<F>(threads: usize, f: F) -> Self
+    where
+        F: Fn(T) -> Result<U, E> + Send + Sync + 'static,
+    {
+        assert!(threads > 0, "par_map needs at least one thread");
+        let (jobs, queue) = mpsc::channel::<(u64, T)>();
+        let (done, results) = mpsc::channel();
+        let queue = Arc::new(Mutex::new(queue));
+        let f = Arc::new(f);
+        let handles = (0..threads)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                let done = done.clone();
+                let f = Arc::clone(&f);
+                thread::spawn(move || {
+                    loop {
+                        let job = queue.lock().unwrap_or_else(PoisonError::into_inner).recv();
+                        let Ok((seq, item)) = job else {
+                            return;
+                        };
+                        let outcome = panic::catch_unwind(AssertUnwindSafe(|| f(item)));
+                        if done.send((seq, outcome)).is_err() {
+                            return;
+                        }
+                    }
+                })
+            })
+            .collect();
+        Self {
+            jobs: Some(jobs),
+            results,
+            handles,
+        }
+    }
+}
+
+impl<T, U, E> Workers<T, U, E> {
+    pub(super) fn submit(&self, seq: u64, item: T) {
+        if let Some(jobs) = &self.jobs {
+            // Workers only exit once `jobs` is dropped, so this cannot fail.
+            let _ = jobs.send((seq, item));
+        }
+    }
+
+    pub(super) fn recv(&self) -> (u64, Outcome<U, E>) {
+        self.results
+            .recv()
+            .expect("workers outlive their job queue")
+    }
+
+    pub(super) fn threads(&self) -> usize {
+        self.handles.len()
+    }
+}
+
+impl<T, U, E> Drop for Workers<T, U, E> {
+    fn drop(&mut self) {
+        self.jobs = None;
+        for handle in self.handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// A source that maps items on a pool of worker threads, keeping input
+/// order.
+///
+/// Items are pulled from the inner source on the consuming thread, numbered,
+/// and handed to the workers, with at most twice as many in flight as there
+/// are threads. Finished results wait in a reordering buffer until every
+/// earlier result has been yielded, so the output, including which error
+/// comes first, is the same as a sequential `map` would give regardless of
+/// how the workers are scheduled. Errors from the inner source keep their
+/// place in the sequence without visiting a worker.
+///
+/// If `f` panics, the panic is resumed on the consuming thread when it
+/// reaches that item. Dropping the adapter waits for the workers to finish
+/// the items they hold.
+///
+/// Created by [`TryNextExt::par_map`](crate::TryNextExt::par_map).
+pub struct ParMap<S: TryNext, U> {
+    source: S,
+    workers: Workers<S::Item, U, S::Error>,
+    ready: BTreeMap<u64, Outcome<U, S::Error>>,
+    next_in: u64,
+    next_out: u64,
+    done: bool,
+}
+
+impl<S, U> ParMap<S, U>
+where
+    S: TryNext,
+    S::Item: Send + 'static,
+    S::Error: Send + 'static,
+    U: Send + 'static,
+{
+    pub(crate) fn new<F>(source: S, threads: usize, f: F) -> Self
+    where
+        F: Fn(S::Item) -> Result<U, S::Error> + Send + Sync + 'static,
+    {
+        Self {
+            source,
+            workers: Workers::new(threads, f),
+            ready: BTreeMap::new(),
+            next_in: 0,
+            next_out: 0,
+            done: false,
+        }
+    }
+}
+
+impl<S: TryNext, U> ParMap<S, U> {
+    fn in_flight(&self) -> usize {
+        (self.next_in - self.next_out) as usize
+    }
+
+    fn fill(&mut self) {
+        while !self.done && self.in_flight() < 2 * self.workers.threads() {
+            let seq = self.next_in;
+            match self.source.try_next() {
+                Ok(Some(item)) => self.workers.submit(seq, item),
+                Ok(None) => {
+                    self.done = true;
+                    return;
+                }
+                Err(err) => {
+                    self.ready.insert(seq, Ok(Err(err)));
+                }
+            }
+            self.next_in += 1;
+        }
+    }
+}
+
+impl<S: TryNext, U> TryNext for ParMap<S, U> {
+    type Item = U;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.fill();
+        loop {
+            if let Some(outcome) = self.ready.remove(&self.next_out) {
+                self.next_out += 1;
+                return match outcome {
+                    Ok(result) => result.map(Some),
+                    Err(payload) => panic::resume_unwind(payload),
+                };
+            }
+            if self.in_flight() == 0 {
+                return Ok(None);
+            }
+            let (seq, outcome) = self.workers.recv();
+            self.ready.insert(seq, outcome);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.in_flight();
+        if self.done {
+            return (pending, Some(pending));
+        }
+        let (lower, upper) = self.source.size_hint();
+        (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        )
+    }
+}
+
+impl<S: TryNext, U> FusedTryNext for ParMap<S, U> {}
+
+impl<S: TryNext + fmt::Debug, U> fmt::Debug for ParMap<S, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParMap")
+            .field("source", &self.source)
+            .field("threads", &self.workers.threads())
+            .field("in_flight", &self.in_flight())
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn keeps_input_order_despite_uneven_work() {
+        let mut src = from_iter((0..20u64).map(Ok)).par_map(4, |n| {
+            thread::sleep(Duration::from_millis((20 - n) % 5));
+            Ok::<_, ()>(n * 10)
+        });
+
+        let out: Vec<u64> = src.try_collect().unwrap();
+        assert_eq!(out, (0..20).map(|n| n * 10).collect::<Vec<_>>());
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn surfaces_the_earliest_error_first() {
+        let mut src = from_iter([Ok(1), Ok(2), Err("source"), Ok(4)]).par_map(3, |n| {
+            if n == 1 {
+                thread::sleep(Duration::from_millis(20));
+            }
+            if n == 2 { Err("worker") } else { Ok(n) }
+        });
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err("worker"));
+        assert_eq!(src.try_next(), Err("source"));
+        assert_eq!(src.try_next(), Ok(Some(4)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "bad record")]
+    fn resumes_worker_panics_in_order() {
+        let mut src = from_iter((0..4).map(Ok)).par_map(2, |n| {
+            if n == 2 {
+                panic!("bad record")
+            } else {
+                Ok::<_, ()>(n)
+            }
+        });
+
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        let _ = src.try_next();
+    }
+}
@@ -0,0 +1,139 @@
+use std::fmt;
+use std::panic;
+
+use super::par_map::Workers;
+use crate::{FusedTryNext, TryNext};
+
+/// A source that maps items on a pool of worker threads, yielding results as
+/// soon as they finish.
+///
+/// The unordered counterpart of [`ParMap`](crate::adapters::ParMap): items
+/// are pulled and handed to the workers the same way, with at most twice as
+/// many in flight as there are threads, but a slow item never holds back the
+/// ones behind it. Errors from the inner source are returned as soon as they
+/// are pulled. If `f` panics, the panic is resumed on the consuming thread
+/// when that result comes back.
+///
+/// Created by [`TryNextExt::par_map_unordered`](crate::TryNextExt::par_map_unordered).
+pub struct ParMapUnordered<S: TryNext, U> {
+    source: S,
+    workers: Workers<S::Item, U, S::Error>,
+    in_flight: usize,
+    submitted: u64,
+    done: bool,
+}
+
+impl<S, U> ParMapUnordered<S, U>
+where
+    S: TryNext,
+    S::Item: Send + 'static,
+    S::Error: Send + 'static,
+    U: Send + 'static,
+{
+    pub(crate) fn new<F>(source: S, threads: usize, f: F) -> Self
+    where
+        F: Fn(S::Item) -> Result<U, S::Error> + Send + Sync + 'static,
+    {
+        Self {
+            source,
+            workers: Workers::new(threads, f),
+            in_flight: 0,
+            submitted: 0,
+            done: false,
+        }
+    }
+}
+
+impl<S: TryNext, U> TryNext for ParMapUnordered<S, U> {
+    type Item = U;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        while !self.done && self.in_flight < 2 * self.workers.threads() {
+            match self.source.try_next()? {
+                Some(item) => {
+                    self.workers.submit(self.submitted, item);
+                    self.submitted += 1;
+                    self.in_flight += 1;
+                }
+                None => self.done = true,
+            }
+        }
+        if self.in_flight == 0 {
+            return Ok(None);
+        }
+        let (_, outcome) = self.workers.recv();
+        self.in_flight -= 1;
+        match outcome {
+            Ok(result) => result.map(Some),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (self.in_flight, Some(self.in_flight));
+        }
+        let (lower, upper) = self.source.size_hint();
+        (
+            lower.saturating_add(self.in_flight),
+            upper.and_then(|upper| upper.checked_add(self.in_flight)),
+        )
+    }
+}
+
+impl<S: TryNext, U> FusedTryNext for ParMapUnordered<S, U> {}
+
+impl<S: TryNext + fmt::Debug, U> fmt::Debug for ParMapUnordered<S, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParMapUnordered")
+            .field("source", &self.source)
+            .field("threads", &self.workers.threads())
+            .field("in_flight", &self.in_flight)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn slow_items_do_not_hold_back_fast_ones() {
+        let (release, gate) = mpsc::channel::<()>();
+        let gate = Mutex::new(gate);
+        let mut src = from_iter((0..4).map(Ok::<u32, ()>)).par_map_unordered(2, move |n| {
+            if n == 0 {
+                let _ = gate.lock().unwrap().recv_timeout(Duration::from_secs(5));
+            }
+            Ok(n)
+        });
+
+        let mut first = Vec::new();
+        for _ in 0..3 {
+            first.push(src.try_next().unwrap().unwrap());
+        }
+        first.sort();
+        assert_eq!(first, [1, 2, 3]);
+
+        release.send(()).unwrap();
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn passes_source_errors_through_immediately() {
+        let mut src = from_iter([Ok(1), Err("source"), Ok(2)]).par_map_unordered(1, Ok);
+
+        assert_eq!(src.try_next(), Err("source"));
+        let mut rest = src.try_collect::<Vec<_>>().unwrap();
+        rest.sort();
+        assert_eq!(rest, [1, 2]);
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::VecDeque;
+
+use crate::{ExactSizeTryNext, FusedTryNext, TryNext, TryPeek};
+
+/// Copies an item into the rollback buffer; set by the first mark.
+type CloneFn<T> = fn(&T) -> T;
+
+/// A source with one item of lookahead and optional rollback.
+///
+/// Besides peeking, a parser can consume items speculatively: after
+/// [`mark`](Self::mark), every item returned is also kept, so that
+/// [`reset`](Self::reset) can put them back to be yielded again, while
+/// [`commit`](Self::commit) accepts them. Marks nest, each `reset` or
+/// `commit` closing the innermost one, and consumed items are only kept
+/// while a mark is outstanding. Errors are never kept or replayed.
+///
+/// ```rust
+/// use try_next::{TryNext, TryNextExt, from_items};
+///
+/// let mut tokens = from_items(["let", "x", "=", "1"]).peekable();
+///
+/// tokens.mark();
+/// let is_fn = tokens.try_next().unwrap() == Some("fn");
+/// assert!(!is_fn);
+/// tokens.reset();
+///
+/// tokens.mark();
+/// assert_eq!(tokens.try_next(), Ok(Some("let")));
+/// assert_eq!(tokens.try_next(), Ok(Some("x")));
+/// tokens.commit();
+/// assert_eq!(tokens.try_next(), Ok(Some("=")));
+/// ```
+///
+/// Created by [`TryNextExt::peekable`](crate::TryNextExt::peekable).
+#[derive(Debug, Clone)]
+pub struct Peekable<S: TryNext> {
+    source: S,
+    ahead: VecDeque<S::Item>,
+    ended: bool,
+    marks: Vec<usize>,
+    consumed: Vec<S::Item>,
+    clone: Option<CloneFn<S::Item>>,
+}
+
+impl<S: TryNext> Peekable<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            source,
+            ahead: VecDeque::new(),
+            ended: false,
+            marks: Vec::new(),
+            consumed: Vec::new(),
+            clone: None,
+        }
+    }
+
+    /// Returns a mutable reference to the next item without consuming it.
+    pub fn try_peek_mut(&mut self) -> Result<Option<&mut S::Item>, S::Error> {
+        self.fill()?;
+        Ok(self.ahead.front_mut())
+    }
+
+    /// Consumes and returns the next item if it satisfies `pred`.
+    pub fn try_next_if(
+        &mut self,
+        pred: impl FnOnce(&S::Item) -> bool,
+    ) -> Result<Option<S::Item>, S::Error> {
+        match self.try_peek()? {
+            Some(item) if pred(item) => self.try_next(),
+            _ => Ok(None),
+        }
+    }
+
+    /// Starts keeping consumed items so they can be put back by
+    /// [`reset`](Self::reset).
+    pub fn mark(&mut self)
+    where
+        S::Item: Clone,
+    {
+        self.clone = Some(S::Item::clone);
+        self.marks.push(self.consumed.len());
+    }
+
+    /// Puts back every item consumed since the innermost mark and closes it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no mark is outstanding.
+    pub fn reset(&mut self) {
+        let start = self.marks.pop().expect("reset called without a mark");
+        for item in self.consumed.drain(start..).rev() {
+            self.ahead.push_front(item);
+        }
+    }
+
+    /// Accepts the items consumed since the innermost mark and closes it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no mark is outstanding.
+    pub fn commit(&mut self) {
+        self.marks.pop().expect("commit called without a mark");
+        if self.marks.is_empty() {
+            self.consumed.clear();
+        }
+    }
+
+    /// Returns the number of outstanding marks.
+    pub fn marks(&self) -> usize {
+        self.marks.len()
+    }
+
+    /// Returns the underlying source, dropping any buffered items.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn fill(&mut self) -> Result<(), S::Error> {
+        if self.ahead.is_empty() && !self.ended {
+            match self.source.try_next()? {
+                Some(item) => self.ahead.push_back(item),
+                None => self.ended = true,
+            }
+        }
+        Ok(())
+    }
+
+    fn keep(&mut self, item: &S::Item) {
+        if let Some(clone) = self.clone.filter(|_| !self.marks.is_empty()) {
+            self.consumed.push(clone(item));
+        }
+    }
+}
+
+impl<S: TryNext> TryNext for Peekable<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let item = match self.ahead.pop_front() {
+            Some(item) => item,
+            None if self.ended => {
+                self.ended = false;
+                return Ok(None);
+            }
+            None => match self.source.try_next()? {
+                Some(item) => item,
+                None => return Ok(None),
+            },
+        };
+        self.keep(&item);
+        Ok(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let ahead = self.ahead.len();
+        if self.ended {
+            return (ahead, Some(ahead));
+        }
+        let (lower, upper) = self.source.size_hint();
+        (
+            lower.saturating_add(ahead),
+            upper.and_then(|upper| upper.checked_add(ahead)),
+        )
+    }
+
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        if max == 0 {
+            return Ok(Vec::new());
+        }
+        let mut chunk: Vec<_> = self.ahead.drain(..max.min(self.ahead.len())).collect();
+        if chunk.len() < max {
+            if self.ended {
+                if chunk.is_empty() {
+                    self.ended = false;
+                }
+            } else {
+                match self.source.try_next_chunk(max - chunk.len()) {
+                    Ok(rest) => chunk.extend(rest),
+                    Err(err) => {
+                        for item in chunk.into_iter().rev() {
+                            self.ahead.push_front(item);
+                        }
+                        return Err(err);
+                    }
+                }
+            }
+        }
+        for item in &chunk {
+            self.keep(item);
+        }
+        Ok(chunk)
+    }
+}
+
+impl<S: TryNext> TryPeek for Peekable<S> {
+    fn try_peek(&mut self) -> Result<Option<&Self::Item>, Self::Error> {
+        self.fill()?;
+        Ok(self.ahead.front())
+    }
+}
+
+impl<S: ExactSizeTryNext> ExactSizeTryNext for Peekable<S> {}
+
+impl<S: FusedTryNext> FusedTryNext for Peekable<S> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, TryPeek};
+
+    /// Yields the given steps in order, then `Ok(None)`.
+    struct Steps(Vec<Result<u8, char>>);
+
+    impl TryNext for Steps {
+        type Item = u8;
+        type Error = char;
+
+        fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            if self.0.is_empty() {
+                return Ok(None);
+            }
+            self.0.remove(0).map(Some)
+        }
+    }
+
+    #[test]
+    fn peek_does_not_consume() {
+        let mut src = Steps(vec![Ok(1), Ok(2)]).peekable();
+
+        assert_eq!(src.try_peek(), Ok(Some(&1)));
+        assert_eq!(src.try_peek(), Ok(Some(&1)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next_if(|&v| v > 5), Ok(None));
+        assert_eq!(src.try_next_if(|&v| v == 2), Ok(Some(2)));
+        assert_eq!(src.try_peek(), Ok(None));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn peek_errors_are_not_buffered() {
+        let mut src = Steps(vec![Err('x'), Ok(3)]).peekable();
+
+        assert_eq!(src.try_peek(), Err('x'));
+        if let Some(v) = src.try_peek_mut().unwrap() {
+            *v *= 10;
+        }
+        assert_eq!(src.try_next(), Ok(Some(30)));
+    }
+
+    #[test]
+    fn chunk_includes_peeked_item() {
+        let mut src = Steps(vec![Ok(1), Ok(2), Ok(3)]).peekable();
+
+        assert_eq!(src.try_peek(), Ok(Some(&1)));
+        assert_eq!(src.try_next_chunk(0), Ok(vec![]));
+        assert_eq!(src.try_next_chunk(2), Ok(vec![1, 2]));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+    }
+
+    #[test]
+    fn reset_replays_items_consumed_since_the_mark() {
+        let mut src = Steps(vec![Ok(1), Ok(2), Ok(3), Ok(4)]).peekable();
+
+        src.mark();
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        src.mark();
+        assert_eq!(src.try_next_chunk(2), Ok(vec![2, 3]));
+        src.reset();
+        assert_eq!(src.marks(), 1);
+        assert_eq!(src.size_hint(), (2, None));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        src.reset();
+
+        assert_eq!(src.try_peek(), Ok(Some(&1)));
+        src.mark();
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        src.commit();
+        assert_eq!(src.marks(), 0);
+        assert_eq!(src.try_next_chunk(5), Ok(vec![2, 3, 4]));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn errors_are_not_replayed() {
+        let mut src = Steps(vec![Ok(1), Err('x'), Ok(2)]).peekable();
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        src.mark();
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        src.reset();
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
@@ -0,0 +1,183 @@
+use std::panic;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
+
+use crate::cancel::{self, CancelToken, Cancelled, CheckCancel};
+use crate::{FusedTryNext, TryNext};
+
+/// A source that pulls from another source on a background thread.
+///
+/// The worker thread pulls ahead into a bounded channel holding up to
+/// `capacity` results, so a slow, I/O-bound source overlaps with the
+/// consumer. Errors are delivered in order and do not stop the worker; the
+/// first `Ok(None)` does. Dropping the adapter stops the worker after its
+/// current pull.
+///
+/// If the inner source panics, the panic is resumed on the consuming thread
+/// when it reaches that point in the sequence.
+///
+/// With [`with_cancel`](Self::with_cancel), a consumer waiting for the
+/// worker gives up as soon as the token is cancelled. Once the adapter has
+/// returned `Ok(None)`, it keeps doing so even after the token fires.
+///
+/// Created by [`TryNextExt::prefetch`](crate::TryNextExt::prefetch).
+#[derive(Debug)]
+pub struct Prefetch<T, E, C = ()> {
+    receiver: Receiver<Result<T, E>>,
+    worker: Option<JoinHandle<()>>,
+    cancel: C,
+    ended: bool,
+}
+
+impl<T, E> Prefetch<T, E> {
+    pub(crate) fn new<S>(mut source: S, capacity: usize) -> Self
+    where
+        S: TryNext<Item = T, Error = E> + Send + 'static,
+        T: Send + 'static,
+        E: Send + 'static,
+    {
+        assert!(capacity > 0, "prefetch capacity must be non-zero");
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        let worker = thread::spawn(move || {
+            loop {
+                let result = match source.try_next() {
+                    Ok(Some(item)) => Ok(item),
+                    Ok(None) => return,
+                    Err(err) => Err(err),
+                };
+                if sender.send(result).is_err() {
+                    return;
+                }
+            }
+        });
+        Self {
+            receiver,
+            worker: Some(worker),
+            cancel: (),
+            ended: false,
+        }
+    }
+
+    /// Returns [`Cancelled`], converted into `E`, from calls made once
+    /// `token` is cancelled.
+    pub fn with_cancel(self, token: CancelToken) -> Prefetch<T, E, CancelToken>
+    where
+        E: From<Cancelled>,
+    {
+        Prefetch {
+            receiver: self.receiver,
+            worker: self.worker,
+            cancel: token,
+            ended: self.ended,
+        }
+    }
+}
+
+impl<T, E, C> Prefetch<T, E, C> {
+    fn finish(&mut self) {
+        self.ended = true;
+        if let Some(worker) = self.worker.take()
+            && let Err(payload) = worker.join()
+        {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    /// Like [`try_next`](TryNext::try_next), but gives up at `deadline`,
+    /// returning `None`.
+    pub(crate) fn try_next_until(&mut self, deadline: Instant) -> Option<Result<Option<T>, E>> {
+        if self.ended {
+            return Some(Ok(None));
+        }
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        match self.receiver.recv_timeout(timeout) {
+            Ok(result) => Some(result.map(Some)),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => {
+                self.finish();
+                Some(Ok(None))
+            }
+        }
+    }
+}
+
+impl<T, E, C: CheckCancel<E>> TryNext for Prefetch<T, E, C> {
+    type Item = T;
+    type Error = E;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.ended {
+            return Ok(None);
+        }
+        match cancel::recv(&self.receiver, &self.cancel)? {
+            Ok(result) => result.map(Some),
+            Err(_) => {
+                self.finish();
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl<T, E, C: CheckCancel<E>> FusedTryNext for Prefetch<T, E, C> {}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::cancel::{CancelToken, Cancelled};
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn delivers_items_and_errors_in_order() {
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2)]).prefetch(1);
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    #[should_panic(expected = "source exploded")]
+    fn resumes_worker_panics() {
+        let mut src = from_iter([Ok::<u8, ()>(1)])
+            .inspect(|_| panic!("source exploded"))
+            .prefetch(4);
+
+        let _ = src.try_next();
+    }
+
+    #[test]
+    fn stops_waiting_once_cancelled() {
+        let token = CancelToken::new();
+        let mut src = from_iter([Ok::<_, Cancelled>(1), Ok(2)])
+            .inspect(|&n| {
+                if n == 2 {
+                    thread::sleep(Duration::from_secs(5));
+                }
+            })
+            .prefetch(1)
+            .with_cancel(token.clone());
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        token.cancel();
+        assert_eq!(src.try_next(), Err(Cancelled));
+    }
+
+    #[test]
+    fn cancelling_after_the_end_keeps_returning_none() {
+        let token = CancelToken::new();
+        let mut src = from_iter([Ok::<_, Cancelled>(1)])
+            .prefetch(1)
+            .with_cancel(token.clone());
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(None));
+        token.cancel();
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
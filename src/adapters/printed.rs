@@ -0,0 +1,222 @@
+use std::fmt::{Debug, Display};
+use std::io::{self, Write};
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that prints each item, error, and the end of input as it passes
+/// through.
+///
+/// Meant for quick troubleshooting without a logging framework: lines go
+/// to standard error unless [`to_writer`](Self::to_writer) supplies another
+/// writer, and failures to write are ignored. Values are formatted with
+/// `{:?}` by default, `{:#?}` after [`pretty`](Self::pretty), or `{}` after
+/// [`display`](Self::display). [`with_label`](Self::with_label) prefixes
+/// every line, and [`with_count`](Self::with_count) numbers the items.
+///
+/// ```rust
+/// use try_next::{TryNext, TryNextExt, from_iter};
+///
+/// let mut src = from_iter([Ok(1), Err("bad"), Ok(2)])
+///     .debug()
+///     .with_label("parse")
+///     .with_count()
+///     .to_writer(Vec::new());
+/// while src.try_next().transpose().is_some() {}
+///
+/// let out = String::from_utf8(src.writer().clone()).unwrap();
+/// assert_eq!(
+///     out,
+///     "parse: #1 1\nparse: error after 1 items: \"bad\"\nparse: #2 2\nparse: end after 2 items\n"
+/// );
+/// ```
+///
+/// Created by [`TryNextExt::debug`](crate::TryNextExt::debug) and
+/// [`TryNextWithContextExt::debug`](crate::TryNextWithContextExt::debug).
+#[derive(Debug, Clone)]
+pub struct Printed<S, T, E, W = io::Stderr> {
+    source: S,
+    writer: W,
+    label: Option<String>,
+    count: bool,
+    items: u64,
+    item: fn(&T) -> String,
+    error: fn(&E) -> String,
+}
+
+impl<S, T: Debug, E: Debug> Printed<S, T, E> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            source,
+            writer: io::stderr(),
+            label: None,
+            count: false,
+            items: 0,
+            item: |item| format!("{item:?}"),
+            error: |error| format!("{error:?}"),
+        }
+    }
+}
+
+impl<S, T, E, W> Printed<S, T, E, W> {
+    /// Starts every line with `label`.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Numbers each item, and reports how many were seen with each error and
+    /// at the end.
+    pub fn with_count(mut self) -> Self {
+        self.count = true;
+        self
+    }
+
+    /// Formats items and errors with `{:#?}`.
+    pub fn pretty(mut self) -> Self
+    where
+        T: Debug,
+        E: Debug,
+    {
+        self.item = |item| format!("{item:#?}");
+        self.error = |error| format!("{error:#?}");
+        self
+    }
+
+    /// Formats items and errors with `{}`.
+    pub fn display(mut self) -> Self
+    where
+        T: Display,
+        E: Display,
+    {
+        self.item = |item| item.to_string();
+        self.error = |error| error.to_string();
+        self
+    }
+
+    /// Writes lines to `writer` instead of standard error.
+    pub fn to_writer<V: Write>(self, writer: V) -> Printed<S, T, E, V> {
+        Printed {
+            source: self.source,
+            writer,
+            label: self.label,
+            count: self.count,
+            items: self.items,
+            item: self.item,
+            error: self.error,
+        }
+    }
+
+    /// Returns the writer lines go to.
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn print(&mut self, result: &Result<Option<T>, E>)
+    where
+        W: Write,
+    {
+        let mut line = match &self.label {
+            Some(label) => format!("{label}: "),
+            None => String::new(),
+        };
+        match result {
+            Ok(Some(item)) => {
+                self.items += 1;
+                if self.count {
+                    line += &format!("#{} ", self.items);
+                }
+                line += &(self.item)(item);
+            }
+            Ok(None) if self.count => line += &format!("end after {} items", self.items),
+            Ok(None) => line += "end",
+            Err(error) => {
+                line += &if self.count {
+                    format!("error after {} items: ", self.items)
+                } else {
+                    "error: ".to_owned()
+                };
+                line += &(self.error)(error);
+            }
+        }
+        let _ = writeln!(self.writer, "{line}");
+    }
+}
+
+impl<S: TryNext, W: Write> TryNext for Printed<S, S::Item, S::Error, W> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next();
+        self.print(&result);
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S: FusedTryNext, W: Write> FusedTryNext for Printed<S, S::Item, S::Error, W> {}
+
+impl<S: TryNextWithContext, W: Write> TryNextWithContext for Printed<S, S::Item, S::Error, W> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next_with_context(context);
+        self.print(&result);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[derive(Debug)]
+    struct Bad;
+
+    impl fmt::Display for Bad {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("bad row")
+        }
+    }
+
+    #[test]
+    fn prints_with_display_and_no_label() {
+        let mut src = from_iter([Ok("a"), Err(Bad)])
+            .debug()
+            .display()
+            .to_writer(Vec::new());
+
+        assert_eq!(src.try_next().unwrap(), Some("a"));
+        assert!(src.try_next().is_err());
+        assert_eq!(src.try_next().unwrap(), None);
+        let out = String::from_utf8(src.writer().clone()).unwrap();
+        assert_eq!(out, "a\nerror: bad row\nend\n");
+    }
+
+    #[test]
+    fn pretty_prints_across_lines() {
+        let mut src = from_iter([Ok::<_, Bad>((1, 2))])
+            .debug()
+            .pretty()
+            .to_writer(Vec::new());
+
+        src.try_next().unwrap();
+        let out = String::from_utf8(src.writer().clone()).unwrap();
+        assert_eq!(out, "(\n    1,\n    2,\n)\n");
+    }
+}
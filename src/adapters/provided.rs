@@ -0,0 +1,86 @@
+use crate::{ProvideContext, TryNext, TryNextWithContext};
+
+/// A [`TryNext`] that runs a context-aware source against contexts handed
+/// out by a [`ProvideContext`].
+///
+/// The provider is asked for a context on every call, so it decides whether
+/// calls share one context or each get their own.
+///
+/// Created by
+/// [`TryNextWithContextExt::with_provider`](crate::TryNextWithContextExt::with_provider).
+#[derive(Debug, Clone)]
+pub struct Provided<S, P> {
+    source: S,
+    provider: P,
+}
+
+impl<S, P> Provided<S, P> {
+    pub(crate) fn new(source: S, provider: P) -> Self {
+        Self { source, provider }
+    }
+
+    /// Returns a reference to the provider.
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Returns a mutable reference to the provider.
+    pub fn provider_mut(&mut self) -> &mut P {
+        &mut self.provider
+    }
+
+    /// Returns the underlying source and the provider.
+    pub fn into_parts(self) -> (S, P) {
+        (self.source, self.provider)
+    }
+}
+
+impl<S, P> TryNext for Provided<S, P>
+where
+    S: TryNextWithContext,
+    P: ProvideContext<S::Context>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next_with_context(self.provider.provide())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, TryNextWithContextExt, fresh_context, from_items};
+
+    #[test]
+    fn asks_the_provider_on_every_call() {
+        let mut src = from_items(["a", "b"])
+            .ignore_context::<Vec<&str>>()
+            .map_with_context(|log, item| {
+                log.push(item);
+                log.len()
+            })
+            .with_provider(fresh_context(Vec::new));
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.provider().last(), Some(&vec!["b"]));
+    }
+
+    #[test]
+    fn borrowed_provider_shares_one_context() {
+        let mut log = Vec::new();
+        let mut src = from_items(["a", "b"])
+            .ignore_context::<Vec<&str>>()
+            .map_with_context(|log, item| {
+                log.push(item);
+                log.len()
+            })
+            .with_provider(&mut log);
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(log, ["a", "b"]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{TryNext, TryNextWithContext};
+
+/// A source that logs every call made to another source, for asserting on
+/// how a consumer pulls.
+///
+/// Each call appends one entry, built by the summary closure from the call's
+/// outcome, so the log records items, errors, and every terminal `Ok(None)`
+/// in the order they were returned. Bulk methods fall back to per-item
+/// calls, so each pull is logged individually.
+///
+/// Available with the `test-util` feature.
+///
+/// ```rust
+/// use try_next::{TryNext, TryNextExt, from_iter};
+///
+/// let mut src = from_iter([Ok(1), Err("bad")]).recorded();
+/// while src.try_next().is_ok_and(|item| item.is_some()) {}
+/// assert_eq!(src.log(), [Ok(Some(1)), Err("bad")]);
+/// ```
+///
+/// Created by [`TryNextExt::recorded`](crate::TryNextExt::recorded),
+/// [`TryNextExt::recorded_with`](crate::TryNextExt::recorded_with), and
+/// their [`TryNextWithContextExt`](crate::TryNextWithContextExt)
+/// counterparts.
+#[derive(Debug, Clone)]
+pub struct Recorded<S, R, F> {
+    source: S,
+    summarize: F,
+    log: Vec<R>,
+}
+
+impl<S, R, F> Recorded<S, R, F> {
+    pub(crate) fn new(source: S, summarize: F) -> Self {
+        Self {
+            source,
+            summarize,
+            log: Vec::new(),
+        }
+    }
+
+    /// Returns the entries logged so far, one per call.
+    pub fn log(&self) -> &[R] {
+        &self.log
+    }
+
+    /// Returns the number of calls made so far.
+    pub fn calls(&self) -> usize {
+        self.log.len()
+    }
+
+    /// Removes and returns the entries logged so far.
+    pub fn take_log(&mut self) -> Vec<R> {
+        std::mem::take(&mut self.log)
+    }
+
+    /// Returns the underlying source, discarding the log.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, R, F> TryNext for Recorded<S, R, F>
+where
+    S: TryNext,
+    F: FnMut(&Result<Option<S::Item>, S::Error>) -> R,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next();
+        self.log.push((self.summarize)(&result));
+        result
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, R, F> TryNextWithContext for Recorded<S, R, F>
+where
+    S: TryNextWithContext,
+    F: FnMut(&Result<Option<S::Item>, S::Error>) -> R,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next_with_context(context);
+        self.log.push((self.summarize)(&result));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_items};
+
+    #[test]
+    fn logs_each_pull_including_repeated_ends() {
+        let mut src = from_items([1, 2]).recorded_with(|r| matches!(r, Ok(Some(_))));
+        let _ = src.try_next_chunk(4);
+        let _ = src.try_next();
+
+        assert_eq!(src.calls(), 4);
+        assert_eq!(src.take_log(), [true, true, false, false]);
+        assert!(src.log().is_empty());
+    }
+}
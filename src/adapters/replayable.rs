@@ -0,0 +1,178 @@
+use crate::replay::ReplayBuffer;
+use crate::{TryNext, TryNextWithContext};
+
+/// A source that records its items so they can be read again.
+///
+/// Every item pulled from the inner source is appended to a
+/// [`ReplayBuffer`] before it is returned. [`rewind`](Self::rewind) moves
+/// the read position back to the start, and [`rewind_to_mark`](Self::rewind_to_mark)
+/// back to the last [`mark`](Self::mark); the recorded items are then
+/// yielded from the buffer, and the inner source is pulled again only once
+/// they run out. Errors are returned as they occur and are not recorded.
+///
+/// Created by [`TryNextExt::replayable`](crate::TryNextExt::replayable) and
+/// [`TryNextWithContextExt::replayable`](crate::TryNextWithContextExt::replayable),
+/// or their `replayable_with` variants taking a buffer such as
+/// `SpillFile` from the [`replay`](crate::replay) module.
+#[derive(Debug, Clone)]
+pub struct Replayable<S, B> {
+    source: S,
+    buffer: B,
+    position: usize,
+    mark: usize,
+}
+
+impl<S, B> Replayable<S, B> {
+    pub(crate) fn new(source: S, buffer: B) -> Self {
+        Self {
+            source,
+            buffer,
+            position: 0,
+            mark: 0,
+        }
+    }
+
+    /// Returns the number of items yielded since the start or the last
+    /// rewind, counting replayed ones.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Remembers the current position for
+    /// [`rewind_to_mark`](Self::rewind_to_mark).
+    pub fn mark(&mut self) {
+        self.mark = self.position;
+    }
+
+    /// Replays from the first item.
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+
+    /// Replays from the position saved by the last [`mark`](Self::mark),
+    /// or from the first item if none was saved.
+    pub fn rewind_to_mark(&mut self) {
+        self.position = self.mark;
+    }
+
+    /// Returns the buffer of recorded items.
+    pub fn buffer(&self) -> &B {
+        &self.buffer
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn pull<T, E>(
+        &mut self,
+        pull: impl FnOnce(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, E>
+    where
+        B: ReplayBuffer<T, E>,
+    {
+        if self.position < self.buffer.len() {
+            let item = self.buffer.replay(self.position)?;
+            self.position += 1;
+            return Ok(Some(item));
+        }
+        let Some(item) = pull(&mut self.source)? else {
+            return Ok(None);
+        };
+        self.buffer.record(&item)?;
+        self.position += 1;
+        Ok(Some(item))
+    }
+}
+
+impl<S, B> TryNext for Replayable<S, B>
+where
+    S: TryNext,
+    B: ReplayBuffer<S::Item, S::Error>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let replay = self.buffer.len() - self.position;
+        let (low, high) = self.source.size_hint();
+        (
+            low.saturating_add(replay),
+            high.and_then(|high| high.checked_add(replay)),
+        )
+    }
+}
+
+impl<S, B> TryNextWithContext for Replayable<S, B>
+where
+    S: TryNextWithContext,
+    B: ReplayBuffer<S::Item, S::Error>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn rewinds_to_the_start_and_to_a_mark() {
+        let mut src = from_items(1..=4).replayable();
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        src.mark();
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+
+        src.rewind_to_mark();
+        assert_eq!(src.position(), 1);
+        assert_eq!(src.try_collect::<Vec<_>>(), Ok(vec![2, 3, 4]));
+
+        src.rewind();
+        assert_eq!(src.size_hint(), (4, Some(4)));
+        assert_eq!(src.try_collect::<Vec<_>>(), Ok(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn errors_are_not_recorded() {
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2)]).replayable();
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+
+        src.rewind();
+        assert_eq!(src.try_collect::<Vec<_>>(), Ok(vec![1, 2]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn replays_from_a_spill_file() {
+        use std::io;
+
+        use crate::replay::SpillFile;
+
+        let words = ["alpha", "beta", "gamma"].map(String::from);
+        let mut src = from_iter(words.clone().map(Ok::<_, io::Error>))
+            .replayable_with(SpillFile::new().unwrap());
+
+        let first: Vec<String> = src.try_collect().unwrap();
+        src.rewind();
+        let second: Vec<String> = src.try_collect().unwrap();
+        assert_eq!(first, words);
+        assert_eq!(second, words);
+    }
+}
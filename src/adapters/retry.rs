@@ -0,0 +1,196 @@
+use crate::cancel::{self, CancelToken, CheckCancel};
+use crate::retry::{AnyError, Classify, RetryPolicy};
+use crate::{TryNext, TryNextWithContext};
+
+/// A source that retries failed pulls according to a [`RetryPolicy`].
+///
+/// When the inner source returns an error that the classifier `K` deems
+/// retryable, the policy is asked for a delay; the adapter sleeps for that
+/// long (or longer, if the error asks for it) and pulls again. The error is
+/// returned once the policy gives up, or immediately if it is not
+/// retryable. The attempt count starts over for every pull.
+///
+/// With [`with_cancel`](Self::with_cancel), a cancelled token stops further
+/// attempts and cuts short any backoff in progress.
+///
+/// Created by [`TryNextExt::retry`](crate::TryNextExt::retry),
+/// [`TryNextExt::retry_transient`](crate::TryNextExt::retry_transient), and
+/// their [`TryNextWithContextExt`](crate::TryNextWithContextExt)
+/// counterparts.
+#[derive(Debug, Clone)]
+pub struct Retry<S, P, K = AnyError, C = ()> {
+    source: S,
+    policy: P,
+    classify: K,
+    cancel: C,
+}
+
+impl<S, P, K> Retry<S, P, K> {
+    pub(crate) fn new(source: S, policy: P, classify: K) -> Self {
+        Self {
+            source,
+            policy,
+            classify,
+            cancel: (),
+        }
+    }
+
+    /// Returns [`Cancelled`](crate::cancel::Cancelled), converted into the
+    /// source's error type, once `token` is cancelled, checking before every
+    /// attempt and while backing off.
+    pub fn with_cancel(self, token: CancelToken) -> Retry<S, P, K, CancelToken> {
+        Retry {
+            source: self.source,
+            policy: self.policy,
+            classify: self.classify,
+            cancel: token,
+        }
+    }
+}
+
+impl<S, P, K, C> Retry<S, P, K, C> {
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn retry<T, E>(&mut self, mut pull: impl FnMut(&mut S) -> Result<T, E>) -> Result<T, E>
+    where
+        P: RetryPolicy,
+        K: Classify<E>,
+        C: CheckCancel<E>,
+    {
+        let mut attempt = 0;
+        loop {
+            self.cancel.check()?;
+            let err = match pull(&mut self.source) {
+                Ok(item) => return Ok(item),
+                Err(err) if self.classify.is_retryable(&err) => err,
+                Err(err) => return Err(err),
+            };
+            attempt += 1;
+            let Some(delay) = self.policy.delay(attempt) else {
+                return Err(err);
+            };
+            let delay = delay.max(self.classify.retry_after(&err).unwrap_or_default());
+            if !delay.is_zero() {
+                cancel::sleep(delay, &self.cancel)?;
+            }
+        }
+    }
+}
+
+impl<S, P, K, C> TryNext for Retry<S, P, K, C>
+where
+    S: TryNext,
+    P: RetryPolicy,
+    K: Classify<S::Error>,
+    C: CheckCancel<S::Error>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.retry(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, P, K, C> TryNextWithContext for Retry<S, P, K, C>
+where
+    S: TryNextWithContext,
+    P: RetryPolicy,
+    K: Classify<S::Error>,
+    C: CheckCancel<S::Error>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.retry(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cancel::CancelToken;
+    use crate::retry::Fixed;
+    use crate::{TryNext, TryNextExt, from_iter};
+    use std::io;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn surfaces_error_once_policy_gives_up() {
+        let items = [
+            Ok(1),
+            Err('a'),
+            Err('b'),
+            Ok(2),
+            Err('c'),
+            Err('d'),
+            Err('e'),
+        ];
+        let mut src = from_iter(items).retry(Fixed::new(Duration::ZERO, 2));
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Err('e'));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn passes_the_attempt_number() {
+        let mut attempts = Vec::new();
+        let mut src = from_iter([Err(()), Err(()), Ok(1)]).retry(|n| {
+            attempts.push(n);
+            Some(Duration::ZERO)
+        });
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(attempts, [1, 2]);
+    }
+
+    #[test]
+    fn transient_fails_fast_on_permanent_errors() {
+        let items: [io::Result<u32>; 4] = [
+            Err(io::ErrorKind::TimedOut.into()),
+            Ok(1),
+            Err(io::ErrorKind::NotFound.into()),
+            Ok(2),
+        ];
+        let mut src = from_iter(items).retry_transient(Fixed::new(Duration::ZERO, 5));
+
+        assert_eq!(src.try_next().unwrap(), Some(1));
+        assert_eq!(src.try_next().unwrap_err().kind(), io::ErrorKind::NotFound);
+        assert_eq!(src.try_next().unwrap(), Some(2));
+    }
+
+    #[test]
+    fn cancelling_cuts_backoff_short() {
+        let token = CancelToken::new();
+        let items: [io::Result<u32>; 2] = [Err(io::ErrorKind::TimedOut.into()), Ok(1)];
+        let mut src = from_iter(items)
+            .retry(Fixed::new(Duration::from_secs(60), 3))
+            .with_cancel(token.clone());
+
+        let canceller = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            token.cancel();
+        });
+        let started = Instant::now();
+        assert_eq!(
+            src.try_next().unwrap_err().kind(),
+            io::ErrorKind::Interrupted
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+        canceller.join().unwrap();
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{DoubleEndedTryNext, ExactSizeTryNext, FusedTryNext, TryNext};
+
+/// A source that yields the items of a [`DoubleEndedTryNext`] in reverse.
+///
+/// Created by [`TryNextExt::rev`](crate::TryNextExt::rev).
+#[derive(Debug, Clone)]
+pub struct Rev<S> {
+    source: S,
+}
+
+impl<S> Rev<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: DoubleEndedTryNext> TryNext for Rev<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S: DoubleEndedTryNext> DoubleEndedTryNext for Rev<S> {
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next()
+    }
+}
+
+impl<S: DoubleEndedTryNext + ExactSizeTryNext> ExactSizeTryNext for Rev<S> {}
+
+impl<S: DoubleEndedTryNext + FusedTryNext> FusedTryNext for Rev<S> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DoubleEndedTryNext, TryNext, TryNextExt};
+    use std::collections::VecDeque;
+
+    struct Deque(VecDeque<u8>);
+
+    impl TryNext for Deque {
+        type Item = u8;
+        type Error = ();
+
+        fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            Ok(self.0.pop_front())
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.len(), Some(self.0.len()))
+        }
+    }
+
+    impl DoubleEndedTryNext for Deque {
+        fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            Ok(self.0.pop_back())
+        }
+    }
+
+    #[test]
+    fn rev_swaps_ends() {
+        let mut src = Deque(VecDeque::from([1, 2, 3, 4])).rev();
+
+        assert_eq!(src.try_next(), Ok(Some(4)));
+        assert_eq!(src.try_next_back(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.try_next_back(), Ok(None));
+    }
+
+    #[test]
+    fn double_rev_is_identity() {
+        let mut src = Deque(VecDeque::from([1, 2])).rev().rev();
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.into_inner().into_inner().0, VecDeque::from([2]));
+    }
+
+    #[test]
+    fn rev_forwards_size_hint() {
+        let mut src = Deque(VecDeque::from([1, 2, 3])).rev();
+        assert_eq!(src.size_hint(), (3, Some(3)));
+        src.try_next().unwrap();
+        assert_eq!(src.size_hint(), (2, Some(2)));
+    }
+}
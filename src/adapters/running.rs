@@ -0,0 +1,137 @@
+use crate::{ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that pairs each item with a running state.
+///
+/// The state starts at `A::default()`. For each item, `fold` updates it in
+/// place and the item is yielded together with a clone of the new state.
+/// Errors pass through and leave the state as it was.
+///
+/// ```rust
+/// use try_next::{TryNextExt, from_items};
+///
+/// let records = from_items([b"abc".to_vec(), b"de".to_vec(), b"f".to_vec()]);
+/// let offsets: Vec<_> = records
+///     .running(|end: &mut usize, rec: &Vec<u8>| *end += rec.len())
+///     .try_collect()
+///     .unwrap();
+/// assert_eq!(
+///     offsets,
+///     [(b"abc".to_vec(), 3), (b"de".to_vec(), 5), (b"f".to_vec(), 6)]
+/// );
+/// ```
+///
+/// Created by [`TryNextExt::running`](crate::TryNextExt::running) and
+/// [`TryNextWithContextExt::running`](crate::TryNextWithContextExt::running).
+#[derive(Debug, Clone)]
+pub struct Running<S, A, F> {
+    source: S,
+    state: A,
+    fold: F,
+}
+
+impl<S, A: Default, F> Running<S, A, F> {
+    pub(crate) fn new(source: S, fold: F) -> Self {
+        Self {
+            source,
+            state: A::default(),
+            fold,
+        }
+    }
+}
+
+impl<S, A, F> Running<S, A, F> {
+    /// Returns the current state.
+    pub fn state(&self) -> &A {
+        &self.state
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn step<T>(&mut self, item: T) -> (T, A)
+    where
+        A: Clone,
+        F: FnMut(&mut A, &T),
+    {
+        (self.fold)(&mut self.state, &item);
+        (item, self.state.clone())
+    }
+}
+
+impl<S, A, F> TryNext for Running<S, A, F>
+where
+    S: TryNext,
+    A: Clone,
+    F: FnMut(&mut A, &S::Item),
+{
+    type Item = (S::Item, A);
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.source.try_next()?.map(|item| self.step(item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, A, F> ExactSizeTryNext for Running<S, A, F>
+where
+    S: ExactSizeTryNext,
+    A: Clone,
+    F: FnMut(&mut A, &S::Item),
+{
+}
+
+impl<S, A, F> FusedTryNext for Running<S, A, F>
+where
+    S: FusedTryNext,
+    A: Clone,
+    F: FnMut(&mut A, &S::Item),
+{
+}
+
+impl<S, A, F> TryNextWithContext for Running<S, A, F>
+where
+    S: TryNextWithContext,
+    A: Clone,
+    F: FnMut(&mut A, &S::Item),
+{
+    type Item = (S::Item, A);
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self
+            .source
+            .try_next_with_context(context)?
+            .map(|item| self.step(item)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn running_mean_skips_errors() {
+        let mut src = from_iter([Ok(2.0), Err('x'), Ok(4.0)]).running(
+            |(sum, n): &mut (f64, u32), x: &f64| {
+                *sum += x;
+                *n += 1;
+            },
+        );
+
+        assert_eq!(src.try_next(), Ok(Some((2.0, (2.0, 1)))));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.state(), &(2.0, 1));
+        assert_eq!(src.try_next(), Ok(Some((4.0, (6.0, 2)))));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
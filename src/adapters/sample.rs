@@ -0,0 +1,178 @@
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "rand")]
+use rand::rngs::Xoshiro256PlusPlus;
+#[cfg(feature = "rand")]
+use rand::{RngExt, SeedableRng};
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// Which items a [`Sample`] adapter keeps.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Sampling {
+    /// Keeps every `n`th item, starting with the first: items `0`, `n`,
+    /// `2n`, and so on.
+    EveryNth(usize),
+    /// Keeps each item with probability `p`, using a deterministic generator
+    /// seeded with `seed`.
+    ///
+    /// Available with the `rand` feature.
+    #[cfg(feature = "rand")]
+    Probability {
+        /// The probability of keeping an item.
+        p: f64,
+        /// The generator seed.
+        seed: u64,
+    },
+    /// Keeps an item only if none has been kept in the last `interval`.
+    Interval(Duration),
+}
+
+/// A source that yields only a sample of another source's items.
+///
+/// Items not selected by the [`Sampling`] mode are pulled and discarded.
+/// Errors are always passed through, so a low-volume tap still sees every
+/// failure.
+///
+/// Created by [`TryNextExt::sample`](crate::TryNextExt::sample) and
+/// [`TryNextWithContextExt::sample`](crate::TryNextWithContextExt::sample).
+#[derive(Debug, Clone)]
+pub struct Sample<S> {
+    source: S,
+    sampling: Sampling,
+    seen: usize,
+    last_kept: Option<Instant>,
+    #[cfg(feature = "rand")]
+    rng: Xoshiro256PlusPlus,
+}
+
+impl<S> Sample<S> {
+    pub(crate) fn new(source: S, sampling: Sampling) -> Self {
+        #[cfg(feature = "rand")]
+        let mut seed = 0;
+        match sampling {
+            Sampling::EveryNth(n) => assert!(n > 0, "sampling interval must be non-zero"),
+            #[cfg(feature = "rand")]
+            Sampling::Probability { p, seed: s } => {
+                assert!(
+                    (0.0..=1.0).contains(&p),
+                    "sampling probability {p} is outside 0.0..=1.0"
+                );
+                seed = s;
+            }
+            Sampling::Interval(_) => {}
+        }
+        Self {
+            source,
+            sampling,
+            seen: 0,
+            last_kept: None,
+            #[cfg(feature = "rand")]
+            rng: Xoshiro256PlusPlus::seed_from_u64(seed),
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn keep(&mut self) -> bool {
+        let index = self.seen;
+        self.seen += 1;
+        match self.sampling {
+            Sampling::EveryNth(n) => index.is_multiple_of(n),
+            #[cfg(feature = "rand")]
+            Sampling::Probability { p, .. } => self.rng.random_bool(p),
+            Sampling::Interval(interval) => {
+                let now = Instant::now();
+                let due = self
+                    .last_kept
+                    .is_none_or(|last| now.duration_since(last) >= interval);
+                if due {
+                    self.last_kept = Some(now);
+                }
+                due
+            }
+        }
+    }
+
+    fn select<T, E>(
+        &mut self,
+        mut pull: impl FnMut(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, E> {
+        while let Some(item) = pull(&mut self.source)? {
+            if self.keep() {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<S: TryNext> TryNext for Sample<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.select(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.source.size_hint().1)
+    }
+}
+
+impl<S: FusedTryNext> FusedTryNext for Sample<S> {}
+
+impl<S: TryNextWithContext> TryNextWithContext for Sample<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.select(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Sampling;
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn every_nth_keeps_errors() {
+        let items = [Ok(0), Ok(1), Err('x'), Ok(2), Ok(3), Ok(4)];
+        let mut src = from_iter(items).sample(Sampling::EveryNth(2));
+
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(4)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn interval_keeps_the_first_item_of_each_window() {
+        let mut src = from_items(0..1000).sample(Sampling::Interval(Duration::from_secs(3600)));
+        assert_eq!(src.try_collect::<Vec<_>>(), Ok(vec![0]));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn probability_is_seeded() {
+        let sampling = Sampling::Probability { p: 0.25, seed: 9 };
+        let a: Vec<u32> = from_items(0..400)
+            .sample(sampling.clone())
+            .try_collect()
+            .unwrap();
+        let b: Vec<u32> = from_items(0..400).sample(sampling).try_collect().unwrap();
+        assert_eq!(a, b);
+        assert!((50..150).contains(&a.len()));
+    }
+}
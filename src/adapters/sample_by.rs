@@ -0,0 +1,156 @@
+use rand::{Rng, RngExt};
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that keeps each item with a probability computed from the item.
+///
+/// For each item, `probability` returns the chance of keeping it. Values at
+/// or below `0.0`, and NaN, always drop the item; values at or above `1.0`
+/// always keep it. Dropped items are pulled and discarded, and errors are
+/// always passed through, as with [`Sample`](super::Sample).
+///
+/// ```rust
+/// use rand::SeedableRng;
+/// use rand::rngs::Xoshiro256PlusPlus;
+/// use try_next::{TryNextExt, from_items};
+///
+/// // Keep every error-level record and about one in ten of the rest.
+/// let records = from_items([("info", 1), ("error", 2), ("info", 3), ("error", 4)]);
+/// let rng = Xoshiro256PlusPlus::seed_from_u64(3);
+/// let tap: Vec<_> = records
+///     .sample_by(rng, |(level, _)| if *level == "error" { 1.0 } else { 0.1 })
+///     .try_collect()
+///     .unwrap();
+/// assert!(tap.contains(&("error", 2)) && tap.contains(&("error", 4)));
+/// ```
+///
+/// Available with the `rand` feature.
+///
+/// Created by [`TryNextExt::sample_by`](crate::TryNextExt::sample_by) and
+/// [`TryNextWithContextExt::sample_by`](crate::TryNextWithContextExt::sample_by).
+#[derive(Debug, Clone)]
+pub struct SampleBy<S, R, F> {
+    source: S,
+    rng: R,
+    probability: F,
+}
+
+impl<S, R, F> SampleBy<S, R, F> {
+    pub(crate) fn new(source: S, rng: R, probability: F) -> Self {
+        Self {
+            source,
+            rng,
+            probability,
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn select<T, E>(
+        &mut self,
+        mut pull: impl FnMut(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, E>
+    where
+        R: Rng,
+        F: FnMut(&T) -> f64,
+    {
+        while let Some(item) = pull(&mut self.source)? {
+            let p = (self.probability)(&item);
+            let keep = if p >= 1.0 {
+                true
+            } else if p > 0.0 {
+                self.rng.random_bool(p)
+            } else {
+                false
+            };
+            if keep {
+                return Ok(Some(item));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<S, R, F> TryNext for SampleBy<S, R, F>
+where
+    S: TryNext,
+    R: Rng,
+    F: FnMut(&S::Item) -> f64,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.select(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.source.size_hint().1)
+    }
+}
+
+impl<S, R, F> FusedTryNext for SampleBy<S, R, F>
+where
+    S: FusedTryNext,
+    R: Rng,
+    F: FnMut(&S::Item) -> f64,
+{
+}
+
+impl<S, R, F> TryNextWithContext for SampleBy<S, R, F>
+where
+    S: TryNextWithContext,
+    R: Rng,
+    F: FnMut(&S::Item) -> f64,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.select(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::Xoshiro256PlusPlus;
+
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn certain_and_impossible_weights_are_exact() {
+        let rng = Xoshiro256PlusPlus::seed_from_u64(5);
+        let mut src =
+            from_iter([Ok(1), Err('x'), Ok(2), Ok(3), Ok(4)]).sample_by(rng, |n| match n % 2 {
+                0 => 1.0,
+                _ => f64::NAN,
+            });
+
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(4)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn weights_follow_the_item() {
+        let rng = Xoshiro256PlusPlus::seed_from_u64(11);
+        let kept: Vec<u32> = from_items(0..2000)
+            .sample_by(rng, |n| if *n < 1000 { 0.1 } else { 0.9 })
+            .try_collect()
+            .unwrap();
+
+        let low = kept.iter().filter(|n| **n < 1000).count();
+        let high = kept.len() - low;
+        assert!((50..150).contains(&low), "{low}");
+        assert!((850..950).contains(&high), "{high}");
+    }
+}
@@ -0,0 +1,129 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::TryNext;
+use crate::replay::ReplayBuffer;
+
+/// A source that can be cloned, each clone reading the stream on its own.
+///
+/// The inner source is pulled at most once per item: whichever clone gets
+/// furthest pulls it and records it in a [`ReplayBuffer`], and the others
+/// read it from there when they catch up. Each clone keeps its own cursor,
+/// and a new clone starts where the original is, so clone before consuming
+/// to give several consumers the full stream. Errors go to the clone whose
+/// pull hit them and are not recorded.
+///
+/// The buffer is shared through an [`Rc`], so the clones belong to one
+/// thread and suit consumers that run one after another.
+///
+/// Created by [`TryNextExt::shared`](crate::TryNextExt::shared) and
+/// [`TryNextExt::shared_with`](crate::TryNextExt::shared_with).
+#[derive(Debug)]
+pub struct Shared<S, B> {
+    inner: Rc<RefCell<Inner<S, B>>>,
+    position: usize,
+}
+
+#[derive(Debug)]
+struct Inner<S, B> {
+    source: S,
+    buffer: B,
+}
+
+impl<S, B> Shared<S, B> {
+    pub(crate) fn new(source: S, buffer: B) -> Self {
+        Self {
+            inner: Rc::new(RefCell::new(Inner { source, buffer })),
+            position: 0,
+        }
+    }
+
+    /// Returns the number of items this clone has read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Moves this clone back to the first item.
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+}
+
+impl<S, B> Clone for Shared<S, B> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Rc::clone(&self.inner),
+            position: self.position,
+        }
+    }
+}
+
+impl<S, B> TryNext for Shared<S, B>
+where
+    S: TryNext,
+    B: ReplayBuffer<S::Item, S::Error>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let mut inner = self.inner.borrow_mut();
+        let item = if self.position < inner.buffer.len() {
+            inner.buffer.replay(self.position)?
+        } else {
+            let Some(item) = inner.source.try_next()? else {
+                return Ok(None);
+            };
+            inner.buffer.record(&item)?;
+            item
+        };
+        self.position += 1;
+        Ok(Some(item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let inner = self.inner.borrow();
+        let replay = inner.buffer.len() - self.position;
+        let (low, high) = inner.source.size_hint();
+        (
+            low.saturating_add(replay),
+            high.and_then(|high| high.checked_add(replay)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn clones_read_the_stream_independently() {
+        let mut validate = from_items(1..=3).shared();
+        let mut load = validate.clone();
+
+        assert_eq!(validate.try_next(), Ok(Some(1)));
+        assert_eq!(validate.try_next(), Ok(Some(2)));
+        let mut late = validate.clone();
+
+        assert_eq!(load.try_collect::<Vec<_>>(), Ok(vec![1, 2, 3]));
+        assert_eq!(late.try_collect::<Vec<_>>(), Ok(vec![3]));
+        assert_eq!(validate.size_hint(), (1, Some(1)));
+        assert_eq!(validate.try_next(), Ok(Some(3)));
+        assert_eq!(validate.try_next(), Ok(None));
+
+        validate.rewind();
+        assert_eq!(validate.position(), 0);
+        assert_eq!(validate.try_collect::<Vec<_>>(), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn errors_reach_only_the_clone_that_pulled_them() {
+        let mut first = from_iter([Ok(1), Err('x'), Ok(2)]).shared();
+        let mut second = first.clone();
+
+        assert_eq!(first.try_next(), Ok(Some(1)));
+        assert_eq!(first.try_next(), Err('x'));
+        assert_eq!(first.try_next(), Ok(Some(2)));
+        assert_eq!(second.try_collect::<Vec<_>>(), Ok(vec![1, 2]));
+    }
+}
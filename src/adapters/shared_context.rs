@@ -0,0 +1,157 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+use crate::{TryNext, TryNextWithContext};
+
+/// A [`TryNext`] that runs a context-aware source against a context shared
+/// through `Rc<RefCell<C>>`.
+///
+/// The context is mutably borrowed for the duration of each call only, so
+/// several sources in one single-threaded pipeline can share it.
+///
+/// # Panics
+///
+/// [`try_next`](TryNext::try_next) panics if the context is already borrowed,
+/// e.g. when the inner source itself tries to pull from another source that
+/// shares the same context.
+///
+/// Created by
+/// [`TryNextWithContextExt::with_shared_context`](crate::TryNextWithContextExt::with_shared_context).
+#[derive(Debug, Clone)]
+pub struct SharedContext<S, C: ?Sized> {
+    source: S,
+    context: Rc<RefCell<C>>,
+}
+
+impl<S, C: ?Sized> SharedContext<S, C> {
+    pub(crate) fn new(source: S, context: Rc<RefCell<C>>) -> Self {
+        Self { source, context }
+    }
+
+    /// Returns the shared context handle.
+    pub fn context(&self) -> &Rc<RefCell<C>> {
+        &self.context
+    }
+
+    /// Returns the underlying source and the shared context handle.
+    pub fn into_parts(self) -> (S, Rc<RefCell<C>>) {
+        (self.source, self.context)
+    }
+}
+
+impl<S> TryNext for SharedContext<S, S::Context>
+where
+    S: TryNextWithContext,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source
+            .try_next_with_context(&mut self.context.borrow_mut())
+    }
+}
+
+/// A [`TryNext`] that runs a context-aware source against a context shared
+/// through `Arc<Mutex<C>>`.
+///
+/// The mutex is locked for the duration of each call only, so sources on
+/// different threads can share one context.
+///
+/// # Panics
+///
+/// [`try_next`](TryNext::try_next) panics if the mutex is poisoned.
+///
+/// Created by
+/// [`TryNextWithContextExt::with_locked_context`](crate::TryNextWithContextExt::with_locked_context).
+#[derive(Debug, Clone)]
+pub struct LockedContext<S, C: ?Sized> {
+    source: S,
+    context: Arc<Mutex<C>>,
+}
+
+impl<S, C: ?Sized> LockedContext<S, C> {
+    pub(crate) fn new(source: S, context: Arc<Mutex<C>>) -> Self {
+        Self { source, context }
+    }
+
+    /// Returns the shared context handle.
+    pub fn context(&self) -> &Arc<Mutex<C>> {
+        &self.context
+    }
+
+    /// Returns the underlying source and the shared context handle.
+    pub fn into_parts(self) -> (S, Arc<Mutex<C>>) {
+        (self.source, self.context)
+    }
+}
+
+impl<S> TryNext for LockedContext<S, S::Context>
+where
+    S: TryNextWithContext,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let mut context = self.context.lock().expect("shared context mutex poisoned");
+        self.source.try_next_with_context(&mut context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextWithContext, TryNextWithContextExt};
+    use std::cell::RefCell;
+    use std::convert::Infallible;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Takes tickets from a shared counter.
+    struct Ticket;
+
+    impl TryNextWithContext for Ticket {
+        type Item = u32;
+        type Error = Infallible;
+        type Context = u32;
+
+        fn try_next_with_context(&mut self, next: &mut u32) -> Result<Option<u32>, Infallible> {
+            *next += 1;
+            Ok(Some(*next))
+        }
+    }
+
+    #[test]
+    fn rc_context_is_shared_between_sources() {
+        let ctx = Rc::new(RefCell::new(0));
+        let mut a = Ticket.with_shared_context(Rc::clone(&ctx));
+        let mut b = Ticket.with_shared_context(Rc::clone(&ctx));
+
+        assert_eq!(a.try_next(), Ok(Some(1)));
+        assert_eq!(b.try_next(), Ok(Some(2)));
+        assert_eq!(a.try_next(), Ok(Some(3)));
+        assert_eq!(*ctx.borrow(), 3);
+    }
+
+    #[test]
+    fn mutex_context_is_shared_across_threads() {
+        let ctx = Arc::new(Mutex::new(0));
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let mut src = Ticket.with_locked_context(Arc::clone(&ctx));
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        src.try_next().unwrap();
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        assert_eq!(*ctx.lock().unwrap(), 40);
+    }
+}
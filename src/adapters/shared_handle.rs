@@ -0,0 +1,159 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::{FusedTryNext, TryNext};
+
+/// A cloneable handle through which several threads pull from one source.
+///
+/// Clones share the source behind an `Arc<Mutex<_>>`, giving work-queue
+/// semantics: each item goes to exactly one handle, whichever pulled it.
+/// The contract is:
+///
+/// - Each call locks the source for one pull (or one
+///   [`try_next_chunk`](TryNext::try_next_chunk)), so items handed to a
+///   single handle keep their source order, but there is no ordering
+///   between handles.
+/// - An error goes to the handle whose pull produced it; the others are not
+///   told and keep pulling.
+/// - Once the source ends, every handle that pulls sees `Ok(None)` for as
+///   long as the source keeps returning it.
+/// - After a pull, the handle yields its thread, so a worker that handles
+///   items quickly does not re-take the lock ahead of the others waiting for
+///   it. This keeps sharing roughly even but is not a fairness guarantee.
+///
+/// # Panics
+///
+/// Pulls panic if the mutex is poisoned, that is, if another handle panicked
+/// while pulling.
+///
+/// ```rust
+/// use std::thread;
+/// use try_next::{TryNext, TryNextExt, from_items};
+///
+/// let queue = from_items(0..100).shared_handle();
+/// let workers: Vec<_> = (0..4)
+///     .map(|_| {
+///         let mut queue = queue.clone();
+///         thread::spawn(move || {
+///             let mut sum = 0;
+///             while let Some(job) = queue.try_next().unwrap() {
+///                 sum += job;
+///             }
+///             sum
+///         })
+///     })
+///     .collect();
+/// let total: u32 = workers.into_iter().map(|w| w.join().unwrap()).sum();
+/// assert_eq!(total, 4950);
+/// ```
+///
+/// Created by [`TryNextExt::shared_handle`](crate::TryNextExt::shared_handle).
+#[derive(Debug)]
+pub struct SharedHandle<S> {
+    source: Arc<Mutex<S>>,
+}
+
+impl<S> SharedHandle<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            source: Arc::new(Mutex::new(source)),
+        }
+    }
+
+    /// Returns the underlying source if this is the last handle, or the
+    /// handle back otherwise.
+    pub fn into_inner(self) -> Result<S, Self> {
+        Arc::try_unwrap(self.source)
+            .map(|source| source.into_inner().expect("shared source mutex poisoned"))
+            .map_err(|source| Self { source })
+    }
+
+    fn with_source<R>(&self, pull: impl FnOnce(&mut S) -> R) -> R {
+        let result = pull(&mut self.source.lock().expect("shared source mutex poisoned"));
+        thread::yield_now();
+        result
+    }
+}
+
+impl<S> Clone for SharedHandle<S> {
+    fn clone(&self) -> Self {
+        Self {
+            source: Arc::clone(&self.source),
+        }
+    }
+}
+
+impl<S: TryNext> TryNext for SharedHandle<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.with_source(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Other handles may take any of the remaining items.
+        let (_, upper) = self
+            .source
+            .lock()
+            .expect("shared source mutex poisoned")
+            .size_hint();
+        (0, upper)
+    }
+
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        self.with_source(|source| source.try_next_chunk(max))
+    }
+}
+
+impl<S: FusedTryNext> FusedTryNext for SharedHandle<S> {}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn each_item_goes_to_one_handle() {
+        let queue = from_items(0..1000).shared_handle();
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let mut queue = queue.clone();
+                thread::spawn(move || {
+                    let mut taken = Vec::new();
+                    loop {
+                        let chunk = queue.try_next_chunk(7).unwrap();
+                        if chunk.is_empty() {
+                            return taken;
+                        }
+                        assert!(chunk.windows(2).all(|w| w[0] < w[1]));
+                        taken.extend(chunk);
+                    }
+                })
+            })
+            .collect();
+
+        let mut all: Vec<u32> = workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect();
+        all.sort();
+        assert_eq!(all, (0..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn errors_go_to_the_pulling_handle() {
+        let mut a = from_iter([Ok(1), Err('x'), Ok(2)]).shared_handle();
+        let mut b = a.clone();
+
+        assert_eq!(a.try_next(), Ok(Some(1)));
+        assert_eq!(b.try_next(), Err('x'));
+        assert_eq!(a.try_next(), Ok(Some(2)));
+        assert_eq!(b.try_next(), Ok(None));
+
+        let a = a.into_inner().unwrap_err();
+        drop(b);
+        assert!(a.into_inner().is_ok());
+    }
+}
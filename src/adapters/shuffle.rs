@@ -0,0 +1,150 @@
+use rand::{Rng, RngExt};
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that yields items in a randomized order using a bounded buffer.
+///
+/// The buffer is first filled with up to `buffer_size` items. Each call then
+/// pulls one more item into the buffer, if the source has not ended, and
+/// yields a buffered item chosen uniformly at random. An item can therefore
+/// only move about `buffer_size` places earlier than its position in the
+/// source, so the result is an approximate shuffle of a stream. When the
+/// buffer can hold the whole source, for example with a `buffer_size` of
+/// `usize::MAX`, every order is equally likely.
+///
+/// If the inner source fails, the error is returned and the buffered items
+/// are kept.
+///
+/// ```rust
+/// use rand::SeedableRng;
+/// use rand::rngs::Xoshiro256PlusPlus;
+/// use try_next::{TryNextExt, from_items};
+///
+/// let rng = Xoshiro256PlusPlus::seed_from_u64(7);
+/// let mut shuffled: Vec<u32> = from_items(0..100).shuffle(16, rng).try_collect().unwrap();
+/// assert_ne!(shuffled, (0..100).collect::<Vec<_>>());
+///
+/// shuffled.sort();
+/// assert_eq!(shuffled, (0..100).collect::<Vec<_>>());
+/// ```
+///
+/// Available with the `rand` feature.
+///
+/// Created by [`TryNextExt::shuffle`](crate::TryNextExt::shuffle) and
+/// [`TryNextWithContextExt::shuffle`](crate::TryNextWithContextExt::shuffle).
+#[derive(Debug, Clone)]
+pub struct Shuffle<S, T, R> {
+    source: S,
+    rng: R,
+    size: usize,
+    buf: Vec<T>,
+    ended: bool,
+}
+
+impl<S, T, R> Shuffle<S, T, R> {
+    pub(crate) fn new(source: S, size: usize, rng: R) -> Self {
+        assert!(size > 0, "shuffle buffer size must be non-zero");
+        Self {
+            source,
+            rng,
+            size,
+            buf: Vec::new(),
+            ended: false,
+        }
+    }
+
+    /// Returns the underlying source, dropping any buffered items.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn pull<E>(
+        &mut self,
+        mut next: impl FnMut(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, E>
+    where
+        R: Rng,
+    {
+        // Top the buffer up to `size` items plus the one about to leave.
+        while !self.ended && self.buf.len() <= self.size {
+            match next(&mut self.source)? {
+                Some(item) => self.buf.push(item),
+                None => self.ended = true,
+            }
+        }
+        if self.buf.is_empty() {
+            return Ok(None);
+        }
+        let pick = self.rng.random_range(0..self.buf.len());
+        Ok(Some(self.buf.swap_remove(pick)))
+    }
+}
+
+impl<S: TryNext, R: Rng> TryNext for Shuffle<S, S::Item, R> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.ended {
+            return (self.buf.len(), Some(self.buf.len()));
+        }
+        let (lower, upper) = self.source.size_hint();
+        (
+            lower.saturating_add(self.buf.len()),
+            upper.and_then(|n| n.checked_add(self.buf.len())),
+        )
+    }
+}
+
+impl<S: TryNext, R: Rng> FusedTryNext for Shuffle<S, S::Item, R> {}
+
+impl<S: TryNextWithContext, R: Rng> TryNextWithContext for Shuffle<S, S::Item, R> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand::rngs::Xoshiro256PlusPlus;
+
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn items_move_at_most_a_buffer_earlier() {
+        let rng = Xoshiro256PlusPlus::seed_from_u64(1);
+        let out: Vec<usize> = from_items(0..200).shuffle(4, rng).try_collect().unwrap();
+
+        for (position, item) in out.iter().enumerate() {
+            assert!(*item <= position + 4, "{item} yielded at {position}");
+        }
+        let mut sorted = out.clone();
+        sorted.sort();
+        assert_eq!(sorted, (0..200).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn errors_keep_the_buffer() {
+        let rng = Xoshiro256PlusPlus::seed_from_u64(2);
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2)]).shuffle(8, rng);
+
+        assert_eq!(src.try_next(), Err('x'));
+        let mut rest = vec![src.try_next().unwrap().unwrap()];
+        rest.push(src.try_next().unwrap().unwrap());
+        rest.sort();
+        assert_eq!(rest, [1, 2]);
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
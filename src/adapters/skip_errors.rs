@@ -0,0 +1,108 @@
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that hands errors to a callback and keeps pulling.
+///
+/// The handler receives each error by value. Returning `Ok(())` skips the
+/// error and pulls again; returning `Err` passes the error on, which lets the
+/// handler enforce a cap or single out fatal errors. Only useful for sources
+/// that can recover after an error.
+///
+/// Created by [`TryNextExt::skip_errors`](crate::TryNextExt::skip_errors) and
+/// [`TryNextWithContextExt::skip_errors`](crate::TryNextWithContextExt::skip_errors).
+#[derive(Debug, Clone)]
+pub struct SkipErrors<S, F> {
+    source: S,
+    handler: F,
+}
+
+impl<S, F> SkipErrors<S, F> {
+    pub(crate) fn new(source: S, handler: F) -> Self {
+        Self { source, handler }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, F> TryNext for SkipErrors<S, F>
+where
+    S: TryNext,
+    F: FnMut(S::Error) -> Result<(), S::Error>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        loop {
+            match self.source.try_next() {
+                Err(err) => (self.handler)(err)?,
+                result => return result,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, F> FusedTryNext for SkipErrors<S, F>
+where
+    S: FusedTryNext,
+    F: FnMut(S::Error) -> Result<(), S::Error>,
+{
+}
+
+impl<S, F> TryNextWithContext for SkipErrors<S, F>
+where
+    S: TryNextWithContext,
+    F: FnMut(S::Error) -> Result<(), S::Error>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        loop {
+            match self.source.try_next_with_context(context) {
+                Err(err) => (self.handler)(err)?,
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn logs_and_continues() {
+        let mut log = Vec::new();
+        let mut src = from_iter([Ok(1), Err('a'), Err('b'), Ok(2)]).skip_errors(|e| {
+            log.push(e);
+            Ok(())
+        });
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(log, ['a', 'b']);
+    }
+
+    #[test]
+    fn handler_can_stop_on_fatal_errors() {
+        let items = [Err("bad row"), Ok(1), Err("disk full"), Ok(2)];
+        let mut src =
+            from_iter(items).skip_errors(|e| if e == "disk full" { Err(e) } else { Ok(()) });
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err("disk full"));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{TryNext, TryNextWithContext};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Live,
+    Ended,
+    Failed,
+}
+
+/// A source that enforces "no polling after the end or an error".
+///
+/// Once the inner source has returned `Ok(None)` or an error, the adapter
+/// never polls it again. Further calls panic in debug builds, pointing at
+/// the caller that ignored the end of the sequence; in release builds they
+/// return `Ok(None)`.
+///
+/// Created by [`TryNextExt::strict`](crate::TryNextExt::strict) and
+/// [`TryNextWithContextExt::strict`](crate::TryNextWithContextExt::strict).
+#[derive(Debug, Clone)]
+pub struct Strict<S> {
+    source: S,
+    state: State,
+}
+
+impl<S> Strict<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            source,
+            state: State::Live,
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn check<T, E>(
+        &mut self,
+        pull: impl FnOnce(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, E> {
+        match self.state {
+            State::Live => {}
+            State::Ended => {
+                debug_assert!(false, "source polled after it returned `Ok(None)`");
+                return Ok(None);
+            }
+            State::Failed => {
+                debug_assert!(false, "source polled after it returned an error");
+                return Ok(None);
+            }
+        }
+        let result = pull(&mut self.source);
+        self.state = match &result {
+            Ok(Some(_)) => State::Live,
+            Ok(None) => State::Ended,
+            Err(_) => State::Failed,
+        };
+        result
+    }
+}
+
+impl<S: TryNext> TryNext for Strict<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.check(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.state {
+            State::Live => self.source.size_hint(),
+            State::Ended | State::Failed => (0, Some(0)),
+        }
+    }
+}
+
+impl<S: TryNextWithContext> TryNextWithContext for Strict<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.check(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn passes_items_through_until_the_end() {
+        let mut src = from_iter([Ok(1), Ok(2)]).strict();
+
+        assert_eq!(src.try_next(), Ok::<_, ()>(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "source polled after it returned an error")]
+    fn panics_when_polled_after_an_error() {
+        let mut src = from_iter([Err('x'), Ok(1)]).strict();
+
+        assert_eq!(src.try_next(), Err('x'));
+        let _ = src.try_next();
+    }
+}
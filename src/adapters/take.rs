@@ -0,0 +1,150 @@
+use crate::{DoubleEndedTryNext, ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that yields at most `n` items.
+///
+/// Once `n` items have been yielded, the inner source is no longer polled.
+/// Errors do not count towards `n`.
+///
+/// Created by [`TryNextExt::take`](crate::TryNextExt::take) and
+/// [`TryNextWithContextExt::take`](crate::TryNextWithContextExt::take).
+#[derive(Debug, Clone)]
+pub struct Take<S> {
+    source: S,
+    remaining: usize,
+}
+
+impl<S> Take<S> {
+    pub(crate) fn new(source: S, n: usize) -> Self {
+        Self {
+            source,
+            remaining: n,
+        }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S: TryNext> TryNext for Take<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let item = self.source.try_next()?;
+        if item.is_some() {
+            self.remaining -= 1;
+        }
+        Ok(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remaining == 0 {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.source.size_hint();
+        let upper = match upper {
+            Some(upper) => upper.min(self.remaining),
+            None => self.remaining,
+        };
+        (lower.min(self.remaining), Some(upper))
+    }
+
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        let chunk = self.source.try_next_chunk(max.min(self.remaining))?;
+        self.remaining -= chunk.len();
+        Ok(chunk)
+    }
+}
+
+/// Pulls the items beyond the first `n` off the back of the source and drops
+/// them before yielding, so the back of a `Take` is its `n`-th item.
+impl<S> DoubleEndedTryNext for Take<S>
+where
+    S: DoubleEndedTryNext + ExactSizeTryNext,
+{
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        while self.source.len() > self.remaining {
+            if self.source.try_next_back()?.is_none() {
+                return Ok(None);
+            }
+        }
+        let item = self.source.try_next_back()?;
+        if item.is_some() {
+            self.remaining -= 1;
+        }
+        Ok(item)
+    }
+}
+
+impl<S: ExactSizeTryNext> ExactSizeTryNext for Take<S> {}
+
+impl<S: FusedTryNext> FusedTryNext for Take<S> {}
+
+impl<S: TryNextWithContext> TryNextWithContext for Take<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        let item = self.source.try_next_with_context(context)?;
+        if item.is_some() {
+            self.remaining -= 1;
+        }
+        Ok(item)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::fixtures::Pop;
+    use crate::{
+        DoubleEndedTryNext, TryNext, TryNextExt, TryNextWithContext, TryNextWithContextExt,
+        from_items,
+    };
+
+    #[test]
+    fn stops_after_n_items() {
+        let mut src = from_items([1, 2, 3, 4]).take(3);
+
+        assert_eq!(src.size_hint(), (3, Some(3)));
+        assert_eq!(src.try_next_chunk(2), Ok(vec![1, 2]));
+        assert_eq!(src.try_next_chunk(2), Ok(vec![3]));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.into_inner().try_next(), Ok(Some(4)));
+    }
+
+    #[test]
+    fn drops_the_excess_from_the_back() {
+        let mut src = from_items([1, 2, 3, 4, 5]).take(3);
+
+        assert_eq!(src.try_next_back(), Ok(Some(3)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next_back(), Ok(Some(2)));
+        assert_eq!(src.try_next_back(), Ok(None));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn leaves_the_rest_in_the_context() {
+        let mut src = Pop.take(1);
+        let mut stack = vec![1, 2, 3];
+
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(Some(3)));
+        assert_eq!(src.try_next_with_context(&mut stack), Ok(None));
+        assert_eq!(stack, [1, 2]);
+    }
+}
@@ -0,0 +1,182 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// The error type of a [`TakeExact`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TakeExactError<E> {
+    /// The inner source failed.
+    Source(E),
+    /// The inner source ended before yielding the expected number of items.
+    Short {
+        /// The number of items that were required.
+        expected: usize,
+        /// The number of items the source yielded before it ended.
+        received: usize,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for TakeExactError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(e) => write!(f, "source error: {e}"),
+            Self::Short { expected, received } => {
+                write!(
+                    f,
+                    "expected {expected} items, but source ended after {received}"
+                )
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for TakeExactError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Source(e) => Some(e),
+            Self::Short { .. } => None,
+        }
+    }
+}
+
+/// A source that yields exactly `n` items, or reports a shortfall.
+///
+/// Like [`Take`](super::Take), it stops polling the inner source after `n`
+/// items, and errors do not count towards `n`. If the inner source ends
+/// early, the end is returned as [`TakeExactError::Short`] with the number
+/// of items received, and `Ok(None)` follows.
+///
+/// ```rust
+/// use try_next::adapters::TakeExactError;
+/// use try_next::{TryNextExt, from_items};
+///
+/// let header: Vec<u8> = from_items(0..16).take_exact(4).try_collect().unwrap();
+/// assert_eq!(header, [0, 1, 2, 3]);
+///
+/// let short = from_items(0..3).take_exact(4).try_collect::<Vec<u8>>();
+/// assert_eq!(short, Err(TakeExactError::Short { expected: 4, received: 3 }));
+/// ```
+///
+/// Created by [`TryNextExt::take_exact`](crate::TryNextExt::take_exact) and
+/// [`TryNextWithContextExt::take_exact`](crate::TryNextWithContextExt::take_exact).
+#[derive(Debug, Clone)]
+pub struct TakeExact<S> {
+    source: S,
+    expected: usize,
+    received: usize,
+    short: bool,
+}
+
+impl<S> TakeExact<S> {
+    pub(crate) fn new(source: S, n: usize) -> Self {
+        Self {
+            source,
+            expected: n,
+            received: 0,
+            short: false,
+        }
+    }
+
+    /// Returns the number of items yielded so far.
+    pub fn received(&self) -> usize {
+        self.received
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn pull<T, E>(
+        &mut self,
+        next: impl FnOnce(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, TakeExactError<E>> {
+        if self.short || self.received == self.expected {
+            return Ok(None);
+        }
+        match next(&mut self.source).map_err(TakeExactError::Source)? {
+            Some(item) => {
+                self.received += 1;
+                Ok(Some(item))
+            }
+            None => {
+                self.short = true;
+                Err(TakeExactError::Short {
+                    expected: self.expected,
+                    received: self.received,
+                })
+            }
+        }
+    }
+}
+
+impl<S: TryNext> TryNext for TakeExact<S> {
+    type Item = S::Item;
+    type Error = TakeExactError<S::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.short {
+            return (0, Some(0));
+        }
+        let remaining = self.expected - self.received;
+        let (lower, upper) = self.source.size_hint();
+        (
+            lower.min(remaining),
+            Some(upper.map_or(remaining, |upper| upper.min(remaining))),
+        )
+    }
+}
+
+impl<S: TryNext> FusedTryNext for TakeExact<S> {}
+
+impl<S: TryNextWithContext> TryNextWithContext for TakeExact<S> {
+    type Item = S::Item;
+    type Error = TakeExactError<S::Error>;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TakeExactError;
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    #[test]
+    fn errors_do_not_count_and_shortfall_is_reported_once() {
+        let mut src = from_iter([Ok(1), Err('x'), Ok(2)]).take_exact(3);
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err(TakeExactError::Source('x')));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(
+            src.try_next(),
+            Err(TakeExactError::Short {
+                expected: 3,
+                received: 2
+            })
+        );
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.received(), 2);
+    }
+
+    #[test]
+    fn stops_polling_after_n_items() {
+        let mut src = from_iter([Ok(1), Ok(2), Err('x')]).take_exact(2);
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.into_inner().try_next(), Err('x'));
+    }
+}
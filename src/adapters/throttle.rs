@@ -0,0 +1,140 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{FusedTryNext, TryNext, TryNextWithContext};
+
+/// A source that caps how many items per second it yields.
+///
+/// Uses a token bucket holding up to `burst` tokens (one by default) and
+/// refilling at `rate` tokens per second; the bucket starts full. Each item
+/// takes a token, and when none is left the adapter sleeps until one is
+/// available before returning the item. Errors and the end of input are
+/// returned without delay and cost nothing.
+///
+/// Created by [`TryNextExt::throttle`](crate::TryNextExt::throttle) and
+/// [`TryNextWithContextExt::throttle`](crate::TryNextWithContextExt::throttle).
+#[derive(Debug, Clone)]
+pub struct Throttle<S> {
+    source: S,
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    refilled: Instant,
+}
+
+impl<S> Throttle<S> {
+    pub(crate) fn new(source: S, rate: f64) -> Self {
+        assert!(
+            rate.is_finite() && rate > 0.0,
+            "throttle rate must be positive and finite"
+        );
+        Self {
+            source,
+            rate,
+            burst: 1.0,
+            tokens: 1.0,
+            refilled: Instant::now(),
+        }
+    }
+
+    /// Lets up to `burst` items through back to back after an idle period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `burst` is zero.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        assert!(burst > 0, "throttle burst must be non-zero");
+        self.burst = f64::from(burst);
+        self.tokens = self.burst;
+        self
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn take_token(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.refilled).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.refilled = now;
+        if self.tokens < 1.0 {
+            let wait = Duration::from_secs_f64((1.0 - self.tokens) / self.rate);
+            thread::sleep(wait);
+            self.tokens = 1.0;
+            self.refilled += wait;
+        }
+        self.tokens -= 1.0;
+    }
+
+    fn pace<T, E>(&mut self, result: Result<Option<T>, E>) -> Result<Option<T>, E> {
+        if let Ok(Some(_)) = result {
+            self.take_token();
+        }
+        result
+    }
+}
+
+impl<S: TryNext> TryNext for Throttle<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next();
+        self.pace(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S: FusedTryNext> FusedTryNext for Throttle<S> {}
+
+impl<S: TryNextWithContext> TryNextWithContext for Throttle<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let result = self.source.try_next_with_context(context);
+        self.pace(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn spaces_items_at_the_rate() {
+        let mut src = from_items(0..5).throttle(200.0);
+
+        let started = Instant::now();
+        assert_eq!(src.try_collect::<Vec<_>>(), Ok(vec![0, 1, 2, 3, 4]));
+        // The first item uses the initial token; four more need 5ms each.
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn bursts_pass_immediately_and_errors_are_free() {
+        let mut src = from_iter([Ok(1), Err('x'), Err('y'), Ok(2), Ok(3)])
+            .throttle(1.0)
+            .with_burst(3);
+
+        let started = Instant::now();
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Err('y'));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert!(started.elapsed() < Duration::from_millis(500));
+    }
+}
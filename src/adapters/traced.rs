@@ -0,0 +1,274 @@
+use std::fmt::Debug;
+
+use tracing::{Level, Span};
+
+use crate::{TryNext, TryNextWithContext};
+
+/// Emits an event at a level chosen at runtime.
+macro_rules! event_at {
+    ($level:expr, $($arg:tt)+) => {
+        match $level {
+            Level::ERROR => tracing::error!($($arg)+),
+            Level::WARN => tracing::warn!($($arg)+),
+            Level::INFO => tracing::info!($($arg)+),
+            Level::DEBUG => tracing::debug!($($arg)+),
+            _ => tracing::trace!($($arg)+),
+        }
+    };
+}
+
+/// A source that runs every pull inside a [`tracing`] span and emits events
+/// for errors, termination, and optionally items.
+///
+/// The span passed at creation stands for the pipeline stage; it is entered
+/// around each call to the inner source, so events the source emits itself
+/// are attributed to the stage too. [`with_pull_spans`](Self::with_pull_spans)
+/// adds a `pull` span per call on top.
+///
+/// By default errors are reported at `WARN`, since a downstream adapter may
+/// still recover from them, and the end of the sequence at `DEBUG`. Items
+/// produce no events unless [`with_item_fields`](Self::with_item_fields) is
+/// used.
+///
+/// Available with the `tracing` feature.
+///
+/// ```rust
+/// use try_next::{TryNext, TryNextExt, from_iter};
+/// use tracing::{Level, info_span};
+///
+/// let mut src = from_iter([Ok((7, "a")), Err("bad")])
+///     .traced(info_span!("ingest"))
+///     .with_error_level(Level::ERROR)
+///     .with_item_fields(Level::DEBUG, |&(id, _)| id);
+///
+/// assert_eq!(src.try_next(), Ok(Some((7, "a"))));
+/// assert_eq!(src.try_next(), Err("bad"));
+/// ```
+///
+/// Created by [`TryNextExt::traced`](crate::TryNextExt::traced) and
+/// [`TryNextWithContextExt::traced`](crate::TryNextWithContextExt::traced).
+#[derive(Debug, Clone)]
+pub struct Traced<S, F> {
+    source: S,
+    span: Span,
+    fields: Option<F>,
+    item_level: Level,
+    error_level: Level,
+    end_level: Level,
+    pull_spans: bool,
+    pulls: u64,
+    items: u64,
+}
+
+impl<S, F> Traced<S, F> {
+    pub(crate) fn new(source: S, span: Span) -> Self {
+        Self {
+            source,
+            span,
+            fields: None,
+            item_level: Level::TRACE,
+            error_level: Level::WARN,
+            end_level: Level::DEBUG,
+            pull_spans: false,
+            pulls: 0,
+            items: 0,
+        }
+    }
+
+    /// Sets the level of the event emitted for each error.
+    pub fn with_error_level(mut self, level: Level) -> Self {
+        self.error_level = level;
+        self
+    }
+
+    /// Sets the level of the event emitted for each `Ok(None)`.
+    pub fn with_end_level(mut self, level: Level) -> Self {
+        self.end_level = level;
+        self
+    }
+
+    /// Opens a `TRACE` span named `pull`, carrying the call index, around
+    /// each call to the inner source.
+    pub fn with_pull_spans(mut self) -> Self {
+        self.pull_spans = true;
+        self
+    }
+
+    /// Emits an event at `level` for each item, with the value extracted by
+    /// `f` recorded as its `fields` field.
+    pub fn with_item_fields<G, T, V>(self, level: Level, f: G) -> Traced<S, G>
+    where
+        G: FnMut(&T) -> V,
+    {
+        Traced {
+            source: self.source,
+            span: self.span,
+            fields: Some(f),
+            item_level: level,
+            error_level: self.error_level,
+            end_level: self.end_level,
+            pull_spans: self.pull_spans,
+            pulls: self.pulls,
+            items: self.items,
+        }
+    }
+
+    /// Returns the stage span.
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn traced<T, E, V>(
+        &mut self,
+        pull: impl FnOnce(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<T>, E>
+    where
+        E: Debug,
+        F: FnMut(&T) -> V,
+        V: Debug,
+    {
+        let _stage = self.span.clone().entered();
+        let index = self.pulls;
+        self.pulls += 1;
+        let _pull = self
+            .pull_spans
+            .then(|| tracing::trace_span!("pull", index).entered());
+
+        let result = pull(&mut self.source);
+        match &result {
+            Ok(Some(item)) => {
+                self.items += 1;
+                if let Some(f) = &mut self.fields {
+                    let fields = f(item);
+                    event_at!(self.item_level, index, fields = ?fields, "item");
+                }
+            }
+            Ok(None) => event_at!(self.end_level, index, items = self.items, "source ended"),
+            Err(error) => event_at!(self.error_level, index, error = ?error, "source failed"),
+        }
+        result
+    }
+}
+
+impl<S, F, V> TryNext for Traced<S, F>
+where
+    S: TryNext,
+    S::Error: Debug,
+    F: FnMut(&S::Item) -> V,
+    V: Debug,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.traced(S::try_next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+impl<S, F, V> TryNextWithContext for Traced<S, F>
+where
+    S: TryNextWithContext,
+    S::Error: Debug,
+    F: FnMut(&S::Item) -> V,
+    V: Debug,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.traced(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::{Debug, Write};
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Level, Metadata, Subscriber, info_span};
+
+    use crate::{TryNext, TryNextExt, from_iter};
+
+    /// Collects one line per span and event.
+    #[derive(Clone, Default)]
+    struct Lines(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Lines {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            let mut lines = self.0.lock().unwrap();
+            let line = lines.last_mut().unwrap();
+            let _ = write!(line, " {}={value:?}", field.name());
+        }
+    }
+
+    impl Subscriber for Lines {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("span {}", span.metadata().name()));
+            span.record(&mut self.clone());
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            self.0
+                .lock()
+                .unwrap()
+                .push(event.metadata().level().to_string());
+            event.record(&mut self.clone());
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn reports_items_errors_and_end() {
+        let lines = Lines::default();
+        tracing::subscriber::with_default(lines.clone(), || {
+            let mut src = from_iter([Ok(3), Err('x')])
+                .traced(info_span!("stage"))
+                .with_item_fields(Level::INFO, |n| n * 2)
+                .with_pull_spans();
+            while !matches!(src.try_next(), Ok(None)) {}
+        });
+
+        assert_eq!(
+            *lines.0.lock().unwrap(),
+            [
+                "span stage",
+                "span pull index=0",
+                "INFO message=item index=0 fields=6",
+                "span pull index=1",
+                "WARN message=source failed index=1 error='x'",
+                "span pull index=2",
+                "DEBUG message=source ended index=2 items=1",
+            ]
+        );
+    }
+}
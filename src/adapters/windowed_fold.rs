@@ -0,0 +1,221 @@
+use crate::{TryNext, TryNextWithContext};
+
+/// Decides where the windows of a [`WindowedFold`] begin and end.
+///
+/// Implemented by `usize` for windows of a fixed number of items, and by
+/// [`ByKey`] for windows of consecutive items sharing a key.
+pub trait Windowing<T> {
+    /// Returns `true` if `item` must start a new window, closing the
+    /// current one first.
+    fn starts_window(&mut self, item: &T) -> bool;
+
+    /// Returns `true` if the current window is complete after `len` items.
+    ///
+    /// The default implementation returns `false`.
+    fn is_full(&self, len: usize) -> bool {
+        let _ = len;
+        false
+    }
+}
+
+impl<T> Windowing<T> for usize {
+    fn starts_window(&mut self, _: &T) -> bool {
+        false
+    }
+
+    fn is_full(&self, len: usize) -> bool {
+        assert!(*self > 0, "window size must be non-zero");
+        len >= *self
+    }
+}
+
+/// Windows of consecutive items with equal keys, starting a new window
+/// whenever the key changes.
+#[derive(Debug, Clone)]
+pub struct ByKey<F, K> {
+    key: F,
+    last: Option<K>,
+}
+
+impl<F, K> ByKey<F, K> {
+    /// Creates key-change windows keyed by `key`.
+    pub fn new(key: F) -> Self {
+        Self { key, last: None }
+    }
+}
+
+impl<T, F, K> Windowing<T> for ByKey<F, K>
+where
+    F: FnMut(&T) -> K,
+    K: PartialEq,
+{
+    fn starts_window(&mut self, item: &T) -> bool {
+        let key = (self.key)(item);
+        let changed = self.last.as_ref().is_some_and(|last| *last != key);
+        self.last = Some(key);
+        changed
+    }
+}
+
+/// A source that folds the items of each window into one aggregate.
+///
+/// Each window starts from a clone of `init` and folds its items in with
+/// `fold`; its aggregate is yielded as soon as the window closes, and the
+/// last, possibly partial, window is yielded at the end of input. An empty
+/// source yields nothing. If the inner source fails mid-window, the error
+/// is returned and the aggregate so far is kept, so the next call continues
+/// the same window.
+///
+/// ```rust
+/// use try_next::adapters::ByKey;
+/// use try_next::{TryNextExt, from_items};
+///
+/// let sums: Vec<u32> = from_items(1..=7)
+///     .windowed_fold(3, 0, |sum, x| sum + x)
+///     .try_collect()
+///     .unwrap();
+/// assert_eq!(sums, [6, 15, 7]);
+///
+/// let readings = from_items([('a', 1), ('a', 5), ('b', 2), ('a', 4)]);
+/// let peaks: Vec<u32> = readings
+///     .windowed_fold(ByKey::new(|r: &(char, u32)| r.0), 0, |max, r| max.max(r.1))
+///     .try_collect()
+///     .unwrap();
+/// assert_eq!(peaks, [5, 2, 4]);
+/// ```
+///
+/// Created by [`TryNextExt::windowed_fold`](crate::TryNextExt::windowed_fold)
+/// and [`TryNextWithContextExt::windowed_fold`](crate::TryNextWithContextExt::windowed_fold).
+#[derive(Debug, Clone)]
+pub struct WindowedFold<S, W, A, F> {
+    source: S,
+    window: W,
+    init: A,
+    fold: F,
+    acc: Option<A>,
+    len: usize,
+}
+
+impl<S, W, A, F> WindowedFold<S, W, A, F> {
+    pub(crate) fn new(source: S, window: W, init: A, fold: F) -> Self {
+        Self {
+            source,
+            window,
+            init,
+            fold,
+            acc: None,
+            len: 0,
+        }
+    }
+
+    /// Returns the underlying source, dropping the current window's
+    /// aggregate.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    fn pull<T, E>(
+        &mut self,
+        mut next: impl FnMut(&mut S) -> Result<Option<T>, E>,
+    ) -> Result<Option<A>, E>
+    where
+        W: Windowing<T>,
+        A: Clone,
+        F: FnMut(A, T) -> A,
+    {
+        loop {
+            let Some(item) = next(&mut self.source)? else {
+                self.len = 0;
+                return Ok(self.acc.take());
+            };
+            let closed = if self.window.starts_window(&item) {
+                self.len = 0;
+                self.acc.take()
+            } else {
+                None
+            };
+            let acc = self.acc.take().unwrap_or_else(|| self.init.clone());
+            self.acc = Some((self.fold)(acc, item));
+            self.len += 1;
+            if closed.is_some() {
+                return Ok(closed);
+            }
+            if self.window.is_full(self.len) {
+                self.len = 0;
+                return Ok(self.acc.take());
+            }
+        }
+    }
+}
+
+impl<S, W, A, F> TryNext for WindowedFold<S, W, A, F>
+where
+    S: TryNext,
+    W: Windowing<S::Item>,
+    A: Clone,
+    F: FnMut(A, S::Item) -> A,
+{
+    type Item = A;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(S::try_next)
+    }
+}
+
+impl<S, W, A, F> TryNextWithContext for WindowedFold<S, W, A, F>
+where
+    S: TryNextWithContext,
+    W: Windowing<S::Item>,
+    A: Clone,
+    F: FnMut(A, S::Item) -> A,
+{
+    type Item = A;
+    type Error = S::Error;
+    type Context = S::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        self.pull(|source| source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ByKey;
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn errors_keep_the_window_open() {
+        let mut src = from_iter([Ok(1), Ok(2), Err('x'), Ok(3), Ok(4)]).windowed_fold(
+            3,
+            Vec::new(),
+            |mut w, x| {
+                w.push(x);
+                w
+            },
+        );
+
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(vec![1, 2, 3])));
+        assert_eq!(src.try_next(), Ok(Some(vec![4])));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn key_change_windows_count_runs() {
+        let runs: Vec<usize> = from_items("aaabccdd".chars())
+            .windowed_fold(ByKey::new(|c: &char| *c), 0, |n, _| n + 1)
+            .try_collect()
+            .unwrap();
+        assert_eq!(runs, [3, 1, 2, 2]);
+    }
+
+    #[test]
+    fn empty_source_yields_nothing() {
+        let mut src = from_items(Vec::<u32>::new()).windowed_fold(2, 0, |a, x| a + x);
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{TryNext, TryNextWithContext};
+
+/// A [`TryNext`] that owns the context of a [`TryNextWithContext`].
+///
+/// Every call to [`try_next`](TryNext::try_next) hands a mutable reference to
+/// the owned context to the inner source. The context can be inspected while
+/// the adapter is in use and recovered with [`into_parts`](Self::into_parts).
+///
+/// Created by
+/// [`TryNextWithContextExt::with_context`](crate::TryNextWithContextExt::with_context).
+#[derive(Debug, Clone)]
+pub struct WithContext<S, C> {
+    source: S,
+    context: C,
+}
+
+impl<S, C> WithContext<S, C> {
+    pub(crate) fn new(source: S, context: C) -> Self {
+        Self { source, context }
+    }
+
+    /// Returns a reference to the owned context.
+    pub fn context(&self) -> &C {
+        &self.context
+    }
+
+    /// Returns a mutable reference to the owned context.
+    pub fn context_mut(&mut self) -> &mut C {
+        &mut self.context
+    }
+
+    /// Returns the underlying source and the context.
+    pub fn into_parts(self) -> (S, C) {
+        (self.source, self.context)
+    }
+}
+
+impl<S> TryNext for WithContext<S, S::Context>
+where
+    S: TryNextWithContext<Context: Sized>,
+{
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.source.try_next_with_context(&mut self.context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt, TryNextWithContext, TryNextWithContextExt};
+    use std::convert::Infallible;
+
+    /// Yields the words of its input, counting them in the context.
+    struct Words(std::vec::IntoIter<&'static str>);
+
+    impl TryNextWithContext for Words {
+        type Item = &'static str;
+        type Error = Infallible;
+        type Context = usize;
+
+        fn try_next_with_context(
+            &mut self,
+            count: &mut usize,
+        ) -> Result<Option<Self::Item>, Self::Error> {
+            let word = self.0.next();
+            *count += word.is_some() as usize;
+            Ok(word)
+        }
+    }
+
+    #[test]
+    fn owns_and_returns_the_context() {
+        let mut src = Words(vec!["a", "b", "c"].into_iter()).with_context(0);
+
+        assert_eq!(src.try_next(), Ok(Some("a")));
+        assert_eq!(*src.context(), 1);
+
+        let mut rest = (&mut src).fuse();
+        assert_eq!(rest.try_next(), Ok(Some("b")));
+        assert_eq!(rest.try_next(), Ok(Some("c")));
+        assert_eq!(rest.try_next(), Ok(None));
+
+        let (_, count) = src.into_parts();
+        assert_eq!(count, 3);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{EitherError, TryNextWithContext};
+
+/// A source that yields pairs of items from `A` and `B`.
+///
+/// The sequence ends as soon as either side ends. `A` is always polled
+/// first; if `A` yields an item and `B` then ends, that item is discarded.
+/// If `B` fails instead, the item is kept and paired with `B`'s next item,
+/// so an error on either side never shifts the pairs.
+///
+/// As a [`TryNextWithContext`], the combined context is the pair
+/// `(A::Context, B::Context)` and the combined error is
+/// [`EitherError<A::Error, B::Error>`](EitherError).
+///
+/// Created by [`TryNextWithContextExt::zip`](crate::TryNextWithContextExt::zip).
+#[derive(Debug, Clone)]
+pub struct Zip<A: TryNextWithContext, B> {
+    first: A,
+    second: B,
+    pending: Option<A::Item>,
+}
+
+impl<A: TryNextWithContext, B> Zip<A, B> {
+    pub(crate) fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            pending: None,
+        }
+    }
+
+    /// Returns the two underlying sources, dropping an item of `A` still
+    /// waiting for its pair.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A, B> TryNextWithContext for Zip<A, B>
+where
+    A: TryNextWithContext<Context: Sized>,
+    B: TryNextWithContext<Context: Sized>,
+{
+    type Item = (A::Item, B::Item);
+    type Error = EitherError<A::Error, B::Error>;
+    type Context = (A::Context, B::Context);
+
+    fn try_next_with_context(
+        &mut self,
+        (first, second): &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        let a = match self.pending.take() {
+            Some(a) => a,
+            None => match self
+                .first
+                .try_next_with_context(first)
+                .map_err(EitherError::Left)?
+            {
+                Some(a) => a,
+                None => return Ok(None),
+            },
+        };
+        match self.second.try_next_with_context(second) {
+            Ok(Some(b)) => Ok(Some((a, b))),
+            Ok(None) => Ok(None),
+            Err(e) => {
+                self.pending = Some(a);
+                Err(EitherError::Right(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::fixtures::{Pop, Upto, drain};
+    use crate::{EitherError, TryNextExt, TryNextWithContext, TryNextWithContextExt, from_iter};
+
+    #[test]
+    fn zips_until_the_shorter_side_ends() {
+        let mut src = Upto(0).zip(Pop);
+        let mut ctx = (5, vec![7, 8]);
+
+        assert_eq!(drain(&mut src, &mut ctx), [(1, 8), (2, 7)]);
+    }
+
+    #[test]
+    fn right_errors_keep_the_left_item() {
+        let right = from_iter([Ok(10), Err('x'), Ok(20), Ok(30)]).ignore_context::<()>();
+        let mut src = Upto(0).zip(right);
+        let mut ctx = (3, ());
+
+        assert_eq!(src.try_next_with_context(&mut ctx), Ok(Some((1, 10))));
+        assert_eq!(
+            src.try_next_with_context(&mut ctx),
+            Err(EitherError::Right('x'))
+        );
+        assert_eq!(src.try_next_with_context(&mut ctx), Ok(Some((2, 20))));
+        assert_eq!(src.try_next_with_context(&mut ctx), Ok(Some((3, 30))));
+        assert_eq!(src.try_next_with_context(&mut ctx), Ok(None));
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt::{Debug, Write};
+
+use crate::TryNext;
+
+/// Asserts that a source yields exactly the given items, then ends.
+///
+/// The source is drained until it returns `Ok(None)` or an error, and the
+/// outcomes are compared with the expected sequence. By default the
+/// sequence must end with `Ok(None)`; pass a trailing `Err(..)` to expect an
+/// error instead. On mismatch the panic message lists both sequences side by
+/// side and marks the steps that differ.
+///
+/// Available with the `test-util` feature.
+///
+/// ```rust
+/// use try_next::{TryNextExt, assert_yields, from_items, from_iter};
+///
+/// assert_yields!(from_items(1..4), [1, 2, 3]);
+/// assert_yields!(from_iter([Ok(1), Err("bad")]), [1], Err("bad"));
+///
+/// let mut src = from_items(1..6).take(2);
+/// assert_yields!(src, [1, 2]);
+/// ```
+#[macro_export]
+macro_rules! assert_yields {
+    ($source:expr, [$($item:expr),* $(,)?] $(,)?) => {
+        $crate::__check_yields(&mut $source, ::std::vec![$($item),*], ::std::result::Result::Ok(()))
+    };
+    ($source:expr, [$($item:expr),* $(,)?], Err($error:expr) $(,)?) => {
+        $crate::__check_yields(
+            &mut $source,
+            ::std::vec![$($item),*],
+            ::std::result::Result::Err($error),
+        )
+    };
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn check_yields<S>(source: &mut S, items: Vec<S::Item>, end: Result<(), S::Error>)
+where
+    S: TryNext + ?Sized,
+    S::Item: PartialEq + Debug,
+    S::Error: PartialEq + Debug,
+{
+    let expected: Vec<_> = items
+        .into_iter()
+        .map(|item| Ok(Some(item)))
+        .chain([end.map(|()| None)])
+        .collect();
+
+    // One step past the expected length is enough to show the surplus
+    // without draining an endless source.
+    let mut actual = Vec::new();
+    while actual.len() <= expected.len() {
+        let step = source.try_next();
+        let done = !matches!(step, Ok(Some(_)));
+        actual.push(step);
+        if done {
+            break;
+        }
+    }
+
+    if actual != expected {
+        panic!("{}", diff(&expected, &actual));
+    }
+}
+
+fn diff<T: Debug + PartialEq>(expected: &[T], actual: &[T]) -> String {
+    let show = |steps: &[T], i: usize| steps.get(i).map_or("-".to_owned(), |s| format!("{s:?}"));
+    let len = expected.len().max(actual.len());
+    let width = (0..len)
+        .map(|i| show(expected, i).len())
+        .max()
+        .unwrap_or(0)
+        .max("expected".len());
+
+    let mut out = String::from("source did not yield the expected sequence\n");
+    let _ = writeln!(out, "       {:<width$}  actual", "expected");
+    for i in 0..len {
+        let marker = if expected.get(i) == actual.get(i) {
+            ' '
+        } else {
+            '>'
+        };
+        let _ = writeln!(
+            out,
+            "{marker} {i:>3}  {:<width$}  {}",
+            show(expected, i),
+            show(actual, i)
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sources::ScriptedSource;
+    use crate::{from_items, from_iter};
+
+    #[test]
+    fn stops_one_step_past_the_expected_end() {
+        let mut src = ScriptedSource::<_, ()>::new([Ok(Some(1)), Ok(Some(2)), Ok(None)]);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            assert_yields!(src, [1, 2, 3, 4]);
+        }));
+        assert!(result.is_err());
+        assert_eq!(src.calls(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = ">   1  Ok(Some(3))  Ok(Some(2))")]
+    fn marks_differing_steps() {
+        assert_yields!(from_items(1..3), [1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = ">   2  Ok(None)     Ok(Some(3))")]
+    fn shows_surplus_items() {
+        assert_yields!(from_items(1..10), [1, 2]);
+    }
+
+    #[test]
+    fn accepts_trailing_error() {
+        assert_yields!(from_iter([Ok(1), Ok(2), Err('x')]), [1, 2], Err('x'));
+    }
+}
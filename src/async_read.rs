@@ -0,0 +1,212 @@
+//! An [`AsyncRead`] bridge over byte-chunk sources, running pulls on Tokio's
+//! blocking thread pool.
+
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::task::{self, JoinHandle};
+
+use crate::TryNext;
+
+type Pulled<S> = (
+    S,
+    Result<Option<<S as TryNext>::Item>, <S as TryNext>::Error>,
+);
+
+/// Where the wrapped source currently is.
+enum State<S: TryNext> {
+    /// The source is here, ready for the next pull.
+    Idle(S),
+    /// A pull is running on the blocking pool, which holds the source.
+    Busy(JoinHandle<Pulled<S>>),
+    /// The source ended, failed to come back from the pool, or is gone.
+    Done,
+}
+
+/// An [`AsyncRead`] that reads the byte chunks of a blocking source.
+///
+/// Each pull of the source runs on Tokio's blocking thread pool with
+/// [`spawn_blocking`](tokio::task::spawn_blocking), so a source that waits on
+/// files, pipes or a sync decoder never stalls the async runtime. The
+/// current chunk is kept in an internal buffer and copied out across as many
+/// reads as it takes; empty chunks are skipped, and the end of the source is
+/// the end of the stream. A source error is returned from the read that
+/// pulled it, converted with [`Into<io::Error>`], and reading may continue
+/// afterwards. If a pull panics, the read fails and the stream ends.
+///
+/// The reader must be polled inside a Tokio runtime. Dropping it while a
+/// pull is running lets the pull finish on the pool and drops the source
+/// there.
+///
+/// ```rust
+/// use tokio::io::AsyncReadExt;
+/// use try_next::{TryNextExt, from_iter};
+///
+/// let rt = tokio::runtime::Builder::new_current_thread().build()?;
+/// let chunks = from_iter(["hello", ", ", "world"].map(Ok::<_, std::io::Error>));
+///
+/// let mut text = String::new();
+/// rt.block_on(chunks.into_async_read().read_to_string(&mut text))?;
+/// assert_eq!(text, "hello, world");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// Available with the `tokio` feature.
+///
+/// Created by [`TryNextExt::into_async_read`](crate::TryNextExt::into_async_read).
+pub struct AsyncReader<S: TryNext> {
+    state: State<S>,
+    chunk: Option<S::Item>,
+    pos: usize,
+}
+
+impl<S: TryNext> AsyncReader<S> {
+    pub(crate) fn new(source: S) -> Self {
+        Self {
+            state: State::Idle(source),
+            chunk: None,
+            pos: 0,
+        }
+    }
+
+    /// Returns the underlying source, dropping any unread bytes, or `None`
+    /// if it is on the blocking pool or has been dropped.
+    pub fn into_inner(self) -> Option<S> {
+        match self.state {
+            State::Idle(source) => Some(source),
+            State::Busy(_) | State::Done => None,
+        }
+    }
+}
+
+// The reader is never pinned structurally: the source and the current chunk
+// are only moved, never borrowed across polls.
+impl<S: TryNext> Unpin for AsyncReader<S> {}
+
+impl<S: TryNext + fmt::Debug> fmt::Debug for AsyncReader<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match &self.state {
+            State::Idle(source) => Some(source),
+            State::Busy(_) | State::Done => None,
+        };
+        f.debug_struct("AsyncReader")
+            .field("source", &source)
+            .field("pulling", &matches!(self.state, State::Busy(_)))
+            .field("pos", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S> AsyncRead for AsyncReader<S>
+where
+    S: TryNext + Send + 'static,
+    S::Item: AsRef<[u8]> + Send,
+    S::Error: Into<io::Error> + Send,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+        loop {
+            if let Some(chunk) = &this.chunk {
+                let rest = &chunk.as_ref()[this.pos..];
+                let n = rest.len().min(buf.remaining());
+                buf.put_slice(&rest[..n]);
+                this.pos += n;
+                if this.pos == chunk.as_ref().len() {
+                    this.chunk = None;
+                }
+                if n > 0 {
+                    return Poll::Ready(Ok(()));
+                }
+            }
+            match std::mem::replace(&mut this.state, State::Done) {
+                State::Idle(mut source) => {
+                    this.state = State::Busy(task::spawn_blocking(move || {
+                        let pulled = source.try_next();
+                        (source, pulled)
+                    }));
+                }
+                State::Busy(mut handle) => {
+                    let Poll::Ready(joined) = Pin::new(&mut handle).poll(cx) else {
+                        this.state = State::Busy(handle);
+                        return Poll::Pending;
+                    };
+                    let (source, pulled) = joined.map_err(io::Error::other)?;
+                    match pulled {
+                        Ok(Some(chunk)) => {
+                            this.state = State::Idle(source);
+                            this.chunk = Some(chunk);
+                            this.pos = 0;
+                        }
+                        Ok(None) => return Poll::Ready(Ok(())),
+                        Err(e) => {
+                            this.state = State::Idle(source);
+                            return Poll::Ready(Err(e.into()));
+                        }
+                    }
+                }
+                State::Done => return Poll::Ready(Ok(())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use tokio::io::AsyncReadExt;
+    use tokio::runtime::{Builder, Runtime};
+
+    use crate::{TryNextExt, from_iter};
+
+    fn runtime() -> Runtime {
+        Builder::new_current_thread().build().unwrap()
+    }
+
+    #[test]
+    fn small_reads_drain_each_chunk() {
+        let chunks = from_iter([Ok::<_, io::Error>(vec![1, 2, 3]), Ok(vec![]), Ok(vec![4])]);
+        let mut reader = chunks.into_async_read();
+        let mut buf = [0; 2];
+
+        let reads: Vec<usize> = runtime().block_on(async {
+            let mut reads = Vec::new();
+            loop {
+                match reader.read(&mut buf).await.unwrap() {
+                    0 => break reads,
+                    n => reads.push(n),
+                }
+            }
+        });
+        assert_eq!(reads, [2, 1, 1]);
+    }
+
+    #[test]
+    fn errors_surface_and_reading_continues() {
+        let chunks = from_iter([
+            Ok(b"ab".to_vec()),
+            Err(io::Error::new(io::ErrorKind::InvalidData, "bad frame")),
+            Ok(b"cd".to_vec()),
+        ]);
+        let mut reader = chunks.into_async_read();
+
+        runtime().block_on(async {
+            let mut out = Vec::new();
+            let err = reader.read_to_end(&mut out).await.unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+            reader.read_to_end(&mut out).await.unwrap();
+            assert_eq!(out, b"abcd");
+        });
+    }
+}
@@ -0,0 +1,114 @@
+//! Context-aware producers whose context may hold short-lived borrows.
+
+/// Context-aware, fallible producer with a borrowed context.
+///
+/// A generalization of [`TryNextWithContext`](crate::TryNextWithContext) where the context is a generic
+/// associated type passed **by value**. This lets the caller assemble a
+/// context out of short-lived borrows — for example
+/// `(&'a mut Connection, &'a Arena)` — instead of having to own everything in
+/// a single context struct.
+///
+/// An owned-context [`TryNextWithContext`](crate::TryNextWithContext) is the
+/// special case `Context<'a> = &'a mut C`.
+///
+/// ```rust
+/// use try_next::TryNextWithBorrowedContext;
+///
+/// struct Interner { names: Vec<String> }
+///
+/// /// Yields the interned id of each word of a borrowed input.
+/// struct Ids { pos: usize }
+///
+/// impl TryNextWithBorrowedContext for Ids {
+///     type Item = usize;
+///     type Error = std::convert::Infallible;
+///     type Context<'a> = (&'a mut Interner, &'a str);
+///
+///     fn try_next_with_borrowed_context(
+///         &mut self,
+///         (interner, input): Self::Context<'_>,
+///     ) -> Result<Option<usize>, Self::Error> {
+///         let Some(word) = input.split(' ').nth(self.pos) else { return Ok(None) };
+///         self.pos += 1;
+///         let id = match interner.names.iter().position(|n| n == word) {
+///             Some(id) => id,
+///             None => {
+///                 interner.names.push(word.to_string());
+///                 interner.names.len() - 1
+///             }
+///         };
+///         Ok(Some(id))
+///     }
+/// }
+///
+/// let mut interner = Interner { names: Vec::new() };
+/// let input = String::from("a b a");
+/// let mut ids = Ids { pos: 0 };
+///
+/// assert_eq!(ids.try_next_with_borrowed_context((&mut interner, &input)), Ok(Some(0)));
+/// assert_eq!(ids.try_next_with_borrowed_context((&mut interner, &input)), Ok(Some(1)));
+/// assert_eq!(ids.try_next_with_borrowed_context((&mut interner, &input)), Ok(Some(0)));
+/// assert_eq!(ids.try_next_with_borrowed_context((&mut interner, &input)), Ok(None));
+/// ```
+pub trait TryNextWithBorrowedContext {
+    /// The type of items yielded by this source.
+    type Item;
+
+    /// The error type that may be returned when producing the next item fails.
+    type Error;
+
+    /// The type of context passed to each call, borrowing for `'a`.
+    type Context<'a>;
+
+    fn try_next_with_borrowed_context(
+        &mut self,
+        context: Self::Context<'_>,
+    ) -> Result<Option<Self::Item>, Self::Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TryNextWithBorrowedContext;
+
+    /// Adds an offset to each element of a borrowed slice, tracking the
+    /// position in a borrowed cursor.
+    struct Offset(u32);
+
+    impl TryNextWithBorrowedContext for Offset {
+        type Item = u32;
+        type Error = ();
+        type Context<'a> = (&'a mut usize, &'a [u32]);
+
+        fn try_next_with_borrowed_context(
+            &mut self,
+            (pos, data): Self::Context<'_>,
+        ) -> Result<Option<Self::Item>, Self::Error> {
+            let Some(&v) = data.get(*pos) else {
+                return Ok(None);
+            };
+            *pos += 1;
+            Ok(Some(v + self.0))
+        }
+    }
+
+    #[test]
+    fn context_may_hold_borrows() {
+        let data = vec![1, 2];
+        let mut pos = 0;
+        let mut src = Offset(10);
+
+        assert_eq!(
+            src.try_next_with_borrowed_context((&mut pos, &data)),
+            Ok(Some(11))
+        );
+        assert_eq!(
+            src.try_next_with_borrowed_context((&mut pos, &data)),
+            Ok(Some(12))
+        );
+        assert_eq!(
+            src.try_next_with_borrowed_context((&mut pos, &data)),
+            Ok(None)
+        );
+        assert_eq!(pos, 2);
+    }
+}
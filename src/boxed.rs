@@ -0,0 +1,172 @@
+//! Type-erased, boxed sources.
+
+#[cfg(feature = "dyn-clone")]
+use dyn_clone::DynClone;
+
+use crate::{TryNext, TryNextWithContext};
+
+/// A boxed, type-erased [`TryNext`].
+///
+/// Useful for heterogeneous pipelines and plugin systems where the concrete
+/// source type varies at run time. Create one with
+/// [`TryNextExt::boxed`](crate::TryNextExt::boxed).
+///
+/// ```rust
+/// use try_next::{BoxTryNext, TryNextExt, from_iter};
+///
+/// let mut sources: Vec<BoxTryNext<'_, u8, ()>> = vec![
+///     from_iter([Ok(1), Err(())]).boxed(),
+///     from_iter([2, 3].map(Ok)).boxed(),
+/// ];
+/// assert_eq!(sources[1].try_next(), Ok(Some(2)));
+/// ```
+pub type BoxTryNext<'a, T, E> = Box<dyn TryNext<Item = T, Error = E> + 'a>;
+
+/// A boxed, type-erased [`TryNext`] that can be sent across threads.
+pub type BoxTryNextSend<'a, T, E> = Box<dyn TryNext<Item = T, Error = E> + Send + 'a>;
+
+/// A boxed, type-erased [`TryNextWithContext`].
+pub type BoxTryNextWithContext<'a, T, E, C> =
+    Box<dyn TryNextWithContext<Item = T, Error = E, Context = C> + 'a>;
+
+/// A boxed, type-erased [`TryNextWithContext`] that can be sent across
+/// threads.
+pub type BoxTryNextWithContextSend<'a, T, E, C> =
+    Box<dyn TryNextWithContext<Item = T, Error = E, Context = C> + Send + 'a>;
+
+/// A boxed, type-erased error, as produced by
+/// [`TryNextExt::boxed_err`](crate::TryNextExt::boxed_err).
+///
+/// Pairs with [`BoxTryNext`] to store sources with different error types
+/// side by side.
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A [`TryNext`] that can be cloned through a trait object.
+///
+/// `Box<dyn TryNext>` cannot be cloned because `Clone` is not object safe.
+/// This subtrait is implemented for every `Clone` source and lets
+/// [`BoxCloneTryNext`] duplicate the boxed source, state and all, for
+/// restarting from a saved point or handing copies to several consumers.
+///
+/// Available with the `dyn-clone` feature.
+///
+/// ```rust
+/// use try_next::{BoxCloneTryNext, TryNext, TryNextExt, from_items};
+///
+/// let mut src: BoxCloneTryNext<'_, u32, _> = from_items(1..=3).boxed_clone();
+/// assert_eq!(src.try_next(), Ok(Some(1)));
+///
+/// let mut copy = src.clone();
+/// assert_eq!(copy.try_collect::<Vec<_>>(), Ok(vec![2, 3]));
+/// assert_eq!(src.try_next(), Ok(Some(2)));
+/// ```
+#[cfg(feature = "dyn-clone")]
+pub trait CloneTryNext: TryNext + DynClone {}
+
+#[cfg(feature = "dyn-clone")]
+impl<S: TryNext + Clone> CloneTryNext for S {}
+
+/// A boxed, type-erased [`CloneTryNext`] that can itself be cloned.
+///
+/// Available with the `dyn-clone` feature.
+#[cfg(feature = "dyn-clone")]
+pub type BoxCloneTryNext<'a, T, E> = Box<dyn CloneTryNext<Item = T, Error = E> + 'a>;
+
+/// A boxed, type-erased [`CloneTryNext`] that can be cloned and sent across
+/// threads.
+///
+/// Available with the `dyn-clone` feature.
+#[cfg(feature = "dyn-clone")]
+pub type BoxCloneTryNextSend<'a, T, E> = Box<dyn CloneTryNext<Item = T, Error = E> + Send + 'a>;
+
+#[cfg(feature = "dyn-clone")]
+impl<T, E> Clone for BoxCloneTryNext<'_, T, E> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(feature = "dyn-clone")]
+impl<T, E> Clone for BoxCloneTryNextSend<'_, T, E> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoxTryNext, BoxTryNextSend, BoxTryNextWithContext, BoxTryNextWithContextSend};
+    use crate::{TryNextExt, TryNextWithContext, TryNextWithContextExt, from_items, from_iter};
+    use std::convert::Infallible;
+
+    struct Doubler;
+
+    impl TryNextWithContext for Doubler {
+        type Item = u32;
+        type Error = Infallible;
+        type Context = Vec<u32>;
+
+        fn try_next_with_context(
+            &mut self,
+            context: &mut Self::Context,
+        ) -> Result<Option<Self::Item>, Self::Error> {
+            Ok(context.pop().map(|v| v * 2))
+        }
+    }
+
+    #[test]
+    fn heterogeneous_sources_share_one_type() {
+        let mut sources: Vec<BoxTryNext<'_, u32, Infallible>> =
+            vec![from_items([1, 2]).boxed(), from_iter([Ok(3)]).boxed()];
+
+        let mut out = Vec::new();
+        for src in &mut sources {
+            while let Some(v) = src.try_next().unwrap() {
+                out.push(v);
+            }
+        }
+        assert_eq!(out, [1, 2, 3]);
+    }
+
+    #[test]
+    fn send_and_context_aliases() {
+        let mut src: BoxTryNextSend<'_, u32, Infallible> = Box::new(from_items([1]));
+        let handle = std::thread::spawn(move || src.try_next());
+        assert_eq!(handle.join().unwrap(), Ok(Some(1)));
+
+        let mut src: BoxTryNextWithContext<'_, u32, Infallible, Vec<u32>> = Box::new(Doubler);
+        assert_eq!(src.try_next_with_context(&mut vec![4]), Ok(Some(8)));
+    }
+
+    #[test]
+    fn boxed_send_moves_across_threads() {
+        let mut queue: Vec<BoxTryNextWithContextSend<'static, u32, Infallible, Vec<u32>>> =
+            vec![Doubler.boxed_send()];
+        let handle = std::thread::spawn(move || {
+            let mut src = queue.pop().unwrap();
+            src.try_next_with_context(&mut vec![5])
+        });
+        assert_eq!(handle.join().unwrap(), Ok(Some(10)));
+
+        let mut local: BoxTryNext<'_, u32, Infallible> = from_items([7]).boxed_local();
+        assert_eq!(local.try_next(), Ok(Some(7)));
+    }
+
+    #[cfg(feature = "dyn-clone")]
+    #[test]
+    fn boxed_clones_restart_from_the_same_point() {
+        use super::BoxCloneTryNextSend;
+
+        let mut src: BoxCloneTryNextSend<'static, u32, char> =
+            from_iter([Ok(1), Err('x'), Ok(2)]).boxed_clone_send();
+        assert_eq!(src.try_next(), Ok(Some(1)));
+
+        let saved = src.clone();
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+
+        let mut retry = saved.clone();
+        let handle = std::thread::spawn(move || retry.try_next());
+        assert_eq!(handle.join().unwrap(), Err('x'));
+    }
+}
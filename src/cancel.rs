@@ -0,0 +1,159 @@
+//! Cooperative cancellation for blocking adapters.
+//!
+//! A [`CancelToken`] is a shared flag: any clone can request cancellation,
+//! and adapters given a clone check it while they wait. The threaded and
+//! blocking adapters ([`prefetch`](crate::TryNextExt::prefetch),
+//! [`retry`](crate::TryNextExt::retry), [`ChannelSource`], and
+//! [`merge_unordered_with_cancel`](crate::sources::merge_unordered_with_cancel))
+//! accept a token through `with_cancel` and then report a [`Cancelled`]
+//! error, converted into the source's error type with `From`, instead of
+//! blocking further.
+//!
+//! ```rust
+//! use std::thread;
+//! use std::time::Duration;
+//! use try_next::TryNext;
+//! use try_next::cancel::{CancelToken, Cancelled};
+//! use try_next::sources::ChannelSource;
+//!
+//! let (_sender, receiver) = std::sync::mpsc::channel::<u32>();
+//! let token = CancelToken::new();
+//! let mut src = ChannelSource::new(receiver).with_cancel(token.clone());
+//!
+//! let stopper = thread::spawn(move || {
+//!     thread::sleep(Duration::from_millis(10));
+//!     token.cancel();
+//! });
+//! assert_eq!(src.try_next(), Err(Cancelled));
+//! stopper.join().unwrap();
+//! ```
+//!
+//! [`ChannelSource`]: crate::sources::ChannelSource
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, RecvError, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often a blocked adapter wakes up to check its token.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// A shared flag requesting that work stop.
+///
+/// Clones share the flag; once any of them calls [`cancel`](Self::cancel),
+/// all of them report cancellation for good.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Creates a token that has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Returns `true` once cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+/// The error reported by an adapter whose [`CancelToken`] was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("operation cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+impl From<Cancelled> for io::Error {
+    fn from(cancelled: Cancelled) -> Self {
+        io::Error::new(io::ErrorKind::Interrupted, cancelled)
+    }
+}
+
+/// A cancellation check an adapter can be configured with.
+///
+/// `()` never cancels and is what adapters use until given a token;
+/// [`CancelToken`] cancels into any error type implementing
+/// `From<`[`Cancelled`]`>`. Implement it for other signals, such as a
+/// shutdown flag an application already has.
+pub trait CheckCancel<E> {
+    /// Returns an error once cancellation has been requested.
+    fn check(&self) -> Result<(), E>;
+
+    /// Returns `false` if [`check`](Self::check) can never fail, letting
+    /// adapters block without polling.
+    fn can_cancel(&self) -> bool {
+        true
+    }
+}
+
+impl<E> CheckCancel<E> for () {
+    fn check(&self) -> Result<(), E> {
+        Ok(())
+    }
+
+    fn can_cancel(&self) -> bool {
+        false
+    }
+}
+
+impl<E: From<Cancelled>> CheckCancel<E> for CancelToken {
+    fn check(&self) -> Result<(), E> {
+        if self.is_cancelled() {
+            Err(Cancelled.into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Receives from `receiver`, waking up periodically to check `cancel`.
+pub(crate) fn recv<T, E>(
+    receiver: &Receiver<T>,
+    cancel: &impl CheckCancel<E>,
+) -> Result<Result<T, RecvError>, E> {
+    if !cancel.can_cancel() {
+        return Ok(receiver.recv());
+    }
+    loop {
+        cancel.check()?;
+        match receiver.recv_timeout(POLL_INTERVAL) {
+            Ok(value) => return Ok(Ok(value)),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return Ok(Err(RecvError)),
+        }
+    }
+}
+
+/// Sleeps for `delay`, returning early with an error if `cancel` fires.
+pub(crate) fn sleep<E>(delay: Duration, cancel: &impl CheckCancel<E>) -> Result<(), E> {
+    if !cancel.can_cancel() {
+        thread::sleep(delay);
+        return Ok(());
+    }
+    let deadline = Instant::now() + delay;
+    loop {
+        cancel.check()?;
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Ok(());
+        }
+        thread::sleep(left.min(POLL_INTERVAL));
+    }
+}
@@ -0,0 +1,386 @@
+//! Sources whose position can be saved and restored across runs.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::TryNext;
+
+/// A [`TryNext`] whose progress can be captured as serializable state.
+///
+/// A resumable job calls [`save_state`](Self::save_state) periodically,
+/// persists the result with any `serde` format, and on restart feeds it back
+/// through [`restore_state`](Self::restore_state) to continue where it left
+/// off. Unlike [`TrySeek`](crate::TrySeek), the state is meant to outlive the
+/// process, so it must be self-contained.
+///
+/// Available with the `serde` feature.
+pub trait Checkpoint: TryNext {
+    /// The saved state of the source.
+    type State: Serialize + DeserializeOwned;
+
+    /// Captures the current state of the source.
+    fn save_state(&self) -> Self::State;
+
+    /// Restores a state previously returned by
+    /// [`save_state`](Self::save_state), so the next call to
+    /// [`try_next`](TryNext::try_next) continues from that point.
+    fn restore_state(&mut self, state: Self::State) -> Result<(), Self::Error>;
+}
+
+/// Somewhere to keep a [`Checkpoint`] state between runs.
+///
+/// Used by [`Resumable`]. [`FileStore`] keeps the state in a JSON file;
+/// implement this trait to keep it in a database row, an object store, or
+/// alongside the job's output.
+///
+/// Available with the `serde` feature.
+pub trait CheckpointStore<T> {
+    /// The error type of the store.
+    type Error;
+
+    /// Returns the last saved state, or `None` if nothing has been saved.
+    fn load(&mut self) -> Result<Option<T>, Self::Error>;
+
+    /// Saves `state`, replacing any earlier one.
+    fn save(&mut self, state: &T) -> Result<(), Self::Error>;
+
+    /// Removes the saved state, so the next run starts from scratch.
+    fn clear(&mut self) -> Result<(), Self::Error>;
+}
+
+/// A [`CheckpointStore`] keeping the state as JSON in a file.
+///
+/// Each save writes a sibling temporary file and renames it over the old
+/// one, so a crash mid-save leaves the previous state intact.
+///
+/// Available with the `serde` feature.
+#[derive(Debug, Clone)]
+pub struct FileStore {
+    path: PathBuf,
+}
+
+impl FileStore {
+    /// Creates a store keeping its state at `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the state file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Deletes the state file, if there is one.
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> CheckpointStore<T> for FileStore {
+    type Error = io::Error;
+
+    fn load(&mut self) -> Result<Option<T>, Self::Error> {
+        match fs::read(&self.path) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn save(&mut self, state: &T) -> Result<(), Self::Error> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, serde_json::to_vec(state)?)?;
+        fs::rename(&tmp, &self.path)
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        FileStore::clear(self)
+    }
+}
+
+/// The error type of a [`Resumable`].
+///
+/// Distinguishes whether the source or the checkpoint store failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResumeError<S, K> {
+    /// The source failed to produce an item or to restore its state.
+    Source(S),
+    /// The store failed to load or save a state.
+    Store(K),
+}
+
+impl<S: fmt::Display, K: fmt::Display> fmt::Display for ResumeError<S, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(e) => write!(f, "source error: {e}"),
+            Self::Store(e) => write!(f, "checkpoint store error: {e}"),
+        }
+    }
+}
+
+impl<S, K> Error for ResumeError<S, K>
+where
+    S: Error + 'static,
+    K: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Source(e) => Some(e),
+            Self::Store(e) => Some(e),
+        }
+    }
+}
+
+/// A [`Checkpoint`] source that saves its state to a [`CheckpointStore`] as
+/// it goes, for at-least-once processing across restarts.
+///
+/// The state is saved when the consumer asks for the next item, which is
+/// taken to mean the items before it have been fully processed, once every
+/// [`every`](Self::every) items, and again at the end of input. After a
+/// crash, [`resume`](Self::resume) continues from the last saved state, so
+/// items since then are delivered again but none are skipped.
+///
+/// Available with the `serde` feature.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// # use std::convert::Infallible;
+/// use try_next::{Checkpoint, FileStore, Resumable, TryNext};
+///
+/// struct Lines { lines: Vec<&'static str>, pos: usize }
+///
+/// impl TryNext for Lines {
+///     type Item = &'static str;
+///     type Error = Infallible;
+///
+///     fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+///         let line = self.lines.get(self.pos).copied();
+///         self.pos += usize::from(line.is_some());
+///         Ok(line)
+///     }
+/// }
+///
+/// impl Checkpoint for Lines {
+///     type State = usize;
+///
+///     fn save_state(&self) -> usize { self.pos }
+///
+///     fn restore_state(&mut self, pos: usize) -> Result<(), Infallible> {
+///         self.pos = pos;
+///         Ok(())
+///     }
+/// }
+///
+/// let lines = || Lines { lines: vec!["a", "b", "c"], pos: 0 };
+/// let path = std::env::temp_dir().join(format!("try-next-doc-{}.json", std::process::id()));
+///
+/// let mut first = Resumable::resume(lines(), FileStore::new(&path))?;
+/// assert_eq!(first.try_next()?, Some("a"));
+/// assert_eq!(first.try_next()?, Some("b"));
+/// drop(first); // crash while processing "b"
+///
+/// let mut second = Resumable::resume(lines(), FileStore::new(&path))?;
+/// assert_eq!(second.try_next()?, Some("b"));
+/// assert_eq!(second.try_next()?, Some("c"));
+/// assert_eq!(second.try_next()?, None);
+/// second.into_store().clear()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Resumable<S, K> {
+    source: S,
+    store: K,
+    every: usize,
+    unsaved: usize,
+}
+
+impl<S, K> Resumable<S, K>
+where
+    S: Checkpoint,
+    K: CheckpointStore<S::State>,
+{
+    /// Wraps `source` without loading a saved state.
+    pub fn new(source: S, store: K) -> Self {
+        Self {
+            source,
+            store,
+            every: 1,
+            unsaved: 0,
+        }
+    }
+
+    /// Wraps `source`, first restoring the state saved in `store`, if any.
+    pub fn resume(mut source: S, mut store: K) -> Result<Self, ResumeError<S::Error, K::Error>> {
+        if let Some(state) = store.load().map_err(ResumeError::Store)? {
+            source.restore_state(state).map_err(ResumeError::Source)?;
+        }
+        Ok(Self::new(source, store))
+    }
+
+    /// Saves the state only once every `n` items instead of after each one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn every(mut self, n: usize) -> Self {
+        assert!(n > 0, "checkpoint interval must be non-zero");
+        self.every = n;
+        self
+    }
+
+    /// Saves the current state now.
+    pub fn checkpoint(&mut self) -> Result<(), K::Error> {
+        self.store.save(&self.source.save_state())?;
+        self.unsaved = 0;
+        Ok(())
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    /// Returns the checkpoint store.
+    pub fn into_store(self) -> K {
+        self.store
+    }
+}
+
+impl<S, K> TryNext for Resumable<S, K>
+where
+    S: Checkpoint,
+    K: CheckpointStore<S::State>,
+{
+    type Item = S::Item;
+    type Error = ResumeError<S::Error, K::Error>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.unsaved >= self.every {
+            self.checkpoint().map_err(ResumeError::Store)?;
+        }
+        match self.source.try_next().map_err(ResumeError::Source)? {
+            Some(item) => {
+                self.unsaved += 1;
+                Ok(Some(item))
+            }
+            None => {
+                self.checkpoint().map_err(ResumeError::Store)?;
+                Ok(None)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::{Checkpoint, CheckpointStore, FileStore, Resumable, ResumeError};
+    use crate::{TryNext, TryNextExt};
+
+    struct Records {
+        data: Vec<u8>,
+        pos: usize,
+    }
+
+    impl TryNext for Records {
+        type Item = u8;
+        type Error = ();
+
+        fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            let item = self.data.get(self.pos).copied();
+            if item.is_some() {
+                self.pos += 1;
+            }
+            Ok(item)
+        }
+    }
+
+    impl Checkpoint for Records {
+        type State = usize;
+
+        fn save_state(&self) -> Self::State {
+            self.pos
+        }
+
+        fn restore_state(&mut self, state: Self::State) -> Result<(), Self::Error> {
+            if state > self.data.len() {
+                return Err(());
+            }
+            self.pos = state;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn resumes_from_persisted_state() {
+        let mut first = Records {
+            data: vec![1, 2, 3],
+            pos: 0,
+        }
+        .fuse();
+        assert_eq!(first.try_next(), Ok(Some(1)));
+        let saved = serde_json::to_string(&first.save_state()).unwrap();
+
+        let mut second = Records {
+            data: vec![1, 2, 3],
+            pos: 0,
+        }
+        .fuse();
+        second
+            .restore_state(serde_json::from_str(&saved).unwrap())
+            .unwrap();
+        assert_eq!(second.try_next(), Ok(Some(2)));
+        assert_eq!(second.try_next(), Ok(Some(3)));
+        assert_eq!(second.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn resumable_saves_every_n_items_and_at_the_end() {
+        let path = std::env::temp_dir().join(format!("try-next-resumable-{}.json", process::id()));
+        let mut store = FileStore::new(&path);
+        store.clear().unwrap();
+        let records = || Records {
+            data: vec![1, 2, 3, 4, 5],
+            pos: 0,
+        };
+
+        let mut first = Resumable::resume(records(), store.clone())
+            .unwrap()
+            .every(2);
+        for expected in 1..=4 {
+            assert_eq!(first.try_next().unwrap(), Some(expected));
+        }
+        // Only the first two items were acknowledged by a later pull.
+        assert_eq!(CheckpointStore::<usize>::load(&mut store).unwrap(), Some(2));
+        drop(first);
+
+        let mut second = Resumable::resume(records(), store.clone()).unwrap();
+        assert_eq!(second.try_collect::<Vec<_>>().unwrap(), [3, 4, 5]);
+        assert_eq!(CheckpointStore::<usize>::load(&mut store).unwrap(), Some(5));
+
+        let mut bad = records();
+        bad.data.truncate(1);
+        assert!(matches!(
+            Resumable::resume(bad, store.clone()),
+            Err(ResumeError::Source(()))
+        ));
+        store.clear().unwrap();
+        assert!(!path.exists());
+    }
+}
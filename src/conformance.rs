@@ -0,0 +1,285 @@
+use std::fmt::Debug;
+
+use crate::{ExactSizeTryNext, FusedTryNext, TryNext};
+
+/// Generates tests checking that a source follows the [`TryNext`] contracts.
+///
+/// Takes a module name for the generated tests and a factory expression,
+/// typically a closure, returning a fresh source; each test builds its own
+/// sources from it, which must be finite and behave the same every time.
+/// The generated tests check that:
+///
+/// - [`size_hint`](TryNext::size_hint) bounds the number of items left at
+///   every step;
+/// - [`try_next_chunk`](TryNext::try_next_chunk) yields the same items and
+///   errors as item-at-a-time calls, for several chunk sizes.
+///
+/// Trailing claims add checks for the capabilities a source advertises:
+///
+/// - `fused` — the source keeps returning `Ok(None)` once exhausted, and
+///   implements [`FusedTryNext`];
+/// - `exact_size` — [`len`](ExactSizeTryNext::len) is exact at every step;
+/// - `fill` — [`try_fill`](TryNext::try_fill) agrees with item-at-a-time
+///   calls (needs `Copy + Default` items).
+///
+/// Items and errors must implement `PartialEq` and `Debug`. The macro expands
+/// to a module importing its parent's names, so place it in a test module.
+///
+/// Available with the `test-util` feature.
+///
+/// ```rust
+/// use try_next::{from_items, from_iter, try_next_conformance_tests};
+///
+/// try_next_conformance_tests!(ranges, || from_items(0..10), fused, exact_size, fill);
+/// try_next_conformance_tests!(failing, || from_iter([Ok(1), Err('x'), Ok(2)]));
+/// ```
+#[macro_export]
+macro_rules! try_next_conformance_tests {
+    ($name:ident, $factory:expr $(, $claim:ident)* $(,)?) => {
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[test]
+            fn size_hint_bounds_remaining_items() {
+                $crate::__check_size_hint($factory);
+            }
+
+            #[test]
+            fn chunks_match_single_pulls() {
+                $crate::__check_chunks($factory);
+            }
+
+            $($crate::try_next_conformance_tests!(@claim $claim, $factory);)*
+        }
+    };
+    (@claim fused, $factory:expr) => {
+        #[test]
+        fn stays_exhausted_after_none() {
+            $crate::__check_fused($factory);
+        }
+    };
+    (@claim exact_size, $factory:expr) => {
+        #[test]
+        fn len_is_exact() {
+            $crate::__check_exact_size($factory);
+        }
+    };
+    (@claim fill, $factory:expr) => {
+        #[test]
+        fn fill_matches_single_pulls() {
+            $crate::__check_fill($factory);
+        }
+    };
+}
+
+const MAX_PULLS: usize = 100_000;
+const SIZES: [usize; 4] = [1, 2, 3, 7];
+
+/// Pulls until the first `Ok(None)` or error.
+fn drain<S: TryNext>(source: &mut S) -> (Vec<S::Item>, Result<(), S::Error>) {
+    let mut items = Vec::new();
+    loop {
+        match source.try_next() {
+            Ok(Some(item)) => items.push(item),
+            Ok(None) => return (items, Ok(())),
+            Err(error) => return (items, Err(error)),
+        }
+        check_bounded(items.len());
+    }
+}
+
+#[track_caller]
+fn check_bounded(pulls: usize) {
+    assert!(
+        pulls < MAX_PULLS,
+        "source did not end within {MAX_PULLS} pulls; conformance tests need a finite source"
+    );
+}
+
+#[doc(hidden)]
+pub fn check_size_hint<S: TryNext>(mut factory: impl FnMut() -> S) {
+    let mut source = factory();
+    let mut hints = Vec::new();
+    let ended = loop {
+        hints.push(source.size_hint());
+        match source.try_next() {
+            Ok(Some(_)) => check_bounded(hints.len()),
+            Ok(None) => break true,
+            Err(_) => break false,
+        }
+    };
+
+    let items = hints.len() - 1;
+    for (pulled, (lower, upper)) in hints.into_iter().enumerate() {
+        let left = items - pulled;
+        if let Some(upper) = upper {
+            assert!(
+                lower <= upper,
+                "size_hint() after {pulled} items is ({lower}, Some({upper})): lower bound exceeds upper"
+            );
+            assert!(
+                left <= upper,
+                "size_hint() after {pulled} items has upper bound {upper}, but {left} more followed"
+            );
+        }
+        // After an error the source may yield more than we saw, so only the
+        // upper bound can be checked.
+        if ended {
+            assert!(
+                lower <= left,
+                "size_hint() after {pulled} items has lower bound {lower}, but only {left} followed"
+            );
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn check_chunks<S>(mut factory: impl FnMut() -> S)
+where
+    S: TryNext,
+    S::Item: PartialEq + Debug,
+    S::Error: PartialEq + Debug,
+{
+    let (items, end) = drain(&mut factory());
+    for max in SIZES {
+        let mut source = factory();
+        let mut got = Vec::new();
+        let got_end = loop {
+            match source.try_next_chunk(max) {
+                Ok(chunk) => {
+                    assert!(
+                        chunk.len() <= max,
+                        "try_next_chunk({max}) returned {} items",
+                        chunk.len()
+                    );
+                    if chunk.is_empty() {
+                        break Ok(());
+                    }
+                    got.extend(chunk);
+                }
+                Err(error) => break Err(error),
+            }
+            check_bounded(got.len());
+        };
+        // A failing chunk discards the items it had already pulled.
+        let kept = match end {
+            Ok(()) => items.len(),
+            Err(_) => items.len() / max * max,
+        };
+        assert_eq!(
+            (&got[..], &got_end),
+            (&items[..kept], &end),
+            "try_next_chunk({max}) disagrees with try_next()"
+        );
+    }
+}
+
+#[doc(hidden)]
+pub fn check_fill<S>(mut factory: impl FnMut() -> S)
+where
+    S: TryNext,
+    S::Item: Copy + Default + PartialEq + Debug,
+    S::Error: PartialEq + Debug,
+{
+    let (items, end) = drain(&mut factory());
+    for max in SIZES {
+        let mut source = factory();
+        let mut buf = vec![S::Item::default(); max];
+        let mut got = Vec::new();
+        let got_end = loop {
+            match source.try_fill(&mut buf) {
+                Ok(filled) => {
+                    assert!(filled <= max, "try_fill() on {max} slots returned {filled}");
+                    if filled == 0 {
+                        break Ok(());
+                    }
+                    got.extend_from_slice(&buf[..filled]);
+                }
+                Err(error) => break Err(error),
+            }
+            check_bounded(got.len());
+        };
+        let kept = match end {
+            Ok(()) => items.len(),
+            Err(_) => items.len() / max * max,
+        };
+        assert_eq!(
+            (&got[..], &got_end),
+            (&items[..kept], &end),
+            "try_fill() on {max} slots disagrees with try_next()"
+        );
+    }
+}
+
+#[doc(hidden)]
+pub fn check_fused<S: FusedTryNext>(mut factory: impl FnMut() -> S) {
+    let mut source = factory();
+    let mut pulls = 0;
+    while !matches!(source.try_next(), Ok(None)) {
+        pulls += 1;
+        check_bounded(pulls);
+    }
+    for extra in 1..=3 {
+        assert!(
+            matches!(source.try_next(), Ok(None)),
+            "fused source did not return Ok(None) on pull {extra} after the end"
+        );
+    }
+}
+
+#[doc(hidden)]
+pub fn check_exact_size<S: ExactSizeTryNext>(mut factory: impl FnMut() -> S) {
+    let mut source = factory();
+    let mut lens = Vec::new();
+    let ended = loop {
+        lens.push(source.len());
+        match source.try_next() {
+            Ok(Some(_)) => check_bounded(lens.len()),
+            Ok(None) => break true,
+            Err(_) => break false,
+        }
+    };
+
+    // An error voids the count, so only runs that end cleanly are checked.
+    if ended {
+        let items = lens.len() - 1;
+        for (pulled, len) in lens.into_iter().enumerate() {
+            assert_eq!(
+                len,
+                items - pulled,
+                "len() after {pulled} items disagrees with the items that followed"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, from_items, from_iter};
+
+    crate::try_next_conformance_tests!(items, || from_items(0..10), fused, exact_size, fill);
+    crate::try_next_conformance_tests!(failing, || from_iter([Ok(1), Ok(2), Err('x'), Ok(3)]));
+
+    struct Overpromising(u8);
+
+    impl TryNext for Overpromising {
+        type Item = u8;
+        type Error = ();
+
+        fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            self.0 = self.0.saturating_sub(1);
+            Ok(Some(self.0).filter(|&n| n > 0))
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (usize::from(self.0), Some(usize::from(self.0)))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "has lower bound 3, but only 2 followed")]
+    fn catches_overstated_size_hint() {
+        super::check_size_hint(|| Overpromising(3));
+    }
+}
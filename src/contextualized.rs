@@ -0,0 +1,57 @@
+//! Errors annotated with a description of what was being done.
+
+use std::error::Error;
+use std::fmt;
+
+/// An error wrapped with a message describing where it happened.
+///
+/// Displays as `"{context}: {error}"`, so nested annotations read as a
+/// chain from the outermost stage inwards. Returned by the
+/// [`err_context`](crate::TryNextExt::err_context) and
+/// [`err_context_with`](crate::TryNextExt::err_context_with) adapters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contextualized<E> {
+    /// The annotation.
+    pub context: String,
+    /// The error returned by the source.
+    pub error: E,
+}
+
+impl<E> Contextualized<E> {
+    /// Returns the wrapped error, discarding the annotation.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Contextualized<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.context, self.error)
+    }
+}
+
+impl<E: Error + 'static> Error for Contextualized<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Contextualized;
+    use std::error::Error;
+    use std::io;
+
+    #[test]
+    fn displays_the_chain() {
+        let err = Contextualized {
+            context: "import".to_owned(),
+            error: Contextualized {
+                context: "reading users.csv".to_owned(),
+                error: io::Error::other("disk full"),
+            },
+        };
+        assert_eq!(err.to_string(), "import: reading users.csv: disk full");
+        assert!(err.source().unwrap().is::<Contextualized<io::Error>>());
+    }
+}
@@ -0,0 +1,189 @@
+/// Defines an enum of sources that is itself a source, dispatching each call
+/// to the wrapped variant.
+///
+/// Every variant wraps one source type, and all of them must share the
+/// first variant's `Item` and `Error` types; a mismatch is a type error at
+/// the offending variant. This lets a pipeline choose between, say, a file
+/// source and a network source at runtime without boxing.
+///
+/// The generated [`TryNext`](crate::TryNext) implementation forwards
+/// `size_hint`, `try_next_chunk`, and `try_fill` as well, so bulk overrides
+/// of the wrapped sources are kept. Write `enum Name: TryNextWithContext`
+/// to implement [`TryNextWithContext`](crate::TryNextWithContext) instead;
+/// the variants must then also share a `Context` type.
+///
+/// ```rust
+/// use std::vec;
+///
+/// use try_next::sources::{ChannelSource, FromItems};
+/// use try_next::{TryNext, from_items, try_next_enum};
+///
+/// try_next_enum! {
+///     /// Where the numbers come from.
+///     pub enum Numbers {
+///         Fixed(FromItems<vec::IntoIter<u32>>),
+///         Channel(ChannelSource<u32>),
+///     }
+/// }
+///
+/// let mut src = Numbers::Fixed(from_items(vec![1, 2]));
+/// assert_eq!(src.try_next(), Ok(Some(1)));
+/// assert_eq!(src.size_hint(), (1, Some(1)));
+/// ```
+#[macro_export]
+macro_rules! try_next_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident: TryNextWithContext {
+            $(#[$first_meta:meta])* $first:ident($first_ty:ty)
+            $(, $(#[$variant_meta:meta])* $variant:ident($ty:ty))* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $(#[$first_meta])* $first($first_ty),
+            $($(#[$variant_meta])* $variant($ty),)*
+        }
+
+        impl $crate::TryNextWithContext for $name {
+            type Item = <$first_ty as $crate::TryNextWithContext>::Item;
+            type Error = <$first_ty as $crate::TryNextWithContext>::Error;
+            type Context = <$first_ty as $crate::TryNextWithContext>::Context;
+
+            fn try_next_with_context(
+                &mut self,
+                context: &mut Self::Context,
+            ) -> ::std::result::Result<::std::option::Option<Self::Item>, Self::Error> {
+                match self {
+                    Self::$first(source) => {
+                        $crate::TryNextWithContext::try_next_with_context(source, context)
+                    }
+                    $(Self::$variant(source) => {
+                        $crate::TryNextWithContext::try_next_with_context(source, context)
+                    })*
+                }
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(#[$first_meta:meta])* $first:ident($first_ty:ty)
+            $(, $(#[$variant_meta:meta])* $variant:ident($ty:ty))* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $(#[$first_meta])* $first($first_ty),
+            $($(#[$variant_meta])* $variant($ty),)*
+        }
+
+        impl $crate::TryNext for $name {
+            type Item = <$first_ty as $crate::TryNext>::Item;
+            type Error = <$first_ty as $crate::TryNext>::Error;
+
+            fn try_next(
+                &mut self,
+            ) -> ::std::result::Result<::std::option::Option<Self::Item>, Self::Error> {
+                match self {
+                    Self::$first(source) => $crate::TryNext::try_next(source),
+                    $(Self::$variant(source) => $crate::TryNext::try_next(source),)*
+                }
+            }
+
+            fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+                match self {
+                    Self::$first(source) => $crate::TryNext::size_hint(source),
+                    $(Self::$variant(source) => $crate::TryNext::size_hint(source),)*
+                }
+            }
+
+            fn try_next_chunk(
+                &mut self,
+                max: usize,
+            ) -> ::std::result::Result<::std::vec::Vec<Self::Item>, Self::Error> {
+                match self {
+                    Self::$first(source) => $crate::TryNext::try_next_chunk(source, max),
+                    $(Self::$variant(source) => $crate::TryNext::try_next_chunk(source, max),)*
+                }
+            }
+
+            fn try_fill(
+                &mut self,
+                buf: &mut [Self::Item],
+            ) -> ::std::result::Result<usize, Self::Error>
+            where
+                Self::Item: ::std::marker::Copy,
+            {
+                match self {
+                    Self::$first(source) => $crate::TryNext::try_fill(source, buf),
+                    $(Self::$variant(source) => $crate::TryNext::try_fill(source, buf),)*
+                }
+            }
+
+            fn try_advance_by(
+                &mut self,
+                n: usize,
+            ) -> ::std::result::Result<
+                ::std::result::Result<(), ::std::num::NonZeroUsize>,
+                Self::Error,
+            > {
+                match self {
+                    Self::$first(source) => $crate::TryNext::try_advance_by(source, n),
+                    $(Self::$variant(source) => $crate::TryNext::try_advance_by(source, n),)*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+    use std::vec;
+
+    use crate::sources::{FromItems, FromIter};
+    use crate::{TryNext, TryNextWithContext, from_items, from_iter};
+
+    try_next_enum! {
+        #[derive(Debug)]
+        enum Either {
+            Items(FromItems<vec::IntoIter<u8>>),
+            /// Results, not items.
+            Results(FromIter<vec::IntoIter<Result<u8, Infallible>>>),
+        }
+    }
+
+    struct Counter(u8);
+
+    impl TryNextWithContext for Counter {
+        type Item = u8;
+        type Error = ();
+        type Context = u8;
+
+        fn try_next_with_context(&mut self, step: &mut u8) -> Result<Option<u8>, ()> {
+            self.0 += *step;
+            Ok(Some(self.0))
+        }
+    }
+
+    try_next_enum! {
+        enum WithContext: TryNextWithContext {
+            Counter(Counter),
+        }
+    }
+
+    #[test]
+    fn dispatches_to_the_active_variant() {
+        let mut items = Either::Items(from_items(vec![1, 2]));
+        let mut results = Either::Results(from_iter(vec![Ok(3)]));
+
+        assert_eq!(items.try_next_chunk(4), Ok(vec![1, 2]));
+        assert_eq!(results.size_hint(), (0, Some(1)));
+        assert_eq!(results.try_next(), Ok(Some(3)));
+        assert_eq!(results.try_next(), Ok(None));
+
+        let mut counter = WithContext::Counter(Counter(0));
+        assert_eq!(counter.try_next_with_context(&mut 5), Ok(Some(5)));
+    }
+}
@@ -0,0 +1,44 @@
+//! Sources that can be consumed from both ends.
+
+use crate::TryNext;
+
+/// A fallible producer that can also yield items from the back.
+///
+/// Both ends draw from the same underlying sequence: once the two ends meet,
+/// both [`try_next`](TryNext::try_next) and
+/// [`try_next_back`](Self::try_next_back) return `Ok(None)`.
+///
+/// Use [`TryNextExt::rev`](crate::TryNextExt::rev) to swap the two ends.
+///
+/// ```rust
+/// use std::collections::VecDeque;
+/// use std::convert::Infallible;
+/// use try_next::{DoubleEndedTryNext, TryNext};
+///
+/// struct Records(VecDeque<u32>);
+///
+/// impl TryNext for Records {
+///     type Item = u32;
+///     type Error = Infallible;
+///
+///     fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+///         Ok(self.0.pop_front())
+///     }
+/// }
+///
+/// impl DoubleEndedTryNext for Records {
+///     fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+///         Ok(self.0.pop_back())
+///     }
+/// }
+///
+/// let mut src = Records(VecDeque::from([1, 2, 3]));
+/// assert_eq!(src.try_next_back(), Ok(Some(3)));
+/// assert_eq!(src.try_next(), Ok(Some(1)));
+/// assert_eq!(src.try_next_back(), Ok(Some(2)));
+/// assert_eq!(src.try_next(), Ok(None));
+/// ```
+pub trait DoubleEndedTryNext: TryNext {
+    /// Attempts to produce the next item from the back of the source.
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error>;
+}
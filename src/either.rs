@@ -0,0 +1,173 @@
+//! Implementations for [`Either`], choosing between two source types at run
+//! time without boxing.
+//!
+//! An `Either<L, R>` is a source when both sides are sources with the same
+//! item and error types (and, for [`TryNextWithContext`], the same context
+//! type); each call goes to whichever side is present.
+//!
+//! ```rust
+//! use either::Either;
+//! use try_next::{TryNext, TryNextExt, from_items};
+//!
+//! let reverse = true;
+//! let mut src = if reverse {
+//!     Either::Left(from_items(1..=3).rev())
+//! } else {
+//!     Either::Right(from_items(1..=3))
+//! };
+//! assert_eq!(src.try_next(), Ok(Some(3)));
+//! ```
+//!
+//! [`EitherError`] also converts to and from `Either`.
+
+use std::num::NonZeroUsize;
+
+use either::Either;
+
+use crate::{
+    DoubleEndedTryNext, EitherError, ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext,
+    TryPeek,
+};
+
+impl<A, B> From<EitherError<A, B>> for Either<A, B> {
+    fn from(err: EitherError<A, B>) -> Self {
+        match err {
+            EitherError::Left(a) => Either::Left(a),
+            EitherError::Right(b) => Either::Right(b),
+        }
+    }
+}
+
+impl<A, B> From<Either<A, B>> for EitherError<A, B> {
+    fn from(either: Either<A, B>) -> Self {
+        match either {
+            Either::Left(a) => EitherError::Left(a),
+            Either::Right(b) => EitherError::Right(b),
+        }
+    }
+}
+
+impl<L, R> TryNext for Either<L, R>
+where
+    L: TryNext,
+    R: TryNext<Item = L::Item, Error = L::Error>,
+{
+    type Item = L::Item;
+    type Error = L::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        either::for_both!(self, source => source.try_next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        either::for_both!(self, source => source.size_hint())
+    }
+
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        either::for_both!(self, source => source.try_next_chunk(max))
+    }
+
+    fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        either::for_both!(self, source => source.try_advance_by(n))
+    }
+}
+
+impl<L, R> DoubleEndedTryNext for Either<L, R>
+where
+    L: DoubleEndedTryNext,
+    R: DoubleEndedTryNext<Item = L::Item, Error = L::Error>,
+{
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        either::for_both!(self, source => source.try_next_back())
+    }
+}
+
+impl<L, R> ExactSizeTryNext for Either<L, R>
+where
+    L: ExactSizeTryNext,
+    R: ExactSizeTryNext<Item = L::Item, Error = L::Error>,
+{
+}
+
+impl<L, R> FusedTryNext for Either<L, R>
+where
+    L: FusedTryNext,
+    R: FusedTryNext<Item = L::Item, Error = L::Error>,
+{
+}
+
+impl<L, R> TryPeek for Either<L, R>
+where
+    L: TryPeek,
+    R: TryPeek<Item = L::Item, Error = L::Error>,
+{
+    fn try_peek(&mut self) -> Result<Option<&Self::Item>, Self::Error> {
+        either::for_both!(self, source => source.try_peek())
+    }
+}
+
+impl<L, R> TryNextWithContext for Either<L, R>
+where
+    L: TryNextWithContext,
+    R: TryNextWithContext<Item = L::Item, Error = L::Error, Context = L::Context>,
+{
+    type Item = L::Item;
+    type Error = L::Error;
+    type Context = L::Context;
+
+    fn try_next_with_context(
+        &mut self,
+        context: &mut Self::Context,
+    ) -> Result<Option<Self::Item>, Self::Error> {
+        either::for_both!(self, source => source.try_next_with_context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use either::Either;
+
+    use crate::{EitherError, TryNext, TryNextExt, TryNextWithContext, from_iter};
+
+    #[test]
+    fn forwards_to_the_present_side() {
+        let pick = |left| {
+            let items = vec![Ok(1), Err('x'), Ok(2)];
+            if left {
+                Either::Left(from_iter(items))
+            } else {
+                Either::Right(from_iter(items).rev())
+            }
+        };
+
+        let mut left = pick(true);
+        assert_eq!(left.size_hint(), (0, Some(3)));
+        assert_eq!(left.try_next(), Ok(Some(1)));
+        assert_eq!(left.try_next(), Err('x'));
+
+        let mut right = pick(false);
+        assert_eq!(right.try_next(), Ok(Some(2)));
+    }
+
+    #[test]
+    fn passes_the_context_through() {
+        let pick = |left| {
+            let items = vec![Ok::<u8, char>(1), Ok(2)];
+            if left {
+                Either::Left(from_iter(items).ignore_context::<u32>())
+            } else {
+                Either::Right(from_iter(items).rev().ignore_context::<u32>())
+            }
+        };
+
+        assert_eq!(pick(true).try_next_with_context(&mut 0), Ok(Some(1)));
+        assert_eq!(pick(false).try_next_with_context(&mut 0), Ok(Some(2)));
+    }
+
+    #[test]
+    fn converts_to_and_from_either_error() {
+        let err: EitherError<u8, char> = Either::Right('x').into();
+        assert_eq!(err, EitherError::Right('x'));
+        assert_eq!(Either::from(err), Either::Right('x'));
+    }
+}
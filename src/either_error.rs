@@ -0,0 +1,79 @@
+//! The error type of adapters combining two sources.
+
+use std::error::Error;
+use std::fmt;
+
+/// An error from one of two combined sources.
+///
+/// Adapters that pull from two sources, such as
+/// [`chain`](crate::TryNextWithContextExt::chain),
+/// [`zip`](crate::TryNextWithContextExt::zip), and
+/// [`interleave`](crate::TryNextWithContextExt::interleave), report errors
+/// as `EitherError<A::Error, B::Error>`, so the two sides can fail with
+/// different types. When both sides share an error type,
+/// [`into_inner`](Self::into_inner) recovers it.
+///
+/// Display and [`Error::source`] forward to the wrapped error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EitherError<A, B> {
+    /// The first source failed.
+    Left(A),
+    /// The second source failed.
+    Right(B),
+}
+
+impl<A, B> EitherError<A, B> {
+    /// Converts the error of the first source with `f`.
+    pub fn map_left<C>(self, f: impl FnOnce(A) -> C) -> EitherError<C, B> {
+        match self {
+            Self::Left(a) => EitherError::Left(f(a)),
+            Self::Right(b) => EitherError::Right(b),
+        }
+    }
+
+    /// Converts the error of the second source with `f`.
+    pub fn map_right<C>(self, f: impl FnOnce(B) -> C) -> EitherError<A, C> {
+        match self {
+            Self::Left(a) => EitherError::Left(a),
+            Self::Right(b) => EitherError::Right(f(b)),
+        }
+    }
+}
+
+impl<E> EitherError<E, E> {
+    /// Returns the wrapped error, whichever source it came from.
+    ///
+    /// ```rust
+    /// use try_next::EitherError;
+    ///
+    /// let err: EitherError<&str, &str> = EitherError::Right("bad row");
+    /// assert_eq!(err.into_inner(), "bad row");
+    /// ```
+    pub fn into_inner(self) -> E {
+        match self {
+            Self::Left(e) | Self::Right(e) => e,
+        }
+    }
+}
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for EitherError<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left(e) => e.fmt(f),
+            Self::Right(e) => e.fmt(f),
+        }
+    }
+}
+
+impl<A, B> Error for EitherError<A, B>
+where
+    A: Error + 'static,
+    B: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Left(e) => e.source(),
+            Self::Right(e) => e.source(),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Sources with a known number of remaining items.
+
+use crate::TryNext;
+
+/// A [`TryNext`] that knows exactly how many items it will still yield.
+///
+/// Implementors must return an exact [`size_hint`](TryNext::size_hint), i.e.
+/// `(n, Some(n))`, where `n` is the number of `Ok(Some(_))` results left
+/// before `Ok(None)`. An error ends that promise: after an `Err`, the count
+/// is no longer meaningful.
+///
+/// ```rust
+/// use try_next::{ExactSizeTryNext, TryNext};
+///
+/// struct Fixed { left: usize }
+///
+/// impl TryNext for Fixed {
+///     type Item = usize;
+///     type Error = ();
+///
+///     fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+///         if self.left == 0 {
+///             return Ok(None);
+///         }
+///         self.left -= 1;
+///         Ok(Some(self.left))
+///     }
+///
+///     fn size_hint(&self) -> (usize, Option<usize>) {
+///         (self.left, Some(self.left))
+///     }
+/// }
+///
+/// impl ExactSizeTryNext for Fixed {}
+///
+/// let mut src = Fixed { left: 4 };
+/// src.try_next().unwrap();
+/// assert_eq!(src.len(), 3);
+/// ```
+pub trait ExactSizeTryNext: TryNext {
+    /// Returns the exact number of items remaining in the source.
+    ///
+    /// The default implementation derives the length from
+    /// [`size_hint`](TryNext::size_hint).
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `size_hint` is not exact.
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        debug_assert_eq!(upper, Some(lower));
+        lower
+    }
+
+    /// Returns `true` if the source has no items left.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExactSizeTryNext;
+    use crate::{DoubleEndedTryNext, TryNext, TryNextExt};
+    use std::collections::VecDeque;
+
+    struct Deque(VecDeque<u8>);
+
+    impl TryNext for Deque {
+        type Item = u8;
+        type Error = ();
+
+        fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            Ok(self.0.pop_front())
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.0.len(), Some(self.0.len()))
+        }
+    }
+
+    impl DoubleEndedTryNext for Deque {
+        fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            Ok(self.0.pop_back())
+        }
+    }
+
+    impl ExactSizeTryNext for Deque {}
+
+    #[test]
+    fn len_tracks_remaining_items_through_adapters() {
+        let mut src = Deque(VecDeque::from([1, 2, 3])).rev().fuse();
+        assert_eq!(src.len(), 3);
+
+        src.try_next().unwrap();
+        src.try_next().unwrap();
+        assert_eq!(src.len(), 1);
+        assert!(!src.is_empty());
+
+        src.try_next().unwrap();
+        assert!(src.is_empty());
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.len(), 0);
+    }
+}
@@ -0,0 +1,2105 @@
+//! Extension methods for [`TryNext`] and [`TryNextWithContext`] sources.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[cfg(feature = "tokio")]
+use crate::AsyncReader;
+#[cfg(feature = "log")]
+use crate::adapters::Logged;
+#[cfg(feature = "tracing")]
+use crate::adapters::Traced;
+use crate::adapters::{
+    ArrayPeekable, Backtrack, Batch, BindContext, BoxedErr, Broadcast, Budget, BudgetLimits,
+    Buffered, Chain, Chunks, ChunksExact, CircuitBreaker, ContextFree, Deltas, ErrContext,
+    ErrContextWith, Fallback, Filter, FilterWithContext, Fuse, Gate, GateHandle, HashJoin,
+    IgnoreContext, Inspect, InspectErr, Instrumented, Interleave, Locate, LockedContext, Map,
+    MapContext, MapPooled, MapWithContext, MergeJoinBy, Metered, ParMap, ParMapUnordered, Peekable,
+    Prefetch, Printed, Provided, Replayable, Retry, Rev, Running, Sample, Sampling, Shared,
+    SharedContext, SharedHandle, SkipErrors, SlowConsumer, Strict, Take, TakeExact, Throttle,
+    TryGate, WindowedFold, Windowing, WithContext, Zip,
+};
+#[cfg(feature = "test-util")]
+use crate::adapters::{FaultInjector, FaultPlan, Recorded};
+#[cfg(feature = "heapless")]
+use crate::adapters::{HeaplessChunks, HeaplessTail};
+#[cfg(feature = "rand")]
+use crate::adapters::{SampleBy, Shuffle};
+use crate::metrics::MetricsRecorder;
+use crate::replay::ReplayBuffer;
+use crate::retry::{AnyError, RetryPolicy, RetryableError, Transient};
+#[cfg(feature = "dyn-clone")]
+use crate::{BoxCloneTryNext, BoxCloneTryNextSend};
+use crate::{
+    BoxError, BoxTryNext, BoxTryNextSend, BoxTryNextWithContext, BoxTryNextWithContextSend,
+    ByteOffset, DoubleEndedTryNext, Pool, ProvideContext, PumpError, Recycle, SnapshotContext,
+    TryNext, TryNextWithContext, TryPush,
+};
+
+/// Adapter methods for [`TryNext`] sources.
+///
+/// This trait is implemented for every [`TryNext`] type; bring it into scope
+/// to use the adapters.
+pub trait TryNextExt: TryNext {
+    /// Reverses the direction of a [`DoubleEndedTryNext`] source.
+    ///
+    /// ```rust
+    /// # use std::collections::VecDeque;
+    /// # use try_next::{DoubleEndedTryNext, TryNext};
+    /// # struct Records(VecDeque<u32>);
+    /// # impl TryNext for Records {
+    /// #     type Item = u32;
+    /// #     type Error = ();
+    /// #     fn try_next(&mut self) -> Result<Option<u32>, ()> { Ok(self.0.pop_front()) }
+    /// # }
+    /// # impl DoubleEndedTryNext for Records {
+    /// #     fn try_next_back(&mut self) -> Result<Option<u32>, ()> { Ok(self.0.pop_back()) }
+    /// # }
+    /// use try_next::TryNextExt;
+    ///
+    /// let mut src = Records(VecDeque::from([1, 2, 3])).rev();
+    /// assert_eq!(src.try_next(), Ok(Some(3)));
+    /// assert_eq!(src.try_next(), Ok(Some(2)));
+    /// ```
+    fn rev(self) -> Rev<Self>
+    where
+        Self: Sized + DoubleEndedTryNext,
+    {
+        Rev::new(self)
+    }
+
+    /// Transforms each item with `f`.
+    ///
+    /// ```rust
+    /// use try_next::{TryNext, TryNextExt, from_items};
+    ///
+    /// let mut src = from_items([1, 2]).map(|n| n * 10);
+    /// assert_eq!(src.try_next(), Ok(Some(10)));
+    /// assert_eq!(src.try_next(), Ok(Some(20)));
+    /// ```
+    fn map<F, T>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> T,
+    {
+        Map::new(self, f)
+    }
+
+    /// Yields only the items for which `pred` returns `true`.
+    ///
+    /// Errors are passed through without consulting `pred`.
+    fn filter<P>(self, pred: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter::new(self, pred)
+    }
+
+    /// Yields at most `n` items.
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Yields exactly `n` items, returning
+    /// [`TakeExactError::Short`](crate::adapters::TakeExactError::Short) with
+    /// the count received if the source ends first.
+    fn take_exact(self, n: usize) -> TakeExact<Self>
+    where
+        Self: Sized,
+    {
+        TakeExact::new(self, n)
+    }
+
+    /// Calls `f` with a reference to each item before yielding it.
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item),
+    {
+        Inspect::new(self, f)
+    }
+
+    /// Pairs each item with a running state, such as a total or an offset.
+    ///
+    /// The state starts at `A::default()`, `fold` updates it for each item,
+    /// and the item is yielded with a clone of the updated state.
+    fn running<A, F>(self, fold: F) -> Running<Self, A, F>
+    where
+        Self: Sized,
+        A: Default + Clone,
+        F: FnMut(&mut A, &Self::Item),
+    {
+        Running::new(self, fold)
+    }
+
+    /// Yields `f(previous, current)` for each pair of consecutive items.
+    ///
+    /// The first item is skipped unless
+    /// [`with_first`](Deltas::with_first) is called on the result.
+    fn deltas<F, U>(self, f: F) -> Deltas<Self, Self::Item, U, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> U,
+    {
+        Deltas::new(self, f)
+    }
+
+    /// Groups items into `Vec`s of `size` items; the last may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn chunks(self, size: usize) -> Chunks<Self, Self::Item>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self, size)
+    }
+
+    /// Groups items into `Vec`s of exactly `size` items.
+    ///
+    /// A short final chunk is not yielded; it is kept for
+    /// [`into_remainder`](ChunksExact::into_remainder), or reported as an
+    /// error after [`deny_remainder`](ChunksExact::deny_remainder).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn chunks_exact(self, size: usize) -> ChunksExact<Self, Self::Item>
+    where
+        Self: Sized,
+    {
+        ChunksExact::new(self, size)
+    }
+
+    /// Groups items into fixed-capacity [`heapless::Vec`]s of `N` items; the
+    /// last may be shorter.
+    ///
+    /// Available with the `heapless` feature.
+    #[cfg(feature = "heapless")]
+    fn heapless_chunks<const N: usize>(self) -> HeaplessChunks<Self, Self::Item, N>
+    where
+        Self: Sized,
+    {
+        HeaplessChunks::new(self)
+    }
+
+    /// Yields only the last `N` items, kept in a fixed-capacity
+    /// [`heapless::Deque`].
+    ///
+    /// Available with the `heapless` feature.
+    #[cfg(feature = "heapless")]
+    fn heapless_tail<const N: usize>(self) -> HeaplessTail<Self, Self::Item, N>
+    where
+        Self: Sized,
+    {
+        HeaplessTail::new(self)
+    }
+
+    /// Retries failed pulls according to `policy`, returning an error only
+    /// once the policy gives up.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use try_next::retry::Fixed;
+    /// use try_next::{TryNext, TryNextExt, from_iter};
+    ///
+    /// let flaky = from_iter([Err("timeout"), Ok(1), Err("timeout"), Err("timeout")]);
+    /// let mut src = flaky.retry(Fixed::new(Duration::ZERO, 1));
+    /// assert_eq!(src.try_next(), Ok(Some(1)));
+    /// assert_eq!(src.try_next(), Err("timeout"));
+    /// ```
+    fn retry<P>(self, policy: P) -> Retry<Self, P>
+    where
+        Self: Sized,
+        P: RetryPolicy,
+    {
+        Retry::new(self, policy, AnyError)
+    }
+
+    /// Like [`retry`](Self::retry), but retries only errors whose
+    /// [`RetryableError`] implementation reports them as transient, and
+    /// waits at least as long as they ask for.
+    fn retry_transient<P>(self, policy: P) -> Retry<Self, P, Transient>
+    where
+        Self: Sized,
+        Self::Error: RetryableError,
+        P: RetryPolicy,
+    {
+        Retry::new(self, policy, Transient)
+    }
+
+    /// Calls `f` with a reference to each error before returning it.
+    ///
+    /// Useful for metrics and logging hooks that should not change the error
+    /// type or the control flow.
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Error),
+    {
+        InspectErr::new(self, f)
+    }
+
+    /// Counts items, errors, and ends, and times each call; see
+    /// [`Instrumented::stats`] and [`Instrumented::handle`].
+    fn instrument(self) -> Instrumented<Self>
+    where
+        Self: Sized,
+    {
+        Instrumented::new(self)
+    }
+
+    /// Reports counts, latency, and throughput to `recorder`, labelled with
+    /// `stage`; see [`Metered`].
+    fn metered<R>(self, recorder: R, stage: &'static str) -> Metered<Self, R>
+    where
+        Self: Sized,
+        R: MetricsRecorder,
+    {
+        Metered::new(self, recorder, stage)
+    }
+
+    /// Runs each pull inside `span` and emits [`tracing`] events for errors
+    /// and termination; see [`Traced`] for levels and item fields.
+    ///
+    /// Available with the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    fn traced(self, span: tracing::Span) -> Traced<Self, fn(&Self::Item)>
+    where
+        Self: Sized,
+    {
+        Traced::new(self, span)
+    }
+
+    /// Logs errors and termination through the [`log`] facade, prefixed
+    /// with `name`; see [`Logged`] for levels and item formatting.
+    ///
+    /// Available with the `log` feature.
+    #[cfg(feature = "log")]
+    fn logged(self, name: &'static str) -> Logged<Self, fn(&Self::Item) -> &'static str>
+    where
+        Self: Sized,
+    {
+        Logged::new(self, name)
+    }
+
+    /// Prints each item, error, and the end of input to standard error with
+    /// `{:?}`; see [`Printed`] for labels, counts, and other formats.
+    fn debug(self) -> Printed<Self, Self::Item, Self::Error>
+    where
+        Self: Sized,
+        Self::Item: Debug,
+        Self::Error: Debug,
+    {
+        Printed::new(self)
+    }
+
+    /// Passes each error to `handler` and keeps pulling, yielding only
+    /// successful items.
+    ///
+    /// The handler returns `Ok(())` to skip the error or `Err` to pass it on.
+    ///
+    /// ```rust
+    /// use try_next::{TryNext, TryNextExt, from_iter};
+    ///
+    /// let mut skipped = 0;
+    /// let mut src = from_iter([Ok(1), Err("bad row"), Ok(2)]).skip_errors(|_| {
+    ///     skipped += 1;
+    ///     Ok(())
+    /// });
+    /// assert_eq!(src.try_next(), Ok(Some(1)));
+    /// assert_eq!(src.try_next(), Ok(Some(2)));
+    /// assert_eq!(src.try_next(), Ok(None));
+    /// assert_eq!(skipped, 1);
+    /// ```
+    fn skip_errors<F>(self, handler: F) -> SkipErrors<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Error) -> Result<(), Self::Error>,
+    {
+        SkipErrors::new(self, handler)
+    }
+
+    /// Wraps errors in [`Contextualized`](crate::Contextualized) with
+    /// `message`, recording which stage of a pipeline failed.
+    ///
+    /// ```rust
+    /// use try_next::{TryNext, TryNextExt, from_iter};
+    ///
+    /// let mut src = from_iter([Err::<u8, _>("unexpected end")]).err_context("reading header");
+    /// let err = src.try_next().unwrap_err();
+    /// assert_eq!(err.to_string(), "reading header: unexpected end");
+    /// ```
+    fn err_context<M>(self, message: M) -> ErrContext<Self, M>
+    where
+        Self: Sized,
+        M: Display,
+    {
+        ErrContext::new(self, message)
+    }
+
+    /// Like [`err_context`](Self::err_context), but builds the message with `f` only
+    /// when an error occurs.
+    fn err_context_with<F, M>(self, f: F) -> ErrContextWith<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> M,
+        M: Display,
+    {
+        ErrContextWith::new(self, f)
+    }
+
+    /// Wraps errors in [`Located`](crate::Located), recording how many items
+    /// were produced before each one.
+    ///
+    /// ```rust
+    /// use try_next::{TryNext, TryNextExt, from_iter};
+    ///
+    /// let mut src = from_iter([Ok(1), Ok(2), Err("malformed")]).locate();
+    /// src.try_next().unwrap();
+    /// src.try_next().unwrap();
+    /// let err = src.try_next().unwrap_err();
+    /// assert_eq!(err.index, 2);
+    /// assert_eq!(err.to_string(), "item 2: malformed");
+    /// ```
+    fn locate(self) -> Locate<Self>
+    where
+        Self: Sized,
+    {
+        Locate::new(self, |_| None)
+    }
+
+    /// Like [`locate`](Self::locate), but also records the source's
+    /// [`ByteOffset`] at the time of each error.
+    fn locate_bytes(self) -> Locate<Self>
+    where
+        Self: Sized + ByteOffset,
+    {
+        Locate::new(self, |source| Some(source.byte_offset()))
+    }
+
+    /// Stops polling this source after `threshold` consecutive errors
+    /// occurring within `window`, returning
+    /// [`BreakerError::Open`](crate::adapters::BreakerError::Open) instead.
+    ///
+    /// See [`CircuitBreaker::with_cooldown`] for re-closing the breaker.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is zero.
+    fn circuit_breaker(self, threshold: usize, window: Duration) -> CircuitBreaker<Self>
+    where
+        Self: Sized,
+    {
+        CircuitBreaker::new(self, threshold, window)
+    }
+
+    /// Caps this source at `rate` items per second, sleeping as needed.
+    ///
+    /// See [`Throttle::with_burst`] for letting short bursts through.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextExt, from_items};
+    ///
+    /// let events: Vec<u32> = from_items(1..=3).throttle(1000.0).with_burst(2).try_collect().unwrap();
+    /// assert_eq!(events, [1, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not a positive, finite number.
+    fn throttle(self, rate: f64) -> Throttle<Self>
+    where
+        Self: Sized,
+    {
+        Throttle::new(self, rate)
+    }
+
+    /// Blocks pulls while `handle` is paused.
+    ///
+    /// ```rust
+    /// use try_next::adapters::GateHandle;
+    /// use try_next::{TryNext, TryNextExt, from_items};
+    ///
+    /// let maintenance = GateHandle::new();
+    /// let mut ingest = from_items(1..=3).gate(maintenance.clone());
+    ///
+    /// assert_eq!(ingest.try_next(), Ok(Some(1)));
+    /// maintenance.pause();
+    /// // `ingest.try_next()` would now block until `maintenance.resume()`.
+    /// maintenance.resume();
+    /// assert_eq!(ingest.try_next(), Ok(Some(2)));
+    /// ```
+    fn gate(self, handle: GateHandle) -> Gate<Self>
+    where
+        Self: Sized,
+    {
+        Gate::new(self, handle)
+    }
+
+    /// Returns [`GateError::Paused`](crate::adapters::GateError::Paused)
+    /// instead of pulling while `handle` is paused.
+    fn try_gate(self, handle: GateHandle) -> TryGate<Self>
+    where
+        Self: Sized,
+    {
+        TryGate::new(self, handle)
+    }
+
+    /// Ends this source once any of `limits` is used up, returning
+    /// [`BudgetError::Exhausted`](crate::adapters::BudgetError::Exhausted)
+    /// with the reason.
+    ///
+    /// ```rust
+    /// use try_next::adapters::{BudgetError, BudgetLimits, Exhausted};
+    /// use try_next::{TryNextExt, from_items};
+    ///
+    /// let mut plugin = from_items(0..).budget(BudgetLimits::new().max_items(3));
+    /// assert_eq!(
+    ///     plugin.try_collect::<Vec<u32>>(),
+    ///     Err(BudgetError::Exhausted(Exhausted::Items))
+    /// );
+    /// ```
+    fn budget(self, limits: BudgetLimits) -> Budget<Self>
+    where
+        Self: Sized,
+    {
+        Budget::new(self, limits)
+    }
+
+    /// Yields only the items selected by `sampling`, passing every error
+    /// through.
+    ///
+    /// ```rust
+    /// use try_next::adapters::Sampling;
+    /// use try_next::{TryNextExt, from_items};
+    ///
+    /// let tap: Vec<u32> = from_items(0..10).sample(Sampling::EveryNth(4)).try_collect().unwrap();
+    /// assert_eq!(tap, [0, 4, 8]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sampling` is `EveryNth(0)` or has a probability outside
+    /// `0.0..=1.0`.
+    fn sample(self, sampling: Sampling) -> Sample<Self>
+    where
+        Self: Sized,
+    {
+        Sample::new(self, sampling)
+    }
+
+    /// Keeps each item with the probability `probability` returns for it,
+    /// passing every error through.
+    ///
+    /// Probabilities at or below `0.0` drop the item and those at or above
+    /// `1.0` keep it. See [`SampleBy`].
+    ///
+    /// Available with the `rand` feature.
+    #[cfg(feature = "rand")]
+    fn sample_by<R, F>(self, rng: R, probability: F) -> SampleBy<Self, R, F>
+    where
+        Self: Sized,
+        R: rand::Rng,
+        F: FnMut(&Self::Item) -> f64,
+    {
+        SampleBy::new(self, rng, probability)
+    }
+
+    /// Folds the items of each window into one aggregate, yielding it when
+    /// the window closes.
+    ///
+    /// `window` is a window size, for windows of a fixed number of items,
+    /// or a [`ByKey`](crate::adapters::ByKey), for windows of consecutive
+    /// items with the same key. Each window's fold starts from a clone of
+    /// `init`; the last window may be partial. See [`WindowedFold`].
+    ///
+    /// # Panics
+    ///
+    /// Panics on the first item if `window` is a size of zero.
+    fn windowed_fold<W, A, F>(self, window: W, init: A, fold: F) -> WindowedFold<Self, W, A, F>
+    where
+        Self: Sized,
+        W: Windowing<Self::Item>,
+        A: Clone,
+        F: FnMut(A, Self::Item) -> A,
+    {
+        WindowedFold::new(self, window, init, fold)
+    }
+
+    /// Yields the items in a randomized order, choosing each one at random
+    /// from a buffer of up to `buffer_size` pending items.
+    ///
+    /// Items move at most about `buffer_size` places earlier, so this is an
+    /// approximate shuffle of a stream; a `buffer_size` of `usize::MAX`
+    /// buffers the whole source for an exact shuffle. See [`Shuffle`].
+    ///
+    /// Available with the `rand` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer_size` is zero.
+    #[cfg(feature = "rand")]
+    fn shuffle<R: rand::Rng>(self, buffer_size: usize, rng: R) -> Shuffle<Self, Self::Item, R>
+    where
+        Self: Sized,
+    {
+        Shuffle::new(self, buffer_size, rng)
+    }
+
+    /// Records every item so the source can be rewound and read again.
+    ///
+    /// Items are kept as clones in memory; use
+    /// [`replayable_with`](Self::replayable_with) to store them elsewhere.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextExt, from_items};
+    ///
+    /// let mut rows = from_items([3, 1, 2]).replayable();
+    /// let first_pass: Vec<u32> = rows.try_collect().unwrap();
+    /// let max = first_pass.into_iter().max().unwrap();
+    /// rows.rewind();
+    /// let scaled: Vec<u32> = rows.map(|row| row * 100 / max).try_collect().unwrap();
+    /// assert_eq!(scaled, [100, 33, 66]);
+    /// ```
+    fn replayable(self) -> Replayable<Self, Vec<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Replayable::new(self, Vec::new())
+    }
+
+    /// Records every item into `buffer` so the source can be rewound and
+    /// read again.
+    fn replayable_with<B>(self, buffer: B) -> Replayable<Self, B>
+    where
+        Self: Sized,
+        B: ReplayBuffer<Self::Item, Self::Error>,
+    {
+        Replayable::new(self, buffer)
+    }
+
+    /// Makes this source cloneable, each clone reading every item from its
+    /// own position.
+    ///
+    /// Items are kept as clones in memory; use
+    /// [`shared_with`](Self::shared_with) to store them elsewhere.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextExt, from_items};
+    ///
+    /// let mut validate = from_items([4, 8, 15]).shared();
+    /// let mut load = validate.clone();
+    ///
+    /// let checked: Vec<u32> = validate.try_collect().unwrap();
+    /// assert!(checked.iter().all(|n| *n < 100));
+    /// assert_eq!(load.try_collect::<Vec<_>>(), Ok(vec![4, 8, 15]));
+    /// ```
+    fn shared(self) -> Shared<Self, Vec<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Shared::new(self, Vec::new())
+    }
+
+    /// Makes this source cloneable, recording items into `buffer` for the
+    /// clones that fall behind.
+    fn shared_with<B>(self, buffer: B) -> Shared<Self, B>
+    where
+        Self: Sized,
+        B: ReplayBuffer<Self::Item, Self::Error>,
+    {
+        Shared::new(self, buffer)
+    }
+
+    /// Replaces the calls selected by `plan` with errors built by
+    /// `make_error`, for exercising error paths in tests.
+    ///
+    /// Available with the `test-util` feature.
+    #[cfg(feature = "test-util")]
+    fn inject_faults<F>(self, plan: FaultPlan, make_error: F) -> FaultInjector<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> Self::Error,
+    {
+        FaultInjector::new(self, plan, make_error)
+    }
+
+    /// Logs a clone of every call's outcome; see [`Recorded::log`].
+    ///
+    /// Available with the `test-util` feature.
+    #[cfg(feature = "test-util")]
+    #[allow(clippy::type_complexity)]
+    fn recorded(
+        self,
+    ) -> Recorded<
+        Self,
+        Result<Option<Self::Item>, Self::Error>,
+        fn(&Result<Option<Self::Item>, Self::Error>) -> Result<Option<Self::Item>, Self::Error>,
+    >
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        Self::Error: Clone,
+    {
+        Recorded::new(self, Clone::clone)
+    }
+
+    /// Logs a summary of every call's outcome built by `summarize`, for
+    /// items or errors that are not [`Clone`] or too large to keep.
+    ///
+    /// Available with the `test-util` feature.
+    #[cfg(feature = "test-util")]
+    fn recorded_with<R, F>(self, summarize: F) -> Recorded<Self, R, F>
+    where
+        Self: Sized,
+        F: FnMut(&Result<Option<Self::Item>, Self::Error>) -> R,
+    {
+        Recorded::new(self, summarize)
+    }
+
+    /// Switches to `other` when this source returns an error.
+    ///
+    /// The error that triggers the switch is discarded; from then on all
+    /// items and errors come from `other`.
+    #[allow(clippy::type_complexity)]
+    fn or_fallback<B>(self, other: B) -> Fallback<Self, B, fn(&mut B) -> Result<(), Self::Error>>
+    where
+        Self: Sized,
+        B: TryNext<Item = Self::Item, Error = Self::Error>,
+    {
+        Fallback::new(self, other, |_| Ok(()))
+    }
+
+    /// Like [`or_fallback`](Self::or_fallback), but runs `prepare` on `other`
+    /// before its first use, for example to seek it to a checkpoint.
+    ///
+    /// ```rust
+    /// # use try_next::{TryNext, TrySeek};
+    /// # struct Snapshot { data: Vec<u32>, pos: usize }
+    /// # impl TryNext for Snapshot {
+    /// #     type Item = u32;
+    /// #     type Error = &'static str;
+    /// #     fn try_next(&mut self) -> Result<Option<u32>, &'static str> {
+    /// #         let item = self.data.get(self.pos).copied();
+    /// #         self.pos += item.is_some() as usize;
+    /// #         Ok(item)
+    /// #     }
+    /// # }
+    /// # impl TrySeek for Snapshot {
+    /// #     type Position = usize;
+    /// #     fn try_position(&mut self) -> Result<usize, &'static str> { Ok(self.pos) }
+    /// #     fn try_seek(&mut self, pos: usize) -> Result<(), &'static str> { self.pos = pos; Ok(()) }
+    /// #     fn try_rewind(&mut self) -> Result<(), &'static str> { self.try_seek(0) }
+    /// # }
+    /// use try_next::{TryNextExt, from_iter};
+    ///
+    /// let replica = from_iter([Ok(1), Err("connection lost")]);
+    /// let snapshot = Snapshot { data: vec![1, 2, 3], pos: 0 };
+    /// let mut src = replica.or_fallback_with(snapshot, |s| s.try_seek(1));
+    /// assert_eq!(src.try_next(), Ok(Some(1)));
+    /// assert_eq!(src.try_next(), Ok(Some(2)));
+    /// ```
+    fn or_fallback_with<B, F>(self, other: B, prepare: F) -> Fallback<Self, B, F>
+    where
+        Self: Sized,
+        B: TryNext<Item = Self::Item, Error = Self::Error>,
+        F: FnOnce(&mut B) -> Result<(), Self::Error>,
+    {
+        Fallback::new(self, other, prepare)
+    }
+
+    /// Sort-merge joins this source with `other`, yielding each item as
+    /// [`Left`](crate::adapters::EitherOrBoth::Left),
+    /// [`Right`](crate::adapters::EitherOrBoth::Right), or
+    /// [`Both`](crate::adapters::EitherOrBoth::Both).
+    ///
+    /// Both sources must be sorted ascending by `cmp`, which compares an
+    /// item of this source with one of `other`. Errors are reported as
+    /// [`EitherError<Self::Error, B::Error>`](crate::EitherError).
+    fn merge_join_by<B, F>(self, other: B, cmp: F) -> MergeJoinBy<Self, B, Self::Item, B::Item, F>
+    where
+        Self: Sized,
+        B: TryNext,
+        F: FnMut(&Self::Item, &B::Item) -> Ordering,
+    {
+        MergeJoinBy::new(self, other, cmp)
+    }
+
+    /// Joins this source against `build` on matching keys, yielding
+    /// `(item, build_item)` for every match.
+    ///
+    /// `build` is drained into a `HashMap` on the first pull, so it should
+    /// be the smaller side. `key_fns` is the pair `(probe_key, build_key)`
+    /// extracting the key from items of this source and of `build`. Call
+    /// [`left_join`](HashJoin::left_join) on the result to keep items
+    /// without a match. Errors are reported as
+    /// [`EitherError<Self::Error, B::Error>`](crate::EitherError).
+    fn hash_join<B, K, F, G>(self, build: B, key_fns: (F, G)) -> HashJoin<Self, B, K, F, G>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        B: TryNext<Item: Clone>,
+        K: Hash + Eq,
+        F: FnMut(&Self::Item) -> K,
+        G: FnMut(&B::Item) -> K,
+    {
+        let (probe_key, build_key) = key_fns;
+        HashJoin::new(self, build, probe_key, build_key)
+    }
+
+    /// Creates a source that returns `Ok(None)` forever after the first
+    /// `Ok(None)`.
+    ///
+    /// The result implements [`FusedTryNext`](crate::FusedTryNext).
+    fn fuse(self) -> Fuse<Self>
+    where
+        Self: Sized,
+    {
+        Fuse::new(self)
+    }
+
+    /// Stops polling this source after its first `Ok(None)` or error, and
+    /// flags callers that keep polling.
+    ///
+    /// Further calls panic in debug builds and return `Ok(None)` in release
+    /// builds. Use it in tests and development pipelines to catch adapters
+    /// that ignore the end of a sequence.
+    fn strict(self) -> Strict<Self>
+    where
+        Self: Sized,
+    {
+        Strict::new(self)
+    }
+
+    /// Creates a source with one item of lookahead.
+    ///
+    /// The result implements [`TryPeek`](crate::TryPeek).
+    fn peekable(self) -> Peekable<Self>
+    where
+        Self: Sized,
+    {
+        Peekable::new(self)
+    }
+
+    /// Creates a source with up to `N` items of lookahead, buffered inline
+    /// without allocation.
+    ///
+    /// ```rust
+    /// use try_next::{TryNext, TryNextExt, from_items};
+    ///
+    /// let mut tokens = from_items(["let", "x", "="]).array_peekable::<2>();
+    /// assert_eq!(tokens.try_peek_nth(1), Ok(Some(&"x")));
+    /// assert_eq!(tokens.try_next(), Ok(Some("let")));
+    /// ```
+    fn array_peekable<const N: usize>(self) -> ArrayPeekable<Self, N>
+    where
+        Self: Sized,
+    {
+        ArrayPeekable::new(self)
+    }
+
+    /// Lifts this source into a [`TryNextWithContext`] that ignores its
+    /// context.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextExt, TryNextWithContext, from_items};
+    ///
+    /// let mut src = from_items([1, 2]).ignore_context::<Vec<String>>();
+    /// let mut log = Vec::new();
+    /// assert_eq!(src.try_next_with_context(&mut log), Ok(Some(1)));
+    /// ```
+    fn ignore_context<C: ?Sized>(self) -> IgnoreContext<Self, C>
+    where
+        Self: Sized,
+    {
+        IgnoreContext::new(self)
+    }
+
+    /// Pulls up to `n` results ahead into an internal buffer whenever it
+    /// runs dry, without changing the sequence of results.
+    ///
+    /// Useful for smoothing out sources with bursty per-call cost on a
+    /// single thread; see [`prefetch`](Self::prefetch) for a threaded
+    /// alternative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    fn buffered(self, n: usize) -> Buffered<Self, Self::Item, Self::Error>
+    where
+        Self: Sized,
+    {
+        Buffered::new(self, n)
+    }
+
+    /// Moves this source onto a background thread that pulls up to
+    /// `capacity` results ahead of the consumer.
+    ///
+    /// ```rust
+    /// use try_next::{TryNext, TryNextExt, from_items};
+    ///
+    /// let mut src = from_items(0..1000).map(|n| n * 2).prefetch(64);
+    /// assert_eq!(src.try_next(), Ok(Some(0)));
+    /// assert_eq!(src.try_next(), Ok(Some(2)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    fn prefetch(self, capacity: usize) -> Prefetch<Self::Item, Self::Error>
+    where
+        Self: Sized + Send + 'static,
+        Self::Item: Send + 'static,
+        Self::Error: Send + 'static,
+    {
+        Prefetch::new(self, capacity)
+    }
+
+    /// Groups items into `Vec` batches of up to `max_items`, releasing a
+    /// partial batch once `max_wait` has passed since its first item.
+    ///
+    /// The source is moved onto a background thread so the deadline holds
+    /// even while a pull is blocked; see [`Batch`] for details.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use try_next::{TryNext, TryNextExt, from_items};
+    ///
+    /// let mut src = from_items(1..=5).batch(2, Duration::from_millis(50));
+    /// assert_eq!(src.try_next(), Ok(Some(vec![1, 2])));
+    /// assert_eq!(src.try_next(), Ok(Some(vec![3, 4])));
+    /// assert_eq!(src.try_next(), Ok(Some(vec![5])));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_items` is zero.
+    fn batch(self, max_items: usize, max_wait: Duration) -> Batch<Self::Item, Self::Error>
+    where
+        Self: Sized + Send + 'static,
+        Self::Item: Send + 'static,
+        Self::Error: Send + 'static,
+    {
+        Batch::new(self, max_items, max_wait)
+    }
+
+    /// Applies `f` to each item on a pool of `threads` worker threads,
+    /// yielding results in input order.
+    ///
+    /// Meant for CPU-bound per-item work; see [`ParMap`] for how ordering and
+    /// errors are handled.
+    ///
+    /// ```rust
+    /// use std::num::ParseIntError;
+    /// use try_next::{TryNext, TryNextExt, from_iter};
+    ///
+    /// let lines = ["1", "22", "x", "4"].map(Ok::<_, ParseIntError>);
+    /// let mut src = from_iter(lines).par_map(2, |s| s.parse::<u32>());
+    /// assert_eq!(src.try_next(), Ok(Some(1)));
+    /// assert_eq!(src.try_next(), Ok(Some(22)));
+    /// assert!(src.try_next().is_err());
+    /// assert_eq!(src.try_next(), Ok(Some(4)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero.
+    fn par_map<F, U>(self, threads: usize, f: F) -> ParMap<Self, U>
+    where
+        Self: Sized,
+        Self::Item: Send + 'static,
+        Self::Error: Send + 'static,
+        U: Send + 'static,
+        F: Fn(Self::Item) -> Result<U, Self::Error> + Send + Sync + 'static,
+    {
+        ParMap::new(self, threads, f)
+    }
+
+    /// Like [`par_map`](Self::par_map), but yields each result as soon as a
+    /// worker finishes it, in whatever order that happens.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextExt, from_iter};
+    ///
+    /// let mut src = from_iter((1..=4).map(Ok::<u64, ()>)).par_map_unordered(2, |n| Ok(n * n));
+    /// let mut squares: Vec<u64> = src.try_collect().unwrap();
+    /// squares.sort();
+    /// assert_eq!(squares, [1, 4, 9, 16]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero.
+    fn par_map_unordered<F, U>(self, threads: usize, f: F) -> ParMapUnordered<Self, U>
+    where
+        Self: Sized,
+        Self::Item: Send + 'static,
+        Self::Error: Send + 'static,
+        U: Send + 'static,
+        F: Fn(Self::Item) -> Result<U, Self::Error> + Send + Sync + 'static,
+    {
+        ParMapUnordered::new(self, threads, f)
+    }
+
+    /// Splits this source into `n` handles that each see every item and
+    /// error, buffering up to 64 results per handle and blocking the handle
+    /// that pulls ahead when another falls that far behind.
+    ///
+    /// ```rust
+    /// use std::thread;
+    /// use try_next::{TryNext, TryNextExt, from_items};
+    ///
+    /// let mut handles = from_items(1..=100u32).broadcast(2);
+    /// let mut counter = handles.pop().unwrap();
+    /// let mut summer = handles.pop().unwrap();
+    ///
+    /// let count = thread::spawn(move || {
+    ///     let mut count = 0;
+    ///     while counter.try_next().unwrap().is_some() {
+    ///         count += 1;
+    ///     }
+    ///     count
+    /// });
+    /// let sum: u32 = summer.try_collect::<Vec<_>>().unwrap().iter().sum();
+    /// assert_eq!((count.join().unwrap(), sum), (100, 5050));
+    /// ```
+    fn broadcast(self, n: usize) -> Vec<Broadcast<Self>>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        Self::Error: Clone,
+    {
+        Broadcast::split(self, n, 64, SlowConsumer::Block)
+    }
+
+    /// Like [`broadcast`](Self::broadcast), with `capacity` results buffered
+    /// per handle and `policy` deciding what happens to a handle that falls
+    /// further behind.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    fn broadcast_with(self, n: usize, capacity: usize, policy: SlowConsumer) -> Vec<Broadcast<Self>>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        Self::Error: Clone,
+    {
+        Broadcast::split(self, n, capacity, policy)
+    }
+
+    /// Moves this source behind a cloneable [`SharedHandle`], so several
+    /// threads can pull from it as a work queue.
+    fn shared_handle(self) -> SharedHandle<Self>
+    where
+        Self: Sized,
+    {
+        SharedHandle::new(self)
+    }
+
+    /// Boxes this source, erasing its concrete type.
+    fn boxed<'a>(self) -> BoxTryNext<'a, Self::Item, Self::Error>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Boxes this source as a [`BoxTryNextSend`], so it can be queued and
+    /// moved to another thread.
+    ///
+    /// ```rust
+    /// use std::thread;
+    /// use try_next::{BoxTryNextSend, TryNext, TryNextExt, from_items};
+    ///
+    /// let jobs: Vec<BoxTryNextSend<'static, u32, _>> = vec![
+    ///     from_items(1..=3).boxed_send(),
+    ///     from_items([10]).map(|n| n * 2).boxed_send(),
+    /// ];
+    /// let sums: Vec<u32> = jobs
+    ///     .into_iter()
+    ///     .map(|mut job| thread::spawn(move || job.try_collect::<Vec<_>>().unwrap().iter().sum()))
+    ///     .map(|handle| handle.join().unwrap())
+    ///     .collect();
+    /// assert_eq!(sums, [6, 20]);
+    /// ```
+    fn boxed_send<'a>(self) -> BoxTryNextSend<'a, Self::Item, Self::Error>
+    where
+        Self: Sized + Send + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Boxes this source without requiring `Send`.
+    ///
+    /// The same as [`boxed`](Self::boxed); spelled out for code that uses
+    /// [`boxed_send`](Self::boxed_send) elsewhere and wants the difference
+    /// visible at the call site.
+    fn boxed_local<'a>(self) -> BoxTryNext<'a, Self::Item, Self::Error>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Boxes this source as a [`BoxCloneTryNext`], which can be cloned
+    /// despite erasing the concrete type.
+    ///
+    /// Available with the `dyn-clone` feature.
+    #[cfg(feature = "dyn-clone")]
+    fn boxed_clone<'a>(self) -> BoxCloneTryNext<'a, Self::Item, Self::Error>
+    where
+        Self: Sized + Clone + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Boxes this source as a [`BoxCloneTryNextSend`], which can be cloned
+    /// and moved to another thread.
+    ///
+    /// Available with the `dyn-clone` feature.
+    #[cfg(feature = "dyn-clone")]
+    fn boxed_clone_send<'a>(self) -> BoxCloneTryNextSend<'a, Self::Item, Self::Error>
+    where
+        Self: Sized + Clone + Send + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Converts this source's errors into a [`BoxError`].
+    ///
+    /// Combined with [`boxed`](Self::boxed), sources with different error
+    /// types can be stored together.
+    ///
+    /// ```rust
+    /// use try_next::{BoxError, BoxTryNext, TryNextExt, from_iter};
+    ///
+    /// let sources: Vec<BoxTryNext<'_, u8, BoxError>> = vec![
+    ///     from_iter([Err(std::io::Error::other("offline"))]).boxed_err().boxed(),
+    ///     from_iter([Err(std::fmt::Error)]).boxed_err().boxed(),
+    /// ];
+    /// assert_eq!(sources.len(), 2);
+    /// ```
+    fn boxed_err(self) -> BoxedErr<Self>
+    where
+        Self: Sized,
+        Self::Error: Into<BoxError>,
+    {
+        BoxedErr::new(self)
+    }
+
+    /// Borrows this source, so an adapter chain can consume part of it and
+    /// the rest can still be used afterwards.
+    ///
+    /// Works like [`Iterator::by_ref`], through the [`TryNext`] impl for
+    /// `&mut S`.
+    ///
+    /// ```rust
+    /// use try_next::{TryNext, TryNextExt, from_items};
+    ///
+    /// let mut src = from_items(1..=5);
+    /// let header: Vec<u32> = src.by_ref().take(2).try_collect().unwrap();
+    /// assert_eq!(header, [1, 2]);
+    /// assert_eq!(src.try_next(), Ok(Some(3)));
+    /// ```
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Exposes this source of byte chunks as a Tokio
+    /// [`AsyncRead`](tokio::io::AsyncRead), pulling on the blocking thread
+    /// pool. See [`AsyncReader`].
+    ///
+    /// Available with the `tokio` feature.
+    #[cfg(feature = "tokio")]
+    fn into_async_read(self) -> AsyncReader<Self>
+    where
+        Self: Sized + Send + 'static,
+        Self::Item: AsRef<[u8]> + Send,
+        Self::Error: Into<std::io::Error> + Send,
+    {
+        AsyncReader::new(self)
+    }
+
+    /// Drains this source into `sink`, returning the number of items
+    /// forwarded.
+    ///
+    /// Method form of [`pump`](crate::pump).
+    fn try_forward<K>(&mut self, sink: &mut K) -> Result<usize, PumpError<Self::Error, K::Error>>
+    where
+        K: TryPush<Item = Self::Item> + ?Sized,
+    {
+        crate::pump(self, sink)
+    }
+
+    /// Drains this source into a collection, returning the first error.
+    ///
+    /// Items are pulled in blocks with [`try_next_chunk`](TryNext::try_next_chunk),
+    /// sized from [`size_hint`](TryNext::size_hint), so sources with bulk
+    /// overrides avoid per-item calls and `Vec` targets reserve each block
+    /// up front. Exact-size sources are drained in a single block.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextExt, from_items, from_iter};
+    ///
+    /// let all: Vec<u32> = from_items(0..5).try_collect().unwrap();
+    /// assert_eq!(all, [0, 1, 2, 3, 4]);
+    ///
+    /// let failed: Result<Vec<u32>, _> = from_iter([Ok(1), Err("bad")]).try_collect();
+    /// assert_eq!(failed, Err("bad"));
+    /// ```
+    fn try_collect<C>(&mut self) -> Result<C, Self::Error>
+    where
+        C: Default + Extend<Self::Item>,
+    {
+        const MIN_BLOCK: usize = 64;
+        let mut out = C::default();
+        loop {
+            let block = self.size_hint().0.max(MIN_BLOCK);
+            let chunk = self.try_next_chunk(block)?;
+            // A short chunk only means nothing more was ready; the source
+            // has ended once a chunk comes back empty.
+            if chunk.is_empty() {
+                return Ok(out);
+            }
+            out.extend(chunk);
+            if self.size_hint().1 == Some(0) {
+                return Ok(out);
+            }
+        }
+    }
+}
+
+impl<S: TryNext + ?Sized> TryNextExt for S {}
+
+/// Adapter methods for [`TryNextWithContext`] sources.
+///
+/// This trait is implemented for every [`TryNextWithContext`] type; bring it
+/// into scope to use the adapters.
+pub trait TryNextWithContextExt: TryNextWithContext {
+    /// Turns a source whose context is `()` into a plain [`TryNext`].
+    ///
+    /// ```rust
+    /// use try_next::{TryNext, TryNextWithContext, TryNextWithContextExt};
+    ///
+    /// struct Once(Option<char>);
+    ///
+    /// impl TryNextWithContext for Once {
+    ///     type Item = char;
+    ///     type Error = ();
+    ///     type Context = ();
+    ///
+    ///     fn try_next_with_context(&mut self, _: &mut ()) -> Result<Option<char>, ()> {
+    ///         Ok(self.0.take())
+    ///     }
+    /// }
+    ///
+    /// let mut src = Once(Some('x')).context_free();
+    /// assert_eq!(src.try_next(), Ok(Some('x')));
+    /// assert_eq!(src.try_next(), Ok(None));
+    /// ```
+    fn context_free(self) -> ContextFree<Self>
+    where
+        Self: Sized + TryNextWithContext<Context = ()>,
+    {
+        ContextFree::new(self)
+    }
+
+    /// Bundles this source with an owned context, producing a plain
+    /// [`TryNext`].
+    ///
+    /// The context is passed to the inner source on every call and can be
+    /// recovered with [`WithContext::into_parts`].
+    ///
+    /// ```rust
+    /// use try_next::{TryNext, TryNextWithContext, TryNextWithContextExt};
+    ///
+    /// struct Numbered;
+    ///
+    /// impl TryNextWithContext for Numbered {
+    ///     type Item = u32;
+    ///     type Error = ();
+    ///     type Context = u32;
+    ///
+    ///     fn try_next_with_context(&mut self, next: &mut u32) -> Result<Option<u32>, ()> {
+    ///         *next += 1;
+    ///         Ok(Some(*next))
+    ///     }
+    /// }
+    ///
+    /// let mut src = Numbered.with_context(10);
+    /// assert_eq!(src.try_next(), Ok(Some(11)));
+    /// assert_eq!(src.try_next(), Ok(Some(12)));
+    /// assert_eq!(src.into_parts().1, 12);
+    /// ```
+    fn with_context(self, context: Self::Context) -> WithContext<Self, Self::Context>
+    where
+        Self: Sized,
+        Self::Context: Sized,
+    {
+        WithContext::new(self, context)
+    }
+
+    /// Borrows this source and `context` together as a plain [`TryNext`].
+    ///
+    /// The returned view can be used with every [`TryNextExt`] adapter for
+    /// as long as the borrow lasts, after which the source and the context
+    /// are available again.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextExt, TryNextWithContext, TryNextWithContextExt};
+    ///
+    /// struct Pop;
+    ///
+    /// impl TryNextWithContext for Pop {
+    ///     type Item = u8;
+    ///     type Error = ();
+    ///     type Context = Vec<u8>;
+    ///
+    ///     fn try_next_with_context(&mut self, stack: &mut Vec<u8>) -> Result<Option<u8>, ()> {
+    ///         Ok(stack.pop())
+    ///     }
+    /// }
+    ///
+    /// let mut src = Pop;
+    /// let mut stack = vec![1, 2, 3];
+    /// let mut popped = Vec::new();
+    /// src.bind_context(&mut stack).try_forward(&mut popped).unwrap();
+    /// assert_eq!(popped, [3, 2, 1]);
+    /// assert!(stack.is_empty());
+    /// ```
+    fn bind_context<'a>(&'a mut self, context: &'a mut Self::Context) -> BindContext<'a, Self> {
+        BindContext::new(self, context)
+    }
+
+    /// Runs this source under a larger context `B`, using `project` to
+    /// borrow the part of `B` the source needs.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextWithContext, TryNextWithContextExt};
+    ///
+    /// struct App { ticks: u32, name: String }
+    ///
+    /// struct Tick;
+    ///
+    /// impl TryNextWithContext for Tick {
+    ///     type Item = u32;
+    ///     type Error = ();
+    ///     type Context = u32;
+    ///
+    ///     fn try_next_with_context(&mut self, ticks: &mut u32) -> Result<Option<u32>, ()> {
+    ///         *ticks += 1;
+    ///         Ok(Some(*ticks))
+    ///     }
+    /// }
+    ///
+    /// let mut src = Tick.map_context(|app: &mut App| &mut app.ticks);
+    /// let mut app = App { ticks: 0, name: "demo".into() };
+    /// assert_eq!(src.try_next_with_context(&mut app), Ok(Some(1)));
+    /// assert_eq!(app.ticks, 1);
+    /// ```
+    fn map_context<B, F>(self, project: F) -> MapContext<Self, F, B>
+    where
+        Self: Sized,
+        F: FnMut(&mut B) -> &mut Self::Context,
+        B: ?Sized,
+    {
+        MapContext::new(self, project)
+    }
+
+    /// Transforms each item with `f`, passing the context through to the
+    /// inner source.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextWithContext, TryNextWithContextExt};
+    ///
+    /// struct Pop;
+    ///
+    /// impl TryNextWithContext for Pop {
+    ///     type Item = u8;
+    ///     type Error = ();
+    ///     type Context = Vec<u8>;
+    ///
+    ///     fn try_next_with_context(&mut self, stack: &mut Vec<u8>) -> Result<Option<u8>, ()> {
+    ///         Ok(stack.pop())
+    ///     }
+    /// }
+    ///
+    /// let mut src = Pop.map(char::from);
+    /// let mut stack = vec![b'a', b'b'];
+    /// assert_eq!(src.try_next_with_context(&mut stack), Ok(Some('b')));
+    /// assert_eq!(src.try_next_with_context(&mut stack), Ok(Some('a')));
+    /// ```
+    fn map<F, T>(self, f: F) -> Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> T,
+    {
+        Map::new(self, f)
+    }
+
+    /// Yields only the items for which `pred` returns `true`, passing the
+    /// context through to the inner source.
+    fn filter<P>(self, pred: P) -> Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Filter::new(self, pred)
+    }
+
+    /// Transforms each item with `f`, which also receives the context.
+    ///
+    /// The closure runs after the inner source has produced the item, so it
+    /// can read or update the same context, e.g. a symbol table or cache.
+    ///
+    /// ```rust
+    /// use try_next::{TryNextExt, TryNextWithContext, TryNextWithContextExt, from_items};
+    ///
+    /// let mut src = from_items(["x", "y"])
+    ///     .ignore_context::<Vec<String>>()
+    ///     .map_with_context(|names, name| {
+    ///         names.push(name.to_owned());
+    ///         names.len()
+    ///     });
+    /// let mut names = Vec::new();
+    /// assert_eq!(src.try_next_with_context(&mut names), Ok(Some(1)));
+    /// assert_eq!(src.try_next_with_context(&mut names), Ok(Some(2)));
+    /// assert_eq!(names, ["x", "y"]);
+    /// ```
+    fn map_with_context<F, T>(self, f: F) -> MapWithContext<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut Self::Context, Self::Item) -> T,
+    {
+        MapWithContext::new(self, f)
+    }
+
+    /// Writes each item into a recycled buffer from the [`Pool`] held by the
+    /// context, yielding the buffer as a [`Pooled`](crate::Pooled) guard.
+    ///
+    /// Buffers return to the pool when the consumer drops them, so steady
+    /// state pipelines stop allocating a fresh buffer per item.
+    fn map_pooled<F, T>(self, f: F) -> MapPooled<Self, F, T>
+    where
+        Self: Sized,
+        Self::Context: AsRef<Pool<T>>,
+        F: FnMut(&mut T, Self::Item),
+        T: Default + Recycle,
+    {
+        MapPooled::new(self, f)
+    }
+
+    /// Yields only the items for which `pred` returns `true`; the predicate
+    /// also receives the context.
+    ///
+    /// Errors are passed through without consulting `pred`.
+    fn filter_with_context<P>(self, pred: P) -> FilterWithContext<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&mut Self::Context, &Self::Item) -> bool,
+    {
+        FilterWithContext::new(self, pred)
+    }
+
+    /// Yields at most `n` items, passing the context through to the inner
+    /// source.
+    fn take(self, n: usize) -> Take<Self>
+    where
+        Self: Sized,
+    {
+        Take::new(self, n)
+    }
+
+    /// Yields exactly `n` items, returning
+    /// [`TakeExactError::Short`](crate::adapters::TakeExactError::Short) with
+    /// the count received if the source ends first. The context is passed
+    /// through to the inner source.
+    fn take_exact(self, n: usize) -> TakeExact<Self>
+    where
+        Self: Sized,
+    {
+        TakeExact::new(self, n)
+    }
+
+    /// Calls `f` with a reference to each item before yielding it, passing
+    /// the context through to the inner source.
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item),
+    {
+        Inspect::new(self, f)
+    }
+
+    /// Pairs each item with a running state, such as a total or an offset.
+    ///
+    /// The state starts at `A::default()`, `fold` updates it for each item,
+    /// and the item is yielded with a clone of the updated state. The
+    /// context is passed through to the inner source.
+    fn running<A, F>(self, fold: F) -> Running<Self, A, F>
+    where
+        Self: Sized,
+        A: Default + Clone,
+        F: FnMut(&mut A, &Self::Item),
+    {
+        Running::new(self, fold)
+    }
+
+    /// Yields `f(previous, current)` for each pair of consecutive items.
+    ///
+    /// The first item is skipped unless
+    /// [`with_first`](Deltas::with_first) is called on the result. The
+    /// context is passed through to the inner source.
+    fn deltas<F, U>(self, f: F) -> Deltas<Self, Self::Item, U, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> U,
+    {
+        Deltas::new(self, f)
+    }
+
+    /// Groups items into `Vec`s of `size` items, passing the context through
+    /// to the inner source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn chunks(self, size: usize) -> Chunks<Self, Self::Item>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self, size)
+    }
+
+    /// Groups items into `Vec`s of exactly `size` items.
+    ///
+    /// A short final chunk is not yielded; it is kept for
+    /// [`into_remainder`](ChunksExact::into_remainder), or reported as an
+    /// error after [`deny_remainder`](ChunksExact::deny_remainder). The
+    /// context is passed through to the inner source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    fn chunks_exact(self, size: usize) -> ChunksExact<Self, Self::Item>
+    where
+        Self: Sized,
+    {
+        ChunksExact::new(self, size)
+    }
+
+    /// Groups items into fixed-capacity [`heapless::Vec`]s of `N` items,
+    /// passing the context through to the inner source.
+    ///
+    /// Available with the `heapless` feature.
+    #[cfg(feature = "heapless")]
+    fn heapless_chunks<const N: usize>(self) -> HeaplessChunks<Self, Self::Item, N>
+    where
+        Self: Sized,
+    {
+        HeaplessChunks::new(self)
+    }
+
+    /// Yields only the last `N` items, kept in a fixed-capacity
+    /// [`heapless::Deque`], passing the context through to the inner source.
+    ///
+    /// Available with the `heapless` feature.
+    #[cfg(feature = "heapless")]
+    fn heapless_tail<const N: usize>(self) -> HeaplessTail<Self, Self::Item, N>
+    where
+        Self: Sized,
+    {
+        HeaplessTail::new(self)
+    }
+
+    /// Retries failed pulls according to `policy`, passing the context to
+    /// every attempt.
+    fn retry<P>(self, policy: P) -> Retry<Self, P>
+    where
+        Self: Sized,
+        P: RetryPolicy,
+    {
+        Retry::new(self, policy, AnyError)
+    }
+
+    /// Like [`retry`](Self::retry), but retries only errors whose
+    /// [`RetryableError`] implementation reports them as transient, and
+    /// waits at least as long as they ask for.
+    fn retry_transient<P>(self, policy: P) -> Retry<Self, P, Transient>
+    where
+        Self: Sized,
+        Self::Error: RetryableError,
+        P: RetryPolicy,
+    {
+        Retry::new(self, policy, Transient)
+    }
+
+    /// Calls `f` with a reference to each error before returning it,
+    /// passing the context through to the inner source.
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Error),
+    {
+        InspectErr::new(self, f)
+    }
+
+    /// Counts items, errors, and ends, and times each call, passing the
+    /// context through; see [`Instrumented::stats`] and
+    /// [`Instrumented::handle`].
+    fn instrument(self) -> Instrumented<Self>
+    where
+        Self: Sized,
+    {
+        Instrumented::new(self)
+    }
+
+    /// Reports counts, latency, and throughput to `recorder`, labelled with
+    /// `stage`; see [`Metered`].
+    fn metered<R>(self, recorder: R, stage: &'static str) -> Metered<Self, R>
+    where
+        Self: Sized,
+        R: MetricsRecorder,
+    {
+        Metered::new(self, recorder, stage)
+    }
+
+    /// Runs each pull inside `span` and emits [`tracing`] events for errors
+    /// and termination; see [`Traced`] for levels and item fields.
+    ///
+    /// Available with the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    fn traced(self, span: tracing::Span) -> Traced<Self, fn(&Self::Item)>
+    where
+        Self: Sized,
+    {
+        Traced::new(self, span)
+    }
+
+    /// Logs errors and termination through the [`log`] facade, prefixed
+    /// with `name`; see [`Logged`] for levels and item formatting.
+    ///
+    /// Available with the `log` feature.
+    #[cfg(feature = "log")]
+    fn logged(self, name: &'static str) -> Logged<Self, fn(&Self::Item) -> &'static str>
+    where
+        Self: Sized,
+    {
+        Logged::new(self, name)
+    }
+
+    /// Prints each item, error, and the end of input to standard error with
+    /// `{:?}`, passing the context through to the inner source.
+    fn debug(self) -> Printed<Self, Self::Item, Self::Error>
+    where
+        Self: Sized,
+        Self::Item: Debug,
+        Self::Error: Debug,
+    {
+        Printed::new(self)
+    }
+
+    /// Passes each error to `handler` and keeps pulling, yielding only
+    /// successful items.
+    ///
+    /// The handler returns `Ok(())` to skip the error or `Err` to pass it on.
+    fn skip_errors<F>(self, handler: F) -> SkipErrors<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Error) -> Result<(), Self::Error>,
+    {
+        SkipErrors::new(self, handler)
+    }
+
+    /// Wraps errors in [`Contextualized`](crate::Contextualized) with
+    /// `message`, recording which stage of a pipeline failed.
+    fn err_context<M>(self, message: M) -> ErrContext<Self, M>
+    where
+        Self: Sized,
+        M: Display,
+    {
+        ErrContext::new(self, message)
+    }
+
+    /// Like [`err_context`](Self::err_context), but builds the message with `f` only
+    /// when an error occurs.
+    fn err_context_with<F, M>(self, f: F) -> ErrContextWith<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> M,
+        M: Display,
+    {
+        ErrContextWith::new(self, f)
+    }
+
+    /// Wraps errors in [`Located`](crate::Located), recording how many items
+    /// were produced before each one.
+    fn locate(self) -> Locate<Self>
+    where
+        Self: Sized,
+    {
+        Locate::new(self, |_| None)
+    }
+
+    /// Like [`locate`](Self::locate), but also records the source's
+    /// [`ByteOffset`] at the time of each error.
+    fn locate_bytes(self) -> Locate<Self>
+    where
+        Self: Sized + ByteOffset,
+    {
+        Locate::new(self, |source| Some(source.byte_offset()))
+    }
+
+    /// Stops polling this source after its first `Ok(None)` or error, and
+    /// flags callers that keep polling.
+    ///
+    /// Further calls panic in debug builds and return `Ok(None)` in release
+    /// builds.
+    fn strict(self) -> Strict<Self>
+    where
+        Self: Sized,
+    {
+        Strict::new(self)
+    }
+
+    /// Boxes this source, erasing its concrete type.
+    fn boxed<'a>(self) -> BoxTryNextWithContext<'a, Self::Item, Self::Error, Self::Context>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Boxes this source as a [`BoxTryNextWithContextSend`], so it can be
+    /// queued and moved to another thread.
+    fn boxed_send<'a>(self) -> BoxTryNextWithContextSend<'a, Self::Item, Self::Error, Self::Context>
+    where
+        Self: Sized + Send + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Boxes this source without requiring `Send`; the same as
+    /// [`boxed`](Self::boxed).
+    fn boxed_local<'a>(self) -> BoxTryNextWithContext<'a, Self::Item, Self::Error, Self::Context>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
+
+    /// Converts this source's errors into a [`BoxError`], passing the
+    /// context through to the inner source.
+    fn boxed_err(self) -> BoxedErr<Self>
+    where
+        Self: Sized,
+        Self::Error: Into<BoxError>,
+    {
+        BoxedErr::new(self)
+    }
+
+    /// Stops polling this source after `threshold` consecutive errors
+    /// occurring within `window`, passing the context through otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is zero.
+    fn circuit_breaker(self, threshold: usize, window: Duration) -> CircuitBreaker<Self>
+    where
+        Self: Sized,
+    {
+        CircuitBreaker::new(self, threshold, window)
+    }
+
+    /// Caps this source at `rate` items per second, passing the context
+    /// through to the inner source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not a positive, finite number.
+    fn throttle(self, rate: f64) -> Throttle<Self>
+    where
+        Self: Sized,
+    {
+        Throttle::new(self, rate)
+    }
+
+    /// Blocks pulls while `handle` is paused, passing the context through to
+    /// the inner source.
+    fn gate(self, handle: GateHandle) -> Gate<Self>
+    where
+        Self: Sized,
+    {
+        Gate::new(self, handle)
+    }
+
+    /// Returns [`GateError::Paused`](crate::adapters::GateError::Paused)
+    /// instead of pulling while `handle` is paused, passing the context
+    /// through to the inner source.
+    fn try_gate(self, handle: GateHandle) -> TryGate<Self>
+    where
+        Self: Sized,
+    {
+        TryGate::new(self, handle)
+    }
+
+    /// Ends this source once any of `limits` is used up, passing the
+    /// context through to the inner source.
+    fn budget(self, limits: BudgetLimits) -> Budget<Self>
+    where
+        Self: Sized,
+    {
+        Budget::new(self, limits)
+    }
+
+    /// Yields only the items selected by `sampling`, passing the context
+    /// through to the inner source.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sampling` is `EveryNth(0)` or has a probability outside
+    /// `0.0..=1.0`.
+    fn sample(self, sampling: Sampling) -> Sample<Self>
+    where
+        Self: Sized,
+    {
+        Sample::new(self, sampling)
+    }
+
+    /// Keeps each item with the probability `probability` returns for it,
+    /// passing the context through to the inner source.
+    ///
+    /// Available with the `rand` feature.
+    #[cfg(feature = "rand")]
+    fn sample_by<R, F>(self, rng: R, probability: F) -> SampleBy<Self, R, F>
+    where
+        Self: Sized,
+        R: rand::Rng,
+        F: FnMut(&Self::Item) -> f64,
+    {
+        SampleBy::new(self, rng, probability)
+    }
+
+    /// Folds the items of each window into one aggregate, yielding it when
+    /// the window closes.
+    ///
+    /// `window` is a window size, for windows of a fixed number of items,
+    /// or a [`ByKey`](crate::adapters::ByKey), for windows of consecutive
+    /// items with the same key. Each window's fold starts from a clone of
+    /// `init`; the last window may be partial. The context is passed through
+    /// to the inner source.
+    ///
+    /// # Panics
+    ///
+    /// Panics on the first item if `window` is a size of zero.
+    fn windowed_fold<W, A, F>(self, window: W, init: A, fold: F) -> WindowedFold<Self, W, A, F>
+    where
+        Self: Sized,
+        W: Windowing<Self::Item>,
+        A: Clone,
+        F: FnMut(A, Self::Item) -> A,
+    {
+        WindowedFold::new(self, window, init, fold)
+    }
+
+    /// Yields the items in a randomized order, choosing each one at random
+    /// from a buffer of up to `buffer_size` pending items. The context is
+    /// passed through to the inner source.
+    ///
+    /// Available with the `rand` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer_size` is zero.
+    #[cfg(feature = "rand")]
+    fn shuffle<R: rand::Rng>(self, buffer_size: usize, rng: R) -> Shuffle<Self, Self::Item, R>
+    where
+        Self: Sized,
+    {
+        Shuffle::new(self, buffer_size, rng)
+    }
+
+    /// Records every item so the source can be rewound and read again,
+    /// passing the context through to the inner source.
+    fn replayable(self) -> Replayable<Self, Vec<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Replayable::new(self, Vec::new())
+    }
+
+    /// Records every item into `buffer` so the source can be rewound and
+    /// read again, passing the context through to the inner source.
+    fn replayable_with<B>(self, buffer: B) -> Replayable<Self, B>
+    where
+        Self: Sized,
+        B: ReplayBuffer<Self::Item, Self::Error>,
+    {
+        Replayable::new(self, buffer)
+    }
+
+    /// Replaces the calls selected by `plan` with errors built by
+    /// `make_error`, for exercising error paths in tests.
+    ///
+    /// Available with the `test-util` feature.
+    #[cfg(feature = "test-util")]
+    fn inject_faults<F>(self, plan: FaultPlan, make_error: F) -> FaultInjector<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> Self::Error,
+    {
+        FaultInjector::new(self, plan, make_error)
+    }
+
+    /// Logs a clone of every call's outcome; see [`Recorded::log`].
+    ///
+    /// Available with the `test-util` feature.
+    #[cfg(feature = "test-util")]
+    #[allow(clippy::type_complexity)]
+    fn recorded(
+        self,
+    ) -> Recorded<
+        Self,
+        Result<Option<Self::Item>, Self::Error>,
+        fn(&Result<Option<Self::Item>, Self::Error>) -> Result<Option<Self::Item>, Self::Error>,
+    >
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        Self::Error: Clone,
+    {
+        Recorded::new(self, Clone::clone)
+    }
+
+    /// Logs a summary of every call's outcome built by `summarize`, for
+    /// items or errors that are not [`Clone`] or too large to keep.
+    ///
+    /// Available with the `test-util` feature.
+    #[cfg(feature = "test-util")]
+    fn recorded_with<R, F>(self, summarize: F) -> Recorded<Self, R, F>
+    where
+        Self: Sized,
+        F: FnMut(&Result<Option<Self::Item>, Self::Error>) -> R,
+    {
+        Recorded::new(self, summarize)
+    }
+
+    /// Pulls up to `n` results ahead into an internal buffer whenever it
+    /// runs dry, passing the context to each pull.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    fn buffered(self, n: usize) -> Buffered<Self, Self::Item, Self::Error>
+    where
+        Self: Sized,
+    {
+        Buffered::new(self, n)
+    }
+
+    /// Restores the context to its state before the pull whenever the inner
+    /// source returns an error.
+    ///
+    /// See [`SnapshotContext`] for what the context must provide.
+    fn backtrack(self) -> Backtrack<Self>
+    where
+        Self: Sized,
+        Self::Context: SnapshotContext,
+    {
+        Backtrack::new(self)
+    }
+
+    /// Yields all items of this source, then all items of `other`.
+    ///
+    /// The two sources may have different context and error types; the
+    /// combined source takes the pair `(Self::Context, B::Context)`, passes
+    /// each side its own half, and reports errors as
+    /// [`EitherError<Self::Error, B::Error>`](crate::EitherError).
+    fn chain<B>(self, other: B) -> Chain<Self, B>
+    where
+        Self: Sized,
+        B: TryNextWithContext<Item = Self::Item>,
+    {
+        Chain::new(self, other)
+    }
+
+    /// Yields pairs of items from this source and `other`, ending when either
+    /// ends.
+    ///
+    /// The combined context is the pair `(Self::Context, B::Context)` and
+    /// the combined error is
+    /// [`EitherError<Self::Error, B::Error>`](crate::EitherError).
+    fn zip<B>(self, other: B) -> Zip<Self, B>
+    where
+        Self: Sized,
+        B: TryNextWithContext,
+    {
+        Zip::new(self, other)
+    }
+
+    /// Alternates between the items of this source and `other`, starting
+    /// with this one.
+    ///
+    /// The combined context is the pair `(Self::Context, B::Context)` and
+    /// the combined error is
+    /// [`EitherError<Self::Error, B::Error>`](crate::EitherError).
+    fn interleave<B>(self, other: B) -> Interleave<Self, B>
+    where
+        Self: Sized,
+        B: TryNextWithContext<Item = Self::Item>,
+    {
+        Interleave::new(self, other)
+    }
+
+    /// Sort-merge joins this source with `other`, yielding each item as
+    /// [`Left`](crate::adapters::EitherOrBoth::Left),
+    /// [`Right`](crate::adapters::EitherOrBoth::Right), or
+    /// [`Both`](crate::adapters::EitherOrBoth::Both).
+    ///
+    /// Both sources must be sorted ascending by `cmp`. The combined context
+    /// is the pair `(Self::Context, B::Context)` and the combined error is
+    /// [`EitherError<Self::Error, B::Error>`](crate::EitherError).
+    fn merge_join_by<B, F>(self, other: B, cmp: F) -> MergeJoinBy<Self, B, Self::Item, B::Item, F>
+    where
+        Self: Sized,
+        B: TryNextWithContext,
+        F: FnMut(&Self::Item, &B::Item) -> Ordering,
+    {
+        MergeJoinBy::new(self, other, cmp)
+    }
+
+    /// Runs this source against a context shared through `Rc<RefCell<_>>`,
+    /// producing a plain [`TryNext`].
+    ///
+    /// The context is borrowed only for the duration of each call, so other
+    /// sources holding the same `Rc` can use it in between.
+    fn with_shared_context(
+        self,
+        context: Rc<RefCell<Self::Context>>,
+    ) -> SharedContext<Self, Self::Context>
+    where
+        Self: Sized,
+    {
+        SharedContext::new(self, context)
+    }
+
+    /// Runs this source against a context shared through `Arc<Mutex<_>>`,
+    /// producing a plain [`TryNext`].
+    ///
+    /// The mutex is locked only for the duration of each call, so sources on
+    /// other threads holding the same `Arc` can use it in between.
+    fn with_locked_context(
+        self,
+        context: Arc<Mutex<Self::Context>>,
+    ) -> LockedContext<Self, Self::Context>
+    where
+        Self: Sized,
+    {
+        LockedContext::new(self, context)
+    }
+
+    /// Runs this source against contexts handed out by `provider`, producing
+    /// a plain [`TryNext`].
+    ///
+    /// The provider is asked for a context on every call; see
+    /// [`ProvideContext`] and [`fresh_context`](crate::fresh_context).
+    fn with_provider<P>(self, provider: P) -> Provided<Self, P>
+    where
+        Self: Sized,
+        P: ProvideContext<Self::Context>,
+    {
+        Provided::new(self, provider)
+    }
+}
+
+impl<S: TryNextWithContext + ?Sized> TryNextWithContextExt for S {}
+
+#[cfg(test)]
+mod tests {
+    use super::TryNextExt;
+    use crate::TryNext;
+    use crate::sources::ChannelSource;
+    use std::convert::Infallible;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    /// Yields `0..len`, recording the size of every chunk request.
+    struct Blocks {
+        next: usize,
+        len: usize,
+        requests: Vec<usize>,
+    }
+
+    impl TryNext for Blocks {
+        type Item = usize;
+        type Error = Infallible;
+
+        fn try_next(&mut self) -> Result<Option<usize>, Infallible> {
+            let item = (self.next < self.len).then_some(self.next);
+            self.next += item.is_some() as usize;
+            Ok(item)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.len - self.next;
+            (remaining, Some(remaining))
+        }
+
+        fn try_next_chunk(&mut self, max: usize) -> Result<Vec<usize>, Infallible> {
+            self.requests.push(max);
+            let end = self.len.min(self.next + max);
+            let chunk = (self.next..end).collect();
+            self.next = end;
+            Ok(chunk)
+        }
+    }
+
+    #[test]
+    fn collect_drains_exact_size_sources_in_one_block() {
+        let mut src = Blocks {
+            next: 0,
+            len: 1000,
+            requests: Vec::new(),
+        };
+        let all: Vec<usize> = src.try_collect().unwrap();
+
+        assert_eq!(all.len(), 1000);
+        assert_eq!(src.requests, [1000]);
+    }
+
+    #[test]
+    fn collect_waits_out_pauses_in_a_live_source() {
+        let (tx, rx) = mpsc::channel();
+        let producer = thread::spawn(move || {
+            for burst in (0..1000).collect::<Vec<_>>().chunks(100) {
+                for &item in burst {
+                    tx.send(item).unwrap();
+                }
+                thread::sleep(Duration::from_millis(2));
+            }
+        });
+
+        let all: Vec<u32> = ChannelSource::new(rx).try_collect().unwrap();
+        producer.join().unwrap();
+        assert_eq!(all, (0..1000).collect::<Vec<_>>());
+    }
+}
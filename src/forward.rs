@@ -0,0 +1,118 @@
+//! Forwarding implementations for references and boxes.
+//!
+//! As with [`Iterator`], a `&mut S` or `Box<S>` is itself a source, so
+//! adapters can take sources by value yet still be used with borrowed or
+//! boxed ones.
+
+use std::num::NonZeroUsize;
+
+use crate::{
+    DoubleEndedTryNext, ExactSizeTryNext, FusedTryNext, TryNext, TryNextWithContext, TryPeek,
+};
+
+macro_rules! forward_try_next {
+    ($($ty:ty),*) => {$(
+        impl<S: TryNext + ?Sized> TryNext for $ty {
+            type Item = S::Item;
+            type Error = S::Error;
+
+            fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+                (**self).try_next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (**self).size_hint()
+            }
+
+            fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+                (**self).try_next_chunk(max)
+            }
+
+            fn try_fill(&mut self, buf: &mut [Self::Item]) -> Result<usize, Self::Error>
+            where
+                Self::Item: Copy,
+            {
+                (**self).try_fill(buf)
+            }
+
+            fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+                (**self).try_advance_by(n)
+            }
+        }
+
+        impl<S: DoubleEndedTryNext + ?Sized> DoubleEndedTryNext for $ty {
+            fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+                (**self).try_next_back()
+            }
+        }
+
+        impl<S: ExactSizeTryNext + ?Sized> ExactSizeTryNext for $ty {
+            fn len(&self) -> usize {
+                (**self).len()
+            }
+        }
+
+        impl<S: FusedTryNext + ?Sized> FusedTryNext for $ty {}
+
+        impl<S: TryPeek + ?Sized> TryPeek for $ty {
+            fn try_peek(&mut self) -> Result<Option<&Self::Item>, Self::Error> {
+                (**self).try_peek()
+            }
+        }
+
+        impl<S: TryNextWithContext + ?Sized> TryNextWithContext for $ty {
+            type Item = S::Item;
+            type Error = S::Error;
+            type Context = S::Context;
+
+            fn try_next_with_context(
+                &mut self,
+                context: &mut Self::Context,
+            ) -> Result<Option<Self::Item>, Self::Error> {
+                (**self).try_next_with_context(context)
+            }
+        }
+    )*};
+}
+
+forward_try_next!(&mut S, Box<S>);
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::fixtures::Pop;
+    use crate::{BoxTryNext, TryNext, TryNextExt, TryNextWithContext, from_items};
+    use std::convert::Infallible;
+
+    #[test]
+    fn borrowed_source_can_be_adapted_and_reused() {
+        let mut src = from_items([1, 2, 3]);
+
+        let mut fused = (&mut src).fuse();
+        assert_eq!(fused.try_next(), Ok(Some(1)));
+        assert_eq!(fused.size_hint(), (2, Some(2)));
+
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(Some(3)));
+    }
+
+    #[test]
+    fn boxed_source_is_a_source() {
+        let src: BoxTryNext<'_, u8, Infallible> = from_items([7]).boxed();
+        let mut fused = src.fuse();
+
+        assert_eq!(fused.try_next(), Ok(Some(7)));
+        assert_eq!(fused.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn context_sources_forward_through_references() {
+        fn first<S: TryNextWithContext>(mut src: S, ctx: &mut S::Context) -> Option<S::Item> {
+            src.try_next_with_context(ctx).ok().flatten()
+        }
+
+        let mut pop = Pop;
+        let mut ctx = vec![1, 2];
+        assert_eq!(first(&mut pop, &mut ctx), Some(2));
+        assert_eq!(first(Box::new(pop), &mut ctx), Some(1));
+    }
+}
@@ -0,0 +1,157 @@
+//! Implementing [`TryNext`](crate::TryNext) for existing iterator types.
+
+/// Implements [`TryNext`](crate::TryNext) for a type that already implements
+/// [`Iterator`].
+///
+/// With just a type, its items must be `Result<T, E>`, and the source yields
+/// `T` and fails with `E`, like [`from_iter`](crate::from_iter). With an
+/// error type after a comma, its items are yielded as they are and the
+/// error type is only nominal, like [`from_items`](crate::from_items) but
+/// with an error type of your choosing.
+///
+/// Prefix the type with `impl<..>` to cover a generic type; the iterator's
+/// own bounds are picked up automatically.
+///
+/// ```rust
+/// use std::convert::Infallible;
+///
+/// use try_next::{TryNext, impl_try_next_from_iterator};
+///
+/// struct Countdown(u32);
+///
+/// impl Iterator for Countdown {
+///     type Item = u32;
+///
+///     fn next(&mut self) -> Option<u32> {
+///         self.0 = self.0.checked_sub(1)?;
+///         Some(self.0)
+///     }
+/// }
+///
+/// impl_try_next_from_iterator!(Countdown, Infallible);
+///
+/// struct Parsed<I>(I);
+///
+/// impl<'a, I: Iterator<Item = &'a str>> Iterator for Parsed<I> {
+///     type Item = Result<u8, std::num::ParseIntError>;
+///
+///     fn next(&mut self) -> Option<Self::Item> {
+///         self.0.next().map(str::parse)
+///     }
+/// }
+///
+/// impl_try_next_from_iterator!(impl<I> Parsed<I>);
+///
+/// let mut countdown = Countdown(2);
+/// assert_eq!(countdown.try_next(), Ok(Some(1)));
+///
+/// let mut parsed = Parsed(["7", "x"].into_iter());
+/// assert_eq!(parsed.try_next(), Ok(Some(7)));
+/// assert!(parsed.try_next().is_err());
+/// ```
+///
+/// The type keeps its [`Iterator`] impl, so methods both traits offer, such
+/// as `size_hint` or the `map` and `filter` adapters, need fully qualified
+/// syntax where both are in scope.
+#[macro_export]
+macro_rules! impl_try_next_from_iterator {
+    (@results [$($generic:tt),*] $ty:ty) => {
+        impl<$($generic),*> $crate::TryNext for $ty
+        where
+            Self: ::std::iter::Iterator,
+            <Self as ::std::iter::Iterator>::Item: $crate::__IterResult,
+        {
+            type Item = <<Self as ::std::iter::Iterator>::Item as $crate::__IterResult>::Ok;
+            type Error = <<Self as ::std::iter::Iterator>::Item as $crate::__IterResult>::Err;
+
+            fn try_next(
+                &mut self,
+            ) -> ::std::result::Result<::std::option::Option<Self::Item>, Self::Error> {
+                ::std::iter::Iterator::next(self)
+                    .map($crate::__IterResult::into_result)
+                    .transpose()
+            }
+
+            fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+                // Errors are not items, so only the upper bound carries over.
+                (0, ::std::iter::Iterator::size_hint(self).1)
+            }
+        }
+    };
+    (@items [$($generic:tt),*] $ty:ty, $error:ty) => {
+        impl<$($generic),*> $crate::TryNext for $ty
+        where
+            Self: ::std::iter::Iterator,
+        {
+            type Item = <Self as ::std::iter::Iterator>::Item;
+            type Error = $error;
+
+            fn try_next(
+                &mut self,
+            ) -> ::std::result::Result<::std::option::Option<Self::Item>, Self::Error> {
+                ::std::result::Result::Ok(::std::iter::Iterator::next(self))
+            }
+
+            fn size_hint(&self) -> (usize, ::std::option::Option<usize>) {
+                ::std::iter::Iterator::size_hint(self)
+            }
+        }
+    };
+    (impl<$($generic:tt),* $(,)?> $ty:ty) => {
+        $crate::impl_try_next_from_iterator!(@results [$($generic),*] $ty);
+    };
+    (impl<$($generic:tt),* $(,)?> $ty:ty, $error:ty) => {
+        $crate::impl_try_next_from_iterator!(@items [$($generic),*] $ty, $error);
+    };
+    ($ty:ty) => {
+        $crate::impl_try_next_from_iterator!(@results [] $ty);
+    };
+    ($ty:ty, $error:ty) => {
+        $crate::impl_try_next_from_iterator!(@items [] $ty, $error);
+    };
+}
+
+/// Splits an iterator's `Result` items for
+/// [`impl_try_next_from_iterator!`](crate::impl_try_next_from_iterator).
+#[doc(hidden)]
+pub trait IterResult {
+    type Ok;
+    type Err;
+
+    fn into_result(self) -> Result<Self::Ok, Self::Err>;
+}
+
+impl<T, E> IterResult for Result<T, E> {
+    type Ok = T;
+    type Err = E;
+
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TryNext, TryNextExt};
+
+    struct Digits<'a>(std::str::Chars<'a>);
+
+    impl Iterator for Digits<'_> {
+        type Item = Result<u32, char>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.0.next().map(|c| c.to_digit(10).ok_or(c))
+        }
+    }
+
+    impl_try_next_from_iterator!(impl<'a> Digits<'a>);
+
+    #[test]
+    fn adapts_result_iterators() {
+        let digits = Digits("1x2".chars());
+        assert_eq!(TryNext::size_hint(&digits).0, 0);
+
+        let collected: Result<Vec<u32>, char> = digits.skip_errors(|_| Ok(())).try_collect();
+        assert_eq!(collected, Ok(vec![1, 2]));
+    }
+}
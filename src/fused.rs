@@ -0,0 +1,15 @@
+//! Sources that stay exhausted.
+
+use crate::TryNext;
+
+/// A [`TryNext`] that keeps returning `Ok(None)` once it has returned
+/// `Ok(None)`.
+///
+/// Plain [`TryNext`] sources make no promise about what happens after the
+/// end of the sequence: they may start yielding items again, return errors,
+/// or even panic. Implementing this marker trait is a guarantee that they
+/// don't, which lets generic code skip defensive state tracking.
+///
+/// Use [`TryNextExt::fuse`](crate::TryNextExt::fuse) to turn any source into
+/// one that upholds this contract.
+pub trait FusedTryNext: TryNext {}
@@ -0,0 +1,150 @@
+//! Conversion into a [`TryNext`] source.
+
+use std::collections::{VecDeque, vec_deque};
+use std::sync::mpsc::Receiver;
+use std::vec;
+
+use crate::TryNext;
+#[cfg(feature = "tokio")]
+use crate::sources::TokioChannelSource;
+use crate::sources::{ChannelSource, FromItems, from_items};
+
+/// Conversion into a [`TryNext`].
+///
+/// This is the analogue of [`IntoIterator`]: functions can accept
+/// `impl IntoTryNext<Item = T, Error = E>` and callers can pass an existing
+/// source, a collection, or a channel receiver uniformly.
+///
+/// Every [`TryNext`] converts into itself. Collections convert into
+/// infallible sources (with [`Infallible`](std::convert::Infallible) as the
+/// error type); iterators of `Result`s can be adapted with
+/// [`from_iter`](crate::from_iter).
+///
+/// ```rust
+/// use try_next::{IntoTryNext, TryNext};
+///
+/// fn total<S>(src: S) -> Result<u32, S::Error>
+/// where
+///     S: IntoTryNext<Item = u32>,
+/// {
+///     let mut src = src.into_try_next();
+///     let mut sum = 0;
+///     while let Some(v) = src.try_next()? {
+///         sum += v;
+///     }
+///     Ok(sum)
+/// }
+///
+/// assert_eq!(total(vec![1, 2, 3]), Ok(6));
+/// assert_eq!(total(try_next::from_iter([Ok(4), Err("bad")])), Err("bad"));
+/// ```
+pub trait IntoTryNext {
+    /// The type of items yielded by the resulting source.
+    type Item;
+
+    /// The error type of the resulting source.
+    type Error;
+
+    /// The source this value converts into.
+    type IntoTryNext: TryNext<Item = Self::Item, Error = Self::Error>;
+
+    /// Converts this value into a source.
+    fn into_try_next(self) -> Self::IntoTryNext;
+}
+
+impl<S: TryNext> IntoTryNext for S {
+    type Item = S::Item;
+    type Error = S::Error;
+    type IntoTryNext = S;
+
+    fn into_try_next(self) -> Self::IntoTryNext {
+        self
+    }
+}
+
+impl<T> IntoTryNext for Vec<T> {
+    type Item = T;
+    type Error = std::convert::Infallible;
+    type IntoTryNext = FromItems<vec::IntoIter<T>>;
+
+    fn into_try_next(self) -> Self::IntoTryNext {
+        from_items(self)
+    }
+}
+
+impl<T> IntoTryNext for VecDeque<T> {
+    type Item = T;
+    type Error = std::convert::Infallible;
+    type IntoTryNext = FromItems<vec_deque::IntoIter<T>>;
+
+    fn into_try_next(self) -> Self::IntoTryNext {
+        from_items(self)
+    }
+}
+
+impl<T, const N: usize> IntoTryNext for [T; N] {
+    type Item = T;
+    type Error = std::convert::Infallible;
+    type IntoTryNext = FromItems<std::array::IntoIter<T, N>>;
+
+    fn into_try_next(self) -> Self::IntoTryNext {
+        from_items(self)
+    }
+}
+
+impl<T> IntoTryNext for Receiver<T> {
+    type Item = T;
+    type Error = std::convert::Infallible;
+    type IntoTryNext = ChannelSource<T>;
+
+    fn into_try_next(self) -> Self::IntoTryNext {
+        ChannelSource::new(self)
+    }
+}
+
+/// Available with the `tokio` feature.
+#[cfg(feature = "tokio")]
+impl<T> IntoTryNext for tokio::sync::mpsc::Receiver<T> {
+    type Item = T;
+    type Error = std::convert::Infallible;
+    type IntoTryNext = TokioChannelSource<T>;
+
+    fn into_try_next(self) -> Self::IntoTryNext {
+        TokioChannelSource::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntoTryNext;
+    use crate::TryNext;
+    use std::collections::VecDeque;
+    use std::sync::mpsc;
+
+    fn drain<S: IntoTryNext>(src: S) -> Result<Vec<S::Item>, S::Error> {
+        let mut src = src.into_try_next();
+        let mut out = Vec::new();
+        while let Some(item) = src.try_next()? {
+            out.push(item);
+        }
+        Ok(out)
+    }
+
+    #[test]
+    fn converts_std_types() {
+        assert_eq!(drain(vec![1, 2]), Ok(vec![1, 2]));
+        assert_eq!(drain(VecDeque::from([3])), Ok(vec![3]));
+        assert_eq!(drain(['a', 'b']), Ok(vec!['a', 'b']));
+
+        let (tx, rx) = mpsc::channel();
+        tx.send(7).unwrap();
+        drop(tx);
+        assert_eq!(drain(rx), Ok(vec![7]));
+    }
+
+    #[test]
+    fn sources_convert_into_themselves() {
+        let src = crate::from_iter([Ok(1), Err(())]);
+        assert_eq!(drain(src), Err(()));
+    }
+}
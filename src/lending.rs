@@ -0,0 +1,311 @@
+//! Lending (borrowing) fallible producers.
+//!
+//! [`TryNextLending`] is a variant of [`TryNext`] whose items may borrow from
+//! the producer itself. This lets parsers and readers hand out slices into an
+//! internal buffer without copying; the borrow ends before the next call.
+//!
+//! Use [`TryNextLending::cloned`] or [`TryNextLending::owned`] to turn a
+//! lending source into an ordinary [`TryNext`] by copying each item.
+
+use std::num::NonZeroUsize;
+
+use crate::TryNext;
+
+/// Lending, context-free, fallible producer.
+///
+/// Like [`TryNext`], but each item may borrow from the producer for the
+/// duration of the borrow of `self`. As a consequence, at most one item can
+/// be alive at a time.
+///
+/// ```rust
+/// use try_next::TryNextLending;
+///
+/// struct Lines { buf: String, input: std::vec::IntoIter<&'static str> }
+///
+/// impl TryNextLending for Lines {
+///     type Item<'a> = &'a str;
+///     type Error = std::convert::Infallible;
+///
+///     fn try_next(&mut self) -> Result<Option<Self::Item<'_>>, Self::Error> {
+///         let Some(line) = self.input.next() else { return Ok(None) };
+///         self.buf.clear();
+///         self.buf.push_str(line.trim());
+///         Ok(Some(&self.buf))
+///     }
+/// }
+///
+/// let mut src = Lines { buf: String::new(), input: vec![" a ", "b  "].into_iter() };
+/// assert_eq!(src.try_next(), Ok(Some("a")));
+/// assert_eq!(src.try_next(), Ok(Some("b")));
+/// assert_eq!(src.try_next(), Ok(None));
+/// ```
+pub trait TryNextLending {
+    /// The type of items yielded by this source, borrowing from `self`.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// The error type that may be returned when producing the next item fails.
+    type Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item<'_>>, Self::Error>;
+
+    /// Returns the bounds on the number of items remaining in the source.
+    ///
+    /// See [`TryNext::size_hint`]. The default implementation returns
+    /// `(0, None)`.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
+    /// Converts this source into a [`TryNext`] that clones each borrowed item.
+    ///
+    /// Due to a current limitation of higher-ranked bounds over generic
+    /// associated types, the source itself must be `'static`.
+    fn cloned<T>(self) -> Cloned<Self>
+    where
+        Self: Sized + 'static + for<'a> TryNextLending<Item<'a> = &'a T>,
+        T: Clone + 'static,
+    {
+        Cloned { source: self }
+    }
+
+    /// Converts this source into a [`TryNext`] that calls
+    /// [`ToOwned::to_owned`] on each borrowed item.
+    ///
+    /// This is the variant to use for unsized items such as `&str` or `&[u8]`.
+    /// As with [`cloned`](Self::cloned), the source must be `'static`.
+    fn owned<T>(self) -> Owned<Self>
+    where
+        Self: Sized + 'static + for<'a> TryNextLending<Item<'a> = &'a T>,
+        T: ToOwned + ?Sized + 'static,
+    {
+        Owned { source: self }
+    }
+}
+
+/// A [`TryNext`] that clones the items of a [`TryNextLending`] source.
+///
+/// Created by [`TryNextLending::cloned`].
+#[derive(Debug, Clone)]
+pub struct Cloned<S> {
+    source: S,
+}
+
+impl<S> Cloned<S> {
+    /// Returns the underlying lending source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+}
+
+impl<S, T> TryNext for Cloned<S>
+where
+    S: 'static + for<'a> TryNextLending<Item<'a> = &'a T>,
+    T: Clone + 'static,
+{
+    type Item = T;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.source.try_next()?.cloned())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+
+    fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        advance_by(&mut self.source, n)
+    }
+}
+
+/// A [`TryNext`] that converts the items of a [`TryNextLending`] source to
+/// their owned form.
+///
+/// Created by [`TryNextLending::owned`].
+#[derive(Debug, Clone)]
+pub struct Owned<S> {
+    source: S,
+}
+
+impl<S> Owned<S> {
+    /// Returns the underlying lending source.
+    pub fn into_inner(self) -> S {
+        self.source
+    }
+
+    pub(crate) fn get_ref(&self) -> &S {
+        &self.source
+    }
+
+    pub(crate) fn get_mut(&mut self) -> &mut S {
+        &mut self.source
+    }
+}
+
+impl<S, T> TryNext for Owned<S>
+where
+    S: 'static + for<'a> TryNextLending<Item<'a> = &'a T>,
+    T: ToOwned + ?Sized + 'static,
+{
+    type Item = T::Owned;
+    type Error = S::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.source.try_next()?.map(ToOwned::to_owned))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.source.size_hint()
+    }
+
+    fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        advance_by(&mut self.source, n)
+    }
+}
+
+/// Skips `n` items of a lending source without converting the borrowed items.
+fn advance_by<S: TryNextLending>(
+    source: &mut S,
+    n: usize,
+) -> Result<Result<(), NonZeroUsize>, S::Error> {
+    for skipped in 0..n {
+        if source.try_next()?.is_none() {
+            return Ok(Err(NonZeroUsize::new(n - skipped).unwrap()));
+        }
+    }
+    Ok(Ok(()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::TryNextLending;
+    use crate::TryNext;
+
+    /// Splits the input into fixed-size windows of a reused buffer.
+    struct Windows {
+        input: Vec<u8>,
+        pos: usize,
+        buf: Vec<u8>,
+    }
+
+    impl TryNextLending for Windows {
+        type Item<'a> = &'a [u8];
+        type Error = ();
+
+        fn try_next(&mut self) -> Result<Option<Self::Item<'_>>, Self::Error> {
+            if self.pos >= self.input.len() {
+                return Ok(None);
+            }
+            let end = (self.pos + 2).min(self.input.len());
+            self.buf.clear();
+            self.buf.extend_from_slice(&self.input[self.pos..end]);
+            self.pos = end;
+            Ok(Some(&self.buf))
+        }
+    }
+
+    struct Slot {
+        value: u32,
+        limit: u32,
+    }
+
+    impl TryNextLending for Slot {
+        type Item<'a> = &'a u32;
+        type Error = ();
+
+        fn try_next(&mut self) -> Result<Option<Self::Item<'_>>, Self::Error> {
+            if self.value == self.limit {
+                return Ok(None);
+            }
+            self.value += 1;
+            Ok(Some(&self.value))
+        }
+    }
+
+    #[test]
+    fn lends_from_internal_buffer() {
+        let mut src = Windows {
+            input: vec![1, 2, 3],
+            pos: 0,
+            buf: Vec::new(),
+        };
+
+        assert_eq!(TryNextLending::try_next(&mut src), Ok(Some(&[1, 2][..])));
+        assert_eq!(TryNextLending::try_next(&mut src), Ok(Some(&[3][..])));
+        assert_eq!(TryNextLending::try_next(&mut src), Ok(None));
+    }
+
+    #[test]
+    fn owned_and_cloned_produce_owned_items() {
+        let mut owned = Windows {
+            input: vec![1, 2, 3],
+            pos: 0,
+            buf: Vec::new(),
+        }
+        .owned();
+        assert_eq!(owned.try_next(), Ok(Some(vec![1, 2])));
+        assert_eq!(owned.try_next(), Ok(Some(vec![3])));
+        assert_eq!(owned.try_next(), Ok(None));
+
+        let mut cloned = Slot { value: 0, limit: 2 }.cloned();
+        assert_eq!(cloned.try_next(), Ok(Some(1)));
+        assert_eq!(cloned.try_next(), Ok(Some(2)));
+        assert_eq!(cloned.try_next(), Ok(None));
+    }
+
+    static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+    /// Counts its clones in `CLONES`.
+    struct Tally;
+
+    impl Clone for Tally {
+        fn clone(&self) -> Self {
+            CLONES.fetch_add(1, Ordering::Relaxed);
+            Tally
+        }
+    }
+
+    /// Lends the same `Tally` `left` times.
+    struct Tallies {
+        item: Tally,
+        left: usize,
+    }
+
+    impl TryNextLending for Tallies {
+        type Item<'a> = &'a Tally;
+        type Error = ();
+
+        fn try_next(&mut self) -> Result<Option<Self::Item<'_>>, Self::Error> {
+            if self.left == 0 {
+                return Ok(None);
+            }
+            self.left -= 1;
+            Ok(Some(&self.item))
+        }
+    }
+
+    #[test]
+    fn advancing_skips_the_conversion() {
+        let mut cloned = Tallies {
+            item: Tally,
+            left: 3,
+        }
+        .cloned();
+        assert_eq!(cloned.try_advance_by(2), Ok(Ok(())));
+        let mut owned = Tallies {
+            item: Tally,
+            left: 3,
+        }
+        .owned();
+        assert_eq!(owned.try_advance_by(2), Ok(Ok(())));
+        assert_eq!(CLONES.load(Ordering::Relaxed), 0);
+
+        assert!(matches!(cloned.try_next(), Ok(Some(Tally))));
+        assert!(matches!(owned.try_next(), Ok(Some(Tally))));
+        assert_eq!(CLONES.load(Ordering::Relaxed), 2);
+    }
+}
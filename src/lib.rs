@@ -114,9 +114,10 @@
 //!
 //! ## Design notes
 //!
-//! - Both traits are deliberately **minimal**: they define no combinators or adapters.
+//! - Both traits are deliberately **minimal**: they define no combinators themselves.
 //!   Their purpose is to provide a simple, low-level interface for fallible, stepwise
-//!   data production.
+//!   data production. Adapters live in the separate [`TryNextExt`] extension trait
+//!   and the [`adapters`] module.
 //! - `TryNextWithContext` can often serve as a building block for adapters that
 //!   integrate external state or resources.
 //! - These traits are a good fit for *incremental* or *stateful* producers such as
@@ -136,6 +137,110 @@
 //! - [`futures::TryStream`](https://docs.rs/futures/latest/futures/stream/trait.TryStream.html) —
 //!   The *asynchronous* equivalent of this pattern.
 
+#![cfg_attr(feature = "nightly", feature(coroutine_trait))]
+#![cfg_attr(
+    all(test, feature = "nightly"),
+    feature(coroutines, stmt_expr_attributes, yield_expr)
+)]
+
+pub mod adapters;
+#[cfg(feature = "test-util")]
+mod assert_yields;
+#[cfg(feature = "tokio")]
+mod async_read;
+mod borrowed_context;
+mod boxed;
+pub mod cancel;
+#[cfg(feature = "serde")]
+mod checkpoint;
+#[cfg(feature = "test-util")]
+mod conformance;
+mod contextualized;
+mod dispatch;
+mod double_ended;
+#[cfg(feature = "either")]
+mod either;
+mod either_error;
+mod exact_size;
+mod ext;
+mod forward;
+mod from_iterator;
+mod fused;
+mod into;
+mod lending;
+mod located;
+pub mod metrics;
+mod peek;
+mod pipeline;
+mod pool;
+mod provide;
+mod pump;
+mod push;
+#[cfg(feature = "mio")]
+mod reactor;
+mod ready;
+pub mod replay;
+pub mod retry;
+mod rows;
+mod seek;
+mod snapshot;
+pub mod sources;
+
+#[cfg(feature = "test-util")]
+#[doc(hidden)]
+pub use assert_yields::check_yields as __check_yields;
+#[cfg(feature = "tokio")]
+pub use async_read::AsyncReader;
+pub use borrowed_context::TryNextWithBorrowedContext;
+#[cfg(feature = "dyn-clone")]
+pub use boxed::{BoxCloneTryNext, BoxCloneTryNextSend, CloneTryNext};
+pub use boxed::{
+    BoxError, BoxTryNext, BoxTryNextSend, BoxTryNextWithContext, BoxTryNextWithContextSend,
+};
+#[cfg(feature = "serde")]
+pub use checkpoint::{Checkpoint, CheckpointStore, FileStore, Resumable, ResumeError};
+#[cfg(feature = "test-util")]
+#[doc(hidden)]
+pub use conformance::{
+    check_chunks as __check_chunks, check_exact_size as __check_exact_size,
+    check_fill as __check_fill, check_fused as __check_fused, check_size_hint as __check_size_hint,
+};
+pub use contextualized::Contextualized;
+pub use double_ended::DoubleEndedTryNext;
+pub use either_error::EitherError;
+pub use exact_size::ExactSizeTryNext;
+pub use ext::{TryNextExt, TryNextWithContextExt};
+#[doc(hidden)]
+pub use from_iterator::IterResult as __IterResult;
+pub use fused::FusedTryNext;
+pub use into::IntoTryNext;
+pub use lending::{Cloned, Owned, TryNextLending};
+pub use located::{ByteOffset, Located};
+pub use peek::TryPeek;
+pub use pipeline::PipelineBuilder;
+#[doc(hidden)]
+pub use pipeline::{
+    expect_context as __expect_context, expect_error as __expect_error,
+    expect_item as __expect_item,
+};
+#[cfg(feature = "heapless")]
+pub use pool::{FixedPool, FixedPooled};
+pub use pool::{Pool, Pooled, Recycle};
+pub use provide::{FreshContext, ProvideContext, fresh_context};
+pub use pump::{PumpError, pump, pump_batched};
+pub use push::{TryPush, WriteSink};
+#[cfg(feature = "mio")]
+pub use reactor::Reactor;
+pub use ready::{Readiness, TryNextReady};
+pub use rows::{MapRows, RowSource, map_rows};
+pub use seek::TrySeek;
+pub use snapshot::SnapshotContext;
+#[cfg(feature = "nightly")]
+pub use sources::from_coroutine;
+pub use sources::{from_items, from_iter, generate, merge_unordered};
+
+use std::num::NonZeroUsize;
+
 /// Context-aware, fallible producer.
 ///
 /// A trait for types that can produce items one at a time with the help of
@@ -159,7 +264,10 @@ pub trait TryNextWithContext {
     type Error;
 
     /// The type of context passed to each call to [`try_next`](Self::try_next).
-    type Context;
+    ///
+    /// The context may be unsized, e.g. a trait object (`dyn Services`) or a
+    /// slice (`[u8]`), since it is only ever accessed through a reference.
+    type Context: ?Sized;
 
     fn try_next_with_context(
         &mut self,
@@ -183,12 +291,93 @@ pub trait TryNext {
     type Error;
 
     fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error>;
+
+    /// Returns the bounds on the number of items remaining in the source.
+    ///
+    /// The semantics match [`Iterator::size_hint`]: the first element is a
+    /// lower bound and the second an optional upper bound. Errors are not
+    /// counted. The hint is advisory only and must not be relied upon for
+    /// memory safety; callers may use it to pre-allocate.
+    ///
+    /// The default implementation returns `(0, None)`, which is correct for
+    /// any source.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+
+    /// Attempts to produce up to `max` items at once.
+    ///
+    /// May return fewer than `max` items while the source still has more,
+    /// for example when it hands out only what is ready; only an empty
+    /// vector (for a non-zero `max`) means the source returned `Ok(None)`.
+    /// If an error occurs, it is returned and any items already pulled as
+    /// part of this chunk are discarded.
+    ///
+    /// The default implementation calls [`try_next`](Self::try_next)
+    /// repeatedly. Sources that can produce items in bulk (for example by
+    /// draining everything currently queued) should override it.
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        let mut chunk = Vec::with_capacity(max.min(self.size_hint().0));
+        while chunk.len() < max {
+            match self.try_next()? {
+                Some(item) => chunk.push(item),
+                None => break,
+            }
+        }
+        Ok(chunk)
+    }
+
+    /// Attempts to fill `buf` with items, returning how many were written.
+    ///
+    /// This mirrors [`std::io::Read::read`] for `Copy` items, letting numeric
+    /// and byte pipelines move data in blocks. As with `Read::read`, a short
+    /// count does not mean the source is exhausted; only `Ok(0)` for a
+    /// non-empty `buf` means the source returned `Ok(None)`. If an error
+    /// occurs, it is returned; items written before the error remain in
+    /// `buf` but are not counted.
+    ///
+    /// The default implementation calls [`try_next`](Self::try_next) for each
+    /// slot.
+    fn try_fill(&mut self, buf: &mut [Self::Item]) -> Result<usize, Self::Error>
+    where
+        Self::Item: Copy,
+    {
+        for (filled, slot) in buf.iter_mut().enumerate() {
+            match self.try_next()? {
+                Some(item) => *slot = item,
+                None => return Ok(filled),
+            }
+        }
+        Ok(buf.len())
+    }
+
+    /// Skips the next `n` items.
+    ///
+    /// This mirrors [`Iterator::advance_by`]: `Ok(Ok(()))` means `n` items
+    /// were skipped, and `Ok(Err(k))` means the source ended with `k` of
+    /// them still to skip. If an error occurs, it is returned; the items
+    /// skipped before it stay consumed, but their number is not reported.
+    ///
+    /// The default implementation calls [`try_next`](Self::try_next) `n`
+    /// times and drops the items. Sources that can skip without producing
+    /// items, such as files of fixed-size records that can seek past them,
+    /// should override it.
+    fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        for skipped in 0..n {
+            if self.try_next()?.is_none() {
+                // `skipped < n`, so the remainder is non-zero.
+                return Ok(Err(NonZeroUsize::new(n - skipped).unwrap()));
+            }
+        }
+        Ok(Ok(()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::{TryNext, TryNextWithContext};
     use std::convert::Infallible;
+    use std::num::NonZeroUsize;
 
     /// A simple source that yields 0..limit, then `Ok(None)`.
     struct Counter {
@@ -293,6 +482,59 @@ mod tests {
         assert_eq!(s.try_next(), Err(UnitErr));
     }
 
+    #[test]
+    fn default_size_hint_is_unbounded() {
+        let c = Counter {
+            current: 0,
+            limit: 3,
+        };
+        assert_eq!(c.size_hint(), (0, None));
+    }
+
+    #[test]
+    fn default_chunk_stops_at_end() {
+        let mut c = Counter {
+            current: 0,
+            limit: 5,
+        };
+        assert_eq!(c.try_next_chunk(2).unwrap(), vec![0, 1]);
+        assert_eq!(c.try_next_chunk(4).unwrap(), vec![2, 3, 4]);
+        assert_eq!(c.try_next_chunk(4).unwrap(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn default_fill_reports_short_count_at_end() {
+        let mut c = Counter {
+            current: 0,
+            limit: 5,
+        };
+        let mut buf = [0; 3];
+        assert_eq!(c.try_fill(&mut buf), Ok(3));
+        assert_eq!(buf, [0, 1, 2]);
+        assert_eq!(c.try_fill(&mut buf), Ok(2));
+        assert_eq!(buf[..2], [3, 4]);
+        assert_eq!(c.try_fill(&mut buf), Ok(0));
+    }
+
+    #[test]
+    fn default_advance_reports_the_shortfall() {
+        let mut c = Counter {
+            current: 0,
+            limit: 5,
+        };
+        assert_eq!(c.try_advance_by(0), Ok(Ok(())));
+        assert_eq!(c.try_advance_by(2), Ok(Ok(())));
+        assert_eq!(c.try_next(), Ok(Some(2)));
+        assert_eq!(c.try_advance_by(4), Ok(Err(NonZeroUsize::new(2).unwrap())));
+
+        let mut s = FailableCounter {
+            current: 0,
+            fail_at: 2,
+            failed: false,
+        };
+        assert_eq!(s.try_advance_by(3), Err(UnitErr));
+    }
+
     #[test]
     fn works_through_trait_object() {
         let mut src: Box<dyn TryNext<Item = usize, Error = Infallible>> = Box::new(Counter {
@@ -332,10 +574,14 @@ mod tests {
 
     /// Drain helper for context-aware sources; returns both the items and the
     /// final context so the caller can assert on context changes.
-    fn drain_with_ctx<S: TryNextWithContext>(
+    #[allow(clippy::type_complexity)]
+    fn drain_with_ctx<S>(
         mut src: S,
         mut ctx: S::Context,
-    ) -> Result<(Vec<S::Item>, S::Context), S::Error> {
+    ) -> Result<(Vec<S::Item>, S::Context), S::Error>
+    where
+        S: TryNextWithContext<Context: Sized>,
+    {
         let mut out = Vec::new();
         while let Some(item) = src.try_next_with_context(&mut ctx)? {
             out.push(item);
@@ -375,4 +621,47 @@ mod tests {
         // Two items + final None
         assert_eq!(ctx.calls, 3);
     }
+
+    trait Services {
+        fn lookup(&mut self, key: usize) -> Option<&'static str>;
+    }
+
+    struct Table(Vec<&'static str>);
+
+    impl Services for Table {
+        fn lookup(&mut self, key: usize) -> Option<&'static str> {
+            self.0.get(key).copied()
+        }
+    }
+
+    impl TryNextWithContext for FailableCounter {
+        type Item = &'static str;
+        type Error = UnitErr;
+        type Context = dyn Services;
+
+        fn try_next_with_context(
+            &mut self,
+            ctx: &mut Self::Context,
+        ) -> Result<Option<Self::Item>, Self::Error> {
+            match self.try_next()? {
+                Some(key) => Ok(ctx.lookup(key)),
+                None => Ok(None),
+            }
+        }
+    }
+
+    #[test]
+    fn context_may_be_a_trait_object() {
+        let mut src = FailableCounter {
+            current: 0,
+            fail_at: 2,
+            failed: false,
+        };
+        let mut table = Table(vec!["zero", "one"]);
+        let ctx: &mut dyn Services = &mut table;
+
+        assert_eq!(src.try_next_with_context(ctx), Ok(Some("zero")));
+        assert_eq!(src.try_next_with_context(ctx), Ok(Some("one")));
+        assert_eq!(src.try_next_with_context(ctx), Err(UnitErr));
+    }
 }
@@ -0,0 +1,81 @@
+//! Errors annotated with where in the sequence they occurred.
+
+use std::error::Error;
+use std::fmt;
+
+/// An error together with the position at which the source produced it.
+///
+/// Returned by the [`locate`](crate::TryNextExt::locate) and
+/// [`locate_bytes`](crate::TryNextExt::locate_bytes) adapters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Located<E> {
+    /// The number of items successfully produced before the error, i.e. the
+    /// zero-based index of the item that could not be produced.
+    pub index: u64,
+    /// The number of bytes the source had consumed when it failed, if it
+    /// reports byte offsets through [`ByteOffset`].
+    pub byte_offset: Option<u64>,
+    /// The error returned by the source.
+    pub error: E,
+}
+
+impl<E> Located<E> {
+    /// Returns the wrapped error, discarding the position.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Located<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.byte_offset {
+            Some(offset) => write!(f, "item {} (byte {offset}): {}", self.index, self.error),
+            None => write!(f, "item {}: {}", self.index, self.error),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for Located<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// A source that can report how many bytes of its input it has consumed.
+///
+/// Implemented by byte-oriented sources (readers, decoders) so that
+/// [`locate_bytes`](crate::TryNextExt::locate_bytes) can attach byte offsets
+/// to errors.
+pub trait ByteOffset {
+    /// Returns the number of input bytes consumed so far.
+    fn byte_offset(&self) -> u64;
+}
+
+impl<S: ByteOffset + ?Sized> ByteOffset for &mut S {
+    fn byte_offset(&self) -> u64 {
+        (**self).byte_offset()
+    }
+}
+
+impl<S: ByteOffset + ?Sized> ByteOffset for Box<S> {
+    fn byte_offset(&self) -> u64 {
+        (**self).byte_offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Located;
+
+    #[test]
+    fn display_includes_position() {
+        let mut err = Located {
+            index: 1_382_441,
+            byte_offset: None,
+            error: "malformed",
+        };
+        assert_eq!(err.to_string(), "item 1382441: malformed");
+        err.byte_offset = Some(90);
+        assert_eq!(err.to_string(), "item 1382441 (byte 90): malformed");
+    }
+}
@@ -0,0 +1,112 @@
+//! Reporting pipeline metrics to a pluggable recorder.
+//!
+//! The [`Metered`](crate::adapters::Metered) adapter reports what happens in
+//! a source through a [`MetricsRecorder`], using the metric names defined
+//! here and a `stage` label naming the pipeline step. Implement the trait to
+//! feed any metrics system.
+//!
+#![cfg_attr(
+    feature = "metrics",
+    doc = "With the `metrics` feature, [`GlobalMetrics`]"
+)]
+#![cfg_attr(
+    not(feature = "metrics"),
+    doc = "With the `metrics` feature, `GlobalMetrics`"
+)]
+//! forwards to the recorder installed for the
+//! [`metrics`](https://docs.rs/metrics) crate, e.g. a Prometheus exporter.
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Counter of items yielded.
+pub const ITEMS: &str = "try_next_items_total";
+/// Counter of errors returned.
+pub const ERRORS: &str = "try_next_errors_total";
+/// Counter of `Ok(None)` results returned.
+pub const ENDS: &str = "try_next_ends_total";
+/// Histogram of call latencies, in seconds.
+pub const LATENCY: &str = "try_next_pull_seconds";
+/// Gauge of items yielded per second over the last rate interval.
+pub const THROUGHPUT: &str = "try_next_items_per_second";
+
+/// A sink for counter, gauge, and histogram updates.
+///
+/// Methods take `&self` so one recorder can serve many sources; use
+/// interior mutability to store the values.
+pub trait MetricsRecorder {
+    /// Adds `value` to the counter `name` for `stage`.
+    fn increment_counter(&self, name: &'static str, stage: &'static str, value: u64);
+
+    /// Sets the gauge `name` for `stage` to `value`.
+    fn set_gauge(&self, name: &'static str, stage: &'static str, value: f64);
+
+    /// Records one observation of `value` in the histogram `name` for
+    /// `stage`.
+    fn record_histogram(&self, name: &'static str, stage: &'static str, value: f64);
+}
+
+impl<R: MetricsRecorder + ?Sized> MetricsRecorder for &R {
+    fn increment_counter(&self, name: &'static str, stage: &'static str, value: u64) {
+        (**self).increment_counter(name, stage, value);
+    }
+
+    fn set_gauge(&self, name: &'static str, stage: &'static str, value: f64) {
+        (**self).set_gauge(name, stage, value);
+    }
+
+    fn record_histogram(&self, name: &'static str, stage: &'static str, value: f64) {
+        (**self).record_histogram(name, stage, value);
+    }
+}
+
+impl<R: MetricsRecorder + ?Sized> MetricsRecorder for Rc<R> {
+    fn increment_counter(&self, name: &'static str, stage: &'static str, value: u64) {
+        (**self).increment_counter(name, stage, value);
+    }
+
+    fn set_gauge(&self, name: &'static str, stage: &'static str, value: f64) {
+        (**self).set_gauge(name, stage, value);
+    }
+
+    fn record_histogram(&self, name: &'static str, stage: &'static str, value: f64) {
+        (**self).record_histogram(name, stage, value);
+    }
+}
+
+impl<R: MetricsRecorder + ?Sized> MetricsRecorder for Arc<R> {
+    fn increment_counter(&self, name: &'static str, stage: &'static str, value: u64) {
+        (**self).increment_counter(name, stage, value);
+    }
+
+    fn set_gauge(&self, name: &'static str, stage: &'static str, value: f64) {
+        (**self).set_gauge(name, stage, value);
+    }
+
+    fn record_histogram(&self, name: &'static str, stage: &'static str, value: f64) {
+        (**self).record_histogram(name, stage, value);
+    }
+}
+
+/// A recorder forwarding to the global [`metrics`](https://docs.rs/metrics)
+/// recorder, with the stage as a `stage` label.
+///
+/// Available with the `metrics` feature.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlobalMetrics;
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for GlobalMetrics {
+    fn increment_counter(&self, name: &'static str, stage: &'static str, value: u64) {
+        ::metrics::counter!(name, "stage" => stage).increment(value);
+    }
+
+    fn set_gauge(&self, name: &'static str, stage: &'static str, value: f64) {
+        ::metrics::gauge!(name, "stage" => stage).set(value);
+    }
+
+    fn record_histogram(&self, name: &'static str, stage: &'static str, value: f64) {
+        ::metrics::histogram!(name, "stage" => stage).record(value);
+    }
+}
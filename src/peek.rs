@@ -0,0 +1,35 @@
+//! Sources that can look at the next item without consuming it.
+
+use crate::TryNext;
+
+/// A [`TryNext`] that can look at its next item without consuming it.
+///
+/// Generic code that needs one item of lookahead (parsers, lexers) can bound
+/// on this trait instead of depending on a concrete adapter type. Any source
+/// can gain this capability through
+/// [`TryNextExt::peekable`](crate::TryNextExt::peekable).
+///
+/// ```rust
+/// use try_next::{TryNext, TryPeek};
+///
+/// /// Consumes leading zeros, leaving the first non-zero item in place.
+/// fn skip_zeros<S>(src: &mut S) -> Result<usize, S::Error>
+/// where
+///     S: TryPeek<Item = u8>,
+/// {
+///     let mut n = 0;
+///     while let Some(0) = src.try_peek()? {
+///         src.try_next()?;
+///         n += 1;
+///     }
+///     Ok(n)
+/// }
+/// ```
+pub trait TryPeek: TryNext {
+    /// Returns a reference to the next item without consuming it.
+    ///
+    /// A subsequent call to [`try_next`](TryNext::try_next) returns the same
+    /// item. If producing the item fails, the error is returned and nothing
+    /// is buffered.
+    fn try_peek(&mut self) -> Result<Option<&Self::Item>, Self::Error>;
+}
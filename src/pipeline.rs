@@ -0,0 +1,253 @@
+use crate::adapters::Prefetch;
+use crate::{PumpError, TryNext, TryNextExt, TryNextWithContext, TryPush};
+
+/// Builds a pipeline from a source and a sequence of adapter stages.
+///
+/// Each stage after `=>` is either a method call, applied to the pipeline
+/// built so far, or a type checkpoint:
+///
+/// - `name(args)` or `name::<T>(args)` calls an adapter method, resolved as
+///   usual, so bring [`TryNextExt`](crate::TryNextExt) or
+///   [`TryNextWithContextExt`](crate::TryNextWithContextExt) into scope;
+/// - `Item = T` and `Error = E` assert the item and error types of a
+///   [`TryNext`] at that point;
+/// - `Context = C` asserts the context type of a [`TryNextWithContext`].
+///
+/// Checkpoints cost nothing at runtime. When a stage produces unexpected
+/// types, the compiler reports the mismatch at the checkpoint right after
+/// it, instead of at the end of a long method chain.
+///
+/// ```rust
+/// use try_next::{TryNext, TryNextExt, from_items, pipeline};
+///
+/// let mut src = pipeline! {
+///     from_items(1..=6)
+///     => filter(|n| n % 2 == 0)
+///     => Item = u32
+///     => map(|n| n * 10)
+///     => chunks(2)
+///     => Item = Vec<u32>
+/// };
+/// assert_eq!(src.try_next(), Ok(Some(vec![20, 40])));
+/// assert_eq!(src.try_next(), Ok(Some(vec![60])));
+/// ```
+///
+/// A stage that changes the type unexpectedly fails at its checkpoint:
+///
+/// ```rust,compile_fail
+/// use try_next::{TryNextExt, from_items, pipeline};
+///
+/// let src = pipeline! {
+///     from_items(1..=6u32)
+///     => map(|n| n.to_string())
+///     => Item = u32
+/// };
+/// ```
+#[macro_export]
+macro_rules! pipeline {
+    ($source:expr $(=> $($stages:tt)+)?) => {
+        $crate::pipeline!(@stages ($source) $($($stages)+)?)
+    };
+    (@stages ($acc:expr)) => {
+        $acc
+    };
+    (@stages ($acc:expr) Item = $ty:ty $(=> $($rest:tt)+)?) => {
+        $crate::pipeline!(@stages ($crate::__expect_item::<$ty, _>($acc)) $($($rest)+)?)
+    };
+    (@stages ($acc:expr) Error = $ty:ty $(=> $($rest:tt)+)?) => {
+        $crate::pipeline!(@stages ($crate::__expect_error::<$ty, _>($acc)) $($($rest)+)?)
+    };
+    (@stages ($acc:expr) Context = $ty:ty $(=> $($rest:tt)+)?) => {
+        $crate::pipeline!(@stages ($crate::__expect_context::<$ty, _>($acc)) $($($rest)+)?)
+    };
+    (
+        @stages ($acc:expr)
+        $method:ident $(::<$($generic:tt),+>)? ($($args:tt)*)
+        $(=> $($rest:tt)+)?
+    ) => {
+        $crate::pipeline!(
+            @stages ($acc.$method $(::<$($generic),+>)? ($($args)*))
+            $($($rest)+)?
+        )
+    };
+}
+
+/// Builds a multi-stage pipeline, optionally running stages on their own
+/// threads.
+///
+/// A pipeline starts from a source and grows one stage at a time. A stage is
+/// any function from the pipeline built so far to a new source, usually a
+/// chain of adapter calls. [`stage`](Self::stage) runs it on whichever thread
+/// pulls from the stage after it; [`threaded_stage`](Self::threaded_stage)
+/// moves it, together with any unthreaded stages before it, onto a new
+/// thread, with a bounded queue to the next stage. The result is a plain
+/// [`TryNext`] from [`build`](Self::build), or can be drained into a
+/// [`TryPush`] sink with [`run_into`](Self::run_into).
+///
+/// Threaded stages behave like [`prefetch`](crate::TryNextExt::prefetch):
+/// errors keep their place in the sequence, and a panic is resumed on the
+/// consuming thread.
+///
+/// ```rust
+/// use try_next::{PipelineBuilder, TryNextExt, from_items};
+///
+/// let mut lengths = Vec::new();
+/// let forwarded = PipelineBuilder::new(from_items(["a", "bb", "ccc"]))
+///     .threaded_stage(16, |lines| lines.map(str::to_uppercase))
+///     .threaded_stage(16, |words| words.map(|word| word.len()))
+///     .stage(|lens| lens.filter(|&len| len > 1))
+///     .run_into(&mut lengths);
+/// assert_eq!(forwarded, Ok(2));
+/// assert_eq!(lengths, [2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PipelineBuilder<S> {
+    source: S,
+}
+
+impl<S: TryNext> PipelineBuilder<S> {
+    /// Starts a pipeline reading from `source`.
+    pub fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// Adds a stage that runs on the thread pulling from it.
+    pub fn stage<T, F>(self, f: F) -> PipelineBuilder<T>
+    where
+        T: TryNext,
+        F: FnOnce(S) -> T,
+    {
+        PipelineBuilder::new(f(self.source))
+    }
+
+    /// Adds a stage that runs on its own thread, handing up to `capacity`
+    /// results to the next stage through a bounded queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn threaded_stage<T, F>(
+        self,
+        capacity: usize,
+        f: F,
+    ) -> PipelineBuilder<Prefetch<T::Item, T::Error>>
+    where
+        T: TryNext + Send + 'static,
+        T::Item: Send + 'static,
+        T::Error: Send + 'static,
+        F: FnOnce(S) -> T,
+    {
+        PipelineBuilder::new(f(self.source).prefetch(capacity))
+    }
+
+    /// Finishes the pipeline, returning its last stage as a source.
+    pub fn build(self) -> S {
+        self.source
+    }
+
+    /// Drains the pipeline into `sink`, returning the number of items
+    /// forwarded.
+    ///
+    /// See [`pump`](crate::pump) for how errors and the end of input are
+    /// handled.
+    pub fn run_into<K>(mut self, sink: &mut K) -> Result<usize, PumpError<S::Error, K::Error>>
+    where
+        K: TryPush<Item = S::Item> + ?Sized,
+    {
+        crate::pump(&mut self.source, sink)
+    }
+}
+
+#[doc(hidden)]
+pub fn expect_item<T, S: TryNext<Item = T>>(source: S) -> S {
+    source
+}
+
+#[doc(hidden)]
+pub fn expect_error<E, S: TryNext<Error = E>>(source: S) -> S {
+    source
+}
+
+#[doc(hidden)]
+pub fn expect_context<C: ?Sized, S: TryNextWithContext<Context = C>>(source: S) -> S {
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::{self, ThreadId};
+
+    use super::PipelineBuilder;
+    use crate::{
+        PumpError, TryNext, TryNextExt, TryNextWithContext, TryNextWithContextExt, from_items,
+        from_iter,
+    };
+
+    struct Steps;
+
+    impl TryNextWithContext for Steps {
+        type Item = u32;
+        type Error = ();
+        type Context = u32;
+
+        fn try_next_with_context(&mut self, next: &mut u32) -> Result<Option<u32>, ()> {
+            *next += 1;
+            Ok(Some(*next))
+        }
+    }
+
+    #[test]
+    fn applies_stages_in_order() {
+        let mut src = pipeline! {
+            from_iter([Ok(1u8), Err("bad"), Ok(3)])
+            => Error = &str
+            => skip_errors(|_| Ok(()))
+            => array_peekable::<2>()
+            => map(u32::from)
+            => Item = u32
+        };
+        assert_eq!(src.try_next_chunk(4), Ok(vec![1, 3]));
+
+        let mut counted = pipeline! {
+            Steps
+            => Context = u32
+            => take(2)
+            => with_context(10)
+            => Item = u32
+        };
+        assert_eq!(counted.try_next_chunk(4), Ok(vec![11, 12]));
+    }
+
+    #[test]
+    fn threaded_stages_run_on_their_own_threads() {
+        fn tag(_: u32) -> ThreadId {
+            thread::current().id()
+        }
+
+        let mut src = PipelineBuilder::new(from_items(0..3u32))
+            .threaded_stage(1, |src| src.map(|n| (n, tag(n))))
+            .threaded_stage(1, |src| src.map(|(n, first)| (n, first, tag(n))))
+            .stage(|src| src.map(|(n, first, second)| (n, first, second, tag(n))))
+            .build();
+
+        let mut ids = Vec::new();
+        while let Some((n, first, second, last)) = src.try_next().unwrap() {
+            ids.push(n);
+            assert_eq!(last, thread::current().id());
+            assert_ne!(first, second);
+            assert_ne!(second, last);
+            assert_ne!(first, last);
+        }
+        assert_eq!(ids, [0, 1, 2]);
+    }
+
+    #[test]
+    fn run_into_stops_at_the_first_error() {
+        let mut out = Vec::new();
+        let result = PipelineBuilder::new(from_iter([Ok(1), Err("bad"), Ok(3)]))
+            .threaded_stage(4, |src| src.map(|n| n * 2))
+            .run_into(&mut out);
+        assert_eq!(result, Err(PumpError::Source("bad")));
+        assert_eq!(out, [2]);
+    }
+}
@@ -0,0 +1,365 @@
+//! Recycled item buffers.
+//!
+//! A [`Pool`] keeps values (typically `Vec<u8>` or `String` buffers) that
+//! have been used and handed back, so a pipeline producing one buffer per
+//! record can reuse allocations instead of making new ones. The pool is meant
+//! to live in a [`TryNextWithContext`](crate::TryNextWithContext) context and
+//! is used by [`map_pooled`](crate::TryNextWithContextExt::map_pooled).
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// A value that can be reset for reuse while keeping its allocation.
+pub trait Recycle {
+    /// Clears the value's contents.
+    fn recycle(&mut self);
+}
+
+impl<T> Recycle for Vec<T> {
+    fn recycle(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Recycle for VecDeque<T> {
+    fn recycle(&mut self) {
+        self.clear();
+    }
+}
+
+impl Recycle for String {
+    fn recycle(&mut self) {
+        self.clear();
+    }
+}
+
+/// A shared pool of reusable values.
+///
+/// Cloning a `Pool` produces another handle to the same pool. Values are
+/// taken with [`take`](Self::take) and return automatically, recycled, when
+/// the [`Pooled`] guard is dropped. At most `max_idle` values are kept; extra
+/// returns are dropped.
+///
+/// ```rust
+/// use try_next::Pool;
+///
+/// let pool = Pool::<Vec<u8>>::new(8);
+/// let mut buf = pool.take();
+/// buf.extend_from_slice(b"record");
+/// let capacity = buf.capacity();
+/// drop(buf);
+///
+/// let buf = pool.take();
+/// assert!(buf.is_empty());
+/// assert_eq!(buf.capacity(), capacity);
+/// ```
+pub struct Pool<T> {
+    idle: Arc<Mutex<Vec<T>>>,
+    max_idle: usize,
+}
+
+impl<T> Pool<T> {
+    /// Creates an empty pool keeping at most `max_idle` returned values.
+    pub fn new(max_idle: usize) -> Self {
+        Self {
+            idle: Arc::new(Mutex::new(Vec::new())),
+            max_idle,
+        }
+    }
+
+    /// Returns the number of values currently waiting for reuse.
+    pub fn idle(&self) -> usize {
+        self.idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Takes a value from the pool, creating a default one if it is empty.
+    pub fn take(&self) -> Pooled<T>
+    where
+        T: Default + Recycle,
+    {
+        let value = self
+            .idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop()
+            .unwrap_or_default();
+        Pooled {
+            value: Some(value),
+            pool: self.clone(),
+        }
+    }
+}
+
+impl<T> Clone for Pool<T> {
+    fn clone(&self) -> Self {
+        Self {
+            idle: Arc::clone(&self.idle),
+            max_idle: self.max_idle,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Pool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool")
+            .field("idle", &self.idle())
+            .field("max_idle", &self.max_idle)
+            .finish()
+    }
+}
+
+impl<T> AsRef<Pool<T>> for Pool<T> {
+    fn as_ref(&self) -> &Pool<T> {
+        self
+    }
+}
+
+/// A value borrowed from a [`Pool`], returned to it when dropped.
+pub struct Pooled<T: Recycle> {
+    value: Option<T>,
+    pool: Pool<T>,
+}
+
+impl<T: Recycle> Pooled<T> {
+    /// Takes the value out of the pool for good.
+    pub fn detach(mut this: Self) -> T {
+        this.value
+            .take()
+            .expect("pooled value is present until drop")
+    }
+}
+
+impl<T: Recycle> Deref for Pooled<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+            .as_ref()
+            .expect("pooled value is present until drop")
+    }
+}
+
+impl<T: Recycle> DerefMut for Pooled<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+            .as_mut()
+            .expect("pooled value is present until drop")
+    }
+}
+
+impl<T: Recycle + fmt::Debug> fmt::Debug for Pooled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Recycle + PartialEq> PartialEq for Pooled<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Recycle> Drop for Pooled<T> {
+    fn drop(&mut self) {
+        let Some(mut value) = self.value.take() else {
+            return;
+        };
+        let mut idle = self
+            .pool
+            .idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if idle.len() < self.pool.max_idle {
+            value.recycle();
+            idle.push(value);
+        }
+    }
+}
+
+/// A pool of up to `N` reusable values, stored inline.
+///
+/// The allocation-free counterpart of [`Pool`]: idle values live in a
+/// [`heapless::Vec`] inside the pool, and [`FixedPooled`] guards borrow the
+/// pool instead of sharing it. Because [`new`](Self::new) is `const`, the
+/// pool can be a `static`.
+///
+/// Available with the `heapless` feature.
+///
+/// ```rust
+/// use try_next::FixedPool;
+///
+/// static BUFFERS: FixedPool<Vec<u8>, 4> = FixedPool::new();
+///
+/// let mut buf = BUFFERS.take();
+/// buf.push(1);
+/// drop(buf);
+/// assert_eq!(BUFFERS.idle(), 1);
+/// assert!(BUFFERS.take().is_empty());
+/// ```
+#[cfg(feature = "heapless")]
+pub struct FixedPool<T, const N: usize> {
+    idle: Mutex<heapless::Vec<T, N>>,
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> FixedPool<T, N> {
+    /// Creates an empty pool.
+    pub const fn new() -> Self {
+        Self {
+            idle: Mutex::new(heapless::Vec::new()),
+        }
+    }
+
+    /// Returns the number of values currently waiting for reuse.
+    pub fn idle(&self) -> usize {
+        self.idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Takes a value from the pool, creating a default one if it is empty.
+    pub fn take(&self) -> FixedPooled<'_, T, N>
+    where
+        T: Default + Recycle,
+    {
+        let value = self
+            .idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop()
+            .unwrap_or_default();
+        FixedPooled {
+            value: Some(value),
+            pool: self,
+        }
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> Default for FixedPool<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> fmt::Debug for FixedPool<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedPool")
+            .field("idle", &self.idle())
+            .field("capacity", &N)
+            .finish()
+    }
+}
+
+/// A value borrowed from a [`FixedPool`], returned to it when dropped.
+#[cfg(feature = "heapless")]
+pub struct FixedPooled<'a, T: Recycle, const N: usize> {
+    value: Option<T>,
+    pool: &'a FixedPool<T, N>,
+}
+
+#[cfg(feature = "heapless")]
+impl<T: Recycle, const N: usize> FixedPooled<'_, T, N> {
+    /// Takes the value out of the pool for good.
+    pub fn detach(mut this: Self) -> T {
+        this.value
+            .take()
+            .expect("pooled value is present until drop")
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T: Recycle, const N: usize> Deref for FixedPooled<'_, T, N> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value
+            .as_ref()
+            .expect("pooled value is present until drop")
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T: Recycle, const N: usize> DerefMut for FixedPooled<'_, T, N> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+            .as_mut()
+            .expect("pooled value is present until drop")
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T: Recycle + fmt::Debug, const N: usize> fmt::Debug for FixedPooled<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<T: Recycle, const N: usize> Drop for FixedPooled<'_, T, N> {
+    fn drop(&mut self) {
+        let Some(mut value) = self.value.take() else {
+            return;
+        };
+        let mut idle = self
+            .pool
+            .idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if !idle.is_full() {
+            value.recycle();
+            let _ = idle.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Pool, Pooled};
+
+    #[test]
+    fn keeps_at_most_max_idle() {
+        let pool = Pool::<String>::new(1);
+        let a = pool.take();
+        let b = pool.take();
+        drop(a);
+        drop(b);
+        assert_eq!(pool.idle(), 1);
+    }
+
+    #[test]
+    fn detached_values_do_not_return() {
+        let pool = Pool::<Vec<u8>>::new(4);
+        let mut buf = pool.take();
+        buf.push(1);
+        assert_eq!(Pooled::detach(buf), [1]);
+        assert_eq!(pool.idle(), 0);
+    }
+}
+
+#[cfg(all(test, feature = "heapless"))]
+mod fixed_tests {
+    use super::{FixedPool, FixedPooled};
+
+    #[test]
+    fn keeps_at_most_capacity_and_detaches() {
+        let pool = FixedPool::<String, 1>::new();
+        let a = pool.take();
+        let b = pool.take();
+        drop(a);
+        drop(b);
+        assert_eq!(pool.idle(), 1);
+
+        let mut s = pool.take();
+        s.push('x');
+        assert_eq!(FixedPooled::detach(s), "x");
+        assert_eq!(pool.idle(), 0);
+    }
+}
@@ -0,0 +1,117 @@
+//! Providers that hand out contexts to context-aware sources.
+
+/// A supplier of the context a [`TryNextWithContext`](crate::TryNextWithContext)
+/// source runs against.
+///
+/// Frameworks that decide where a context lives (per request, per batch, per
+/// connection) implement this trait and hand the provider to
+/// [`with_provider`](crate::TryNextWithContextExt::with_provider), so call
+/// sites never construct or pass the context themselves.
+///
+/// ```rust
+/// use try_next::ProvideContext;
+///
+/// struct Session { requests: u32 }
+///
+/// struct Server { session: Session }
+///
+/// impl ProvideContext<Session> for Server {
+///     fn provide(&mut self) -> &mut Session {
+///         &mut self.session
+///     }
+/// }
+///
+/// let mut server = Server { session: Session { requests: 0 } };
+/// server.provide().requests += 1;
+/// assert_eq!(server.session.requests, 1);
+/// ```
+pub trait ProvideContext<C: ?Sized> {
+    /// Returns the context to use for the next call.
+    fn provide(&mut self) -> &mut C;
+}
+
+impl<C: ?Sized> ProvideContext<C> for &mut C {
+    fn provide(&mut self) -> &mut C {
+        self
+    }
+}
+
+impl<C: ?Sized> ProvideContext<C> for Box<C> {
+    fn provide(&mut self) -> &mut C {
+        self
+    }
+}
+
+/// A [`ProvideContext`] that builds a new context on every call.
+///
+/// Created by [`fresh_context`].
+#[derive(Debug, Clone)]
+pub struct FreshContext<C, F> {
+    make: F,
+    current: Option<C>,
+}
+
+impl<C, F> FreshContext<C, F> {
+    /// Returns the context built by the most recent call, if any.
+    pub fn last(&self) -> Option<&C> {
+        self.current.as_ref()
+    }
+}
+
+impl<C, F> ProvideContext<C> for FreshContext<C, F>
+where
+    F: FnMut() -> C,
+{
+    fn provide(&mut self) -> &mut C {
+        self.current.insert((self.make)())
+    }
+}
+
+/// Creates a provider that calls `make` for a new context on every call.
+///
+/// ```rust
+/// use try_next::{ProvideContext, fresh_context};
+///
+/// let mut provider = fresh_context(Vec::<u8>::new);
+/// provider.provide().push(1);
+/// assert!(provider.provide().is_empty());
+/// ```
+pub fn fresh_context<C, F>(make: F) -> FreshContext<C, F>
+where
+    F: FnMut() -> C,
+{
+    FreshContext {
+        make,
+        current: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProvideContext, fresh_context};
+
+    #[test]
+    fn borrowed_and_boxed_contexts_are_providers() {
+        let mut count = 0;
+        {
+            let mut provider = &mut count;
+            *provider.provide() += 1;
+        }
+        let mut boxed = Box::new(count);
+        *boxed.provide() += 1;
+        assert_eq!(*boxed, 2);
+    }
+
+    #[test]
+    fn fresh_context_keeps_the_last_one() {
+        let mut calls = 0;
+        let mut provider = fresh_context(|| {
+            calls += 1;
+            calls
+        });
+        assert_eq!(provider.last(), None);
+        assert_eq!(*provider.provide(), 1);
+        assert_eq!(*provider.provide(), 2);
+        assert_eq!(provider.last(), Some(&2));
+    }
+}
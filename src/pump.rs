@@ -0,0 +1,228 @@
+//! Driving a [`TryNext`] source into a [`TryPush`] sink.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::{TryNext, TryPush};
+
+/// The error returned by [`pump`] and [`pump_batched`].
+///
+/// Distinguishes whether the source or the sink failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PumpError<S, K> {
+    /// The source failed to produce the next item.
+    Source(S),
+    /// The sink failed to accept an item or to finish.
+    Sink(K),
+}
+
+impl<S: fmt::Display, K: fmt::Display> fmt::Display for PumpError<S, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Source(e) => write!(f, "source error: {e}"),
+            Self::Sink(e) => write!(f, "sink error: {e}"),
+        }
+    }
+}
+
+impl<S, K> Error for PumpError<S, K>
+where
+    S: Error + 'static,
+    K: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Source(e) => Some(e),
+            Self::Sink(e) => Some(e),
+        }
+    }
+}
+
+/// Drains `source` into `sink`, returning the number of items forwarded.
+///
+/// Items are pushed one at a time until the source returns `Ok(None)`, after
+/// which [`TryPush::try_finish`] is called. The first error from either side
+/// stops the transfer; `try_finish` is not called in that case.
+///
+/// ```rust
+/// use std::convert::Infallible;
+/// use try_next::{TryNext, pump};
+///
+/// struct Countdown(u8);
+///
+/// impl TryNext for Countdown {
+///     type Item = u8;
+///     type Error = Infallible;
+///
+///     fn try_next(&mut self) -> Result<Option<u8>, Infallible> {
+///         Ok(self.0.checked_sub(1).inspect(|&n| self.0 = n))
+///     }
+/// }
+///
+/// let mut out = Vec::new();
+/// assert_eq!(pump(&mut Countdown(3), &mut out), Ok(3));
+/// assert_eq!(out, [2, 1, 0]);
+/// ```
+pub fn pump<S, K>(source: &mut S, sink: &mut K) -> Result<usize, PumpError<S::Error, K::Error>>
+where
+    S: TryNext + ?Sized,
+    K: TryPush<Item = S::Item> + ?Sized,
+{
+    let mut count = 0;
+    while let Some(item) = source.try_next().map_err(PumpError::Source)? {
+        sink.try_push(item).map_err(PumpError::Sink)?;
+        count += 1;
+    }
+    sink.try_finish().map_err(PumpError::Sink)?;
+    Ok(count)
+}
+
+/// Drains `source` into a sink of batches, returning the number of items
+/// forwarded.
+///
+/// Items are grouped into `Vec`s of up to `batch_size` items; the final batch
+/// may be shorter. Empty batches are never pushed. Like [`pump`], the first
+/// error stops the transfer and items already collected into an unfinished
+/// batch are dropped.
+///
+/// # Panics
+///
+/// Panics if `batch_size` is zero.
+pub fn pump_batched<S, K>(
+    source: &mut S,
+    sink: &mut K,
+    batch_size: usize,
+) -> Result<usize, PumpError<S::Error, K::Error>>
+where
+    S: TryNext + ?Sized,
+    K: TryPush<Item = Vec<S::Item>> + ?Sized,
+{
+    assert!(batch_size > 0, "batch size must be non-zero");
+    let mut count = 0;
+    let mut batch = Vec::with_capacity(batch_size);
+    while let Some(item) = source.try_next().map_err(PumpError::Source)? {
+        batch.push(item);
+        if batch.len() == batch_size {
+            count += batch.len();
+            let full = std::mem::replace(&mut batch, Vec::with_capacity(batch_size));
+            sink.try_push(full).map_err(PumpError::Sink)?;
+        }
+    }
+    if !batch.is_empty() {
+        count += batch.len();
+        sink.try_push(batch).map_err(PumpError::Sink)?;
+    }
+    sink.try_finish().map_err(PumpError::Sink)?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PumpError, pump, pump_batched};
+    use crate::{TryNext, TryNextExt, TryPush};
+
+    struct Upto {
+        next: u32,
+        end: u32,
+        fail_at: Option<u32>,
+    }
+
+    impl TryNext for Upto {
+        type Item = u32;
+        type Error = &'static str;
+
+        fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            if Some(self.next) == self.fail_at {
+                return Err("source");
+            }
+            if self.next == self.end {
+                return Ok(None);
+            }
+            self.next += 1;
+            Ok(Some(self.next - 1))
+        }
+    }
+
+    /// Accepts up to `cap` items and records whether it was finished.
+    #[derive(Default)]
+    struct Bounded {
+        items: Vec<Vec<u32>>,
+        cap: usize,
+        finished: bool,
+    }
+
+    impl TryPush for Bounded {
+        type Item = Vec<u32>;
+        type Error = &'static str;
+
+        fn try_push(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+            if self.items.len() == self.cap {
+                return Err("sink");
+            }
+            self.items.push(item);
+            Ok(())
+        }
+
+        fn try_finish(&mut self) -> Result<(), Self::Error> {
+            self.finished = true;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn forwards_and_counts() {
+        let mut src = Upto {
+            next: 0,
+            end: 4,
+            fail_at: None,
+        };
+        let mut out = Vec::new();
+
+        assert_eq!(src.try_forward(&mut out), Ok(4));
+        assert_eq!(out, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn attributes_source_errors() {
+        let mut src = Upto {
+            next: 0,
+            end: 4,
+            fail_at: Some(2),
+        };
+        let mut out = Vec::new();
+
+        assert_eq!(pump(&mut src, &mut out), Err(PumpError::Source("source")));
+        assert_eq!(out, [0, 1]);
+    }
+
+    #[test]
+    fn batches_and_attributes_sink_errors() {
+        let mut src = Upto {
+            next: 0,
+            end: 5,
+            fail_at: None,
+        };
+        let mut sink = Bounded {
+            cap: 10,
+            ..Bounded::default()
+        };
+        assert_eq!(pump_batched(&mut src, &mut sink, 2), Ok(5));
+        assert_eq!(sink.items, [vec![0, 1], vec![2, 3], vec![4]]);
+        assert!(sink.finished);
+
+        let mut src = Upto {
+            next: 0,
+            end: 5,
+            fail_at: None,
+        };
+        let mut sink = Bounded {
+            cap: 1,
+            ..Bounded::default()
+        };
+        assert_eq!(
+            pump_batched(&mut src, &mut sink, 2),
+            Err(PumpError::Sink("sink"))
+        );
+        assert!(!sink.finished);
+    }
+}
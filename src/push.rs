@@ -0,0 +1,191 @@
+//! The push-side counterpart of [`TryNext`](crate::TryNext).
+//!
+//! A [`TryPush`] is a fallible *sink*: items are pushed into it one at a time
+//! and each push may fail. Together with [`TryNext`](crate::TryNext) it lets a
+//! whole pipeline — source, transformations, and destination — be described
+//! in one vocabulary.
+
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::io::{self, Write};
+use std::marker::PhantomData;
+use std::sync::mpsc::{SendError, Sender, SyncSender};
+
+/// Fallible, push-based item sink.
+///
+/// Items are delivered with [`try_push`](Self::try_push). When no more items
+/// will be pushed, the caller should invoke [`try_finish`](Self::try_finish)
+/// so the sink can flush buffers or commit its output.
+///
+/// ```rust
+/// use try_next::TryPush;
+///
+/// let mut sink = Vec::new();
+/// sink.try_push(1).unwrap();
+/// sink.try_push(2).unwrap();
+/// sink.try_finish().unwrap();
+/// assert_eq!(sink, [1, 2]);
+/// ```
+pub trait TryPush {
+    /// The type of items accepted by this sink.
+    type Item;
+
+    /// The error type that may be returned when accepting an item fails.
+    type Error;
+
+    /// Attempts to push one item into the sink.
+    fn try_push(&mut self, item: Self::Item) -> Result<(), Self::Error>;
+
+    /// Signals that no more items will be pushed.
+    ///
+    /// The default implementation does nothing.
+    fn try_finish(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<T> TryPush for Vec<T> {
+    type Item = T;
+    type Error = Infallible;
+
+    fn try_push(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        self.push(item);
+        Ok(())
+    }
+}
+
+impl<T> TryPush for VecDeque<T> {
+    type Item = T;
+    type Error = Infallible;
+
+    fn try_push(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        self.push_back(item);
+        Ok(())
+    }
+}
+
+impl<T> TryPush for Sender<T> {
+    type Item = T;
+    type Error = SendError<T>;
+
+    fn try_push(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        self.send(item)
+    }
+}
+
+impl<T> TryPush for SyncSender<T> {
+    type Item = T;
+    type Error = SendError<T>;
+
+    fn try_push(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        self.send(item)
+    }
+}
+
+/// A [`TryPush`] sink writing byte-like items to an [`io::Write`].
+///
+/// Each item is written in full with [`Write::write_all`];
+/// [`try_finish`](TryPush::try_finish) flushes the writer.
+///
+/// ```rust
+/// use try_next::{TryPush, WriteSink};
+///
+/// let mut sink = WriteSink::new(Vec::new());
+/// sink.try_push("hello, ").unwrap();
+/// sink.try_push("world").unwrap();
+/// sink.try_finish().unwrap();
+/// assert_eq!(sink.into_inner(), b"hello, world");
+/// ```
+#[derive(Debug)]
+pub struct WriteSink<W, T> {
+    writer: W,
+    _item: PhantomData<fn(T)>,
+}
+
+impl<W: Write, T: AsRef<[u8]>> WriteSink<W, T> {
+    /// Creates a sink writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            _item: PhantomData,
+        }
+    }
+}
+
+impl<W, T> WriteSink<W, T> {
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write, T: AsRef<[u8]>> TryPush for WriteSink<W, T> {
+    type Item = T;
+    type Error = io::Error;
+
+    fn try_push(&mut self, item: Self::Item) -> Result<(), Self::Error> {
+        self.writer.write_all(item.as_ref())
+    }
+
+    fn try_finish(&mut self) -> Result<(), Self::Error> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TryPush, WriteSink};
+    use std::io::{self, Write};
+    use std::sync::mpsc;
+
+    /// A writer that accepts a fixed number of bytes, then fails.
+    struct Limited {
+        left: usize,
+        flushed: bool,
+    }
+
+    impl Write for Limited {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.left == 0 {
+                return Err(io::ErrorKind::WriteZero.into());
+            }
+            let n = buf.len().min(self.left);
+            self.left -= n;
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            self.flushed = true;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_sink_reports_io_errors_and_flushes() {
+        let mut sink = WriteSink::new(Limited {
+            left: 3,
+            flushed: false,
+        });
+
+        assert!(sink.try_push(vec![1, 2]).is_ok());
+        assert!(sink.try_push(vec![3, 4]).is_err());
+        assert!(sink.try_finish().is_ok());
+        assert!(sink.get_ref().flushed);
+    }
+
+    #[test]
+    fn channel_sender_fails_once_receiver_is_gone() {
+        let (mut tx, rx) = mpsc::channel();
+
+        assert_eq!(tx.try_push(1), Ok(()));
+        assert_eq!(rx.recv(), Ok(1));
+
+        drop(rx);
+        assert_eq!(tx.try_push(2), Err(mpsc::SendError(2)));
+    }
+}
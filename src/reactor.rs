@@ -0,0 +1,231 @@
+//! A minimal readiness-driven reactor over [`mio`].
+
+use std::io;
+use std::time::Duration;
+
+use mio::event::Source;
+use mio::{Events, Interest, Poll, Registry, Token};
+
+use crate::sources::ReadReady;
+use crate::{Readiness, TryNextReady};
+
+/// A [`TryNextReady`] that can also be registered with a [`mio`] registry.
+trait Pollable: TryNextReady + Source {}
+
+impl<S: TryNextReady + Source> Pollable for S {}
+
+/// A single-threaded event loop that pulls from non-blocking sources when
+/// their file descriptors become readable.
+///
+/// Sources are registered with a [`mio::Poll`] for readable events and must
+/// implement both [`TryNextReady`] and [`mio::event::Source`]. A
+/// [`read_ready`](crate::sources::read_ready) source does when its reader
+/// does, as with [`mio::net::TcpStream`]. Each readiness event drains its
+/// source until it reports [`Readiness::Pending`], since mio's events are
+/// edge-triggered, and hands every result to a callback together with the
+/// source's [`Token`]:
+///
+/// - `Ok(Some(item))` for each item,
+/// - `Ok(None)` once the source ends,
+/// - `Err(e)` if a pull fails.
+///
+/// After its end or an error a source is deregistered and dropped.
+///
+/// ```rust,no_run
+/// use std::net::TcpListener;
+/// use mio::net::TcpStream;
+/// use try_next::Reactor;
+/// use try_next::sources::read_ready;
+///
+/// let listener = TcpListener::bind("127.0.0.1:7000")?;
+/// let mut reactor = Reactor::new()?;
+/// for stream in listener.incoming().take(2) {
+///     let stream = stream?;
+///     stream.set_nonblocking(true)?;
+///     reactor.register(read_ready(TcpStream::from_std(stream), 4096))?;
+/// }
+///
+/// reactor.run(|token, result| match result {
+///     Ok(Some(chunk)) => println!("{token:?}: {} bytes", chunk.len()),
+///     Ok(None) => println!("{token:?}: closed"),
+///     Err(e) => eprintln!("{token:?}: {e}"),
+/// })?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// Available with the `mio` feature.
+pub struct Reactor<'a, T, E> {
+    poll: Poll,
+    events: Events,
+    sources: Vec<Option<Box<dyn Pollable<Item = T, Error = E> + 'a>>>,
+    live: usize,
+}
+
+impl<'a, T, E> Reactor<'a, T, E> {
+    /// Creates a reactor with no sources.
+    pub fn new() -> io::Result<Self> {
+        Ok(Self {
+            poll: Poll::new()?,
+            events: Events::with_capacity(128),
+            sources: Vec::new(),
+            live: 0,
+        })
+    }
+
+    /// Registers `source` for readable events and returns its token.
+    ///
+    /// Tokens are not reused, so each one identifies a single source for
+    /// the lifetime of the reactor.
+    pub fn register<S>(&mut self, mut source: S) -> io::Result<Token>
+    where
+        S: TryNextReady<Item = T, Error = E> + Source + 'a,
+    {
+        let token = Token(self.sources.len());
+        self.poll
+            .registry()
+            .register(&mut source, token, Interest::READABLE)?;
+        self.sources.push(Some(Box::new(source)));
+        self.live += 1;
+        Ok(token)
+    }
+
+    /// Returns the number of registered sources that have not yet ended or
+    /// failed.
+    pub fn len(&self) -> usize {
+        self.live
+    }
+
+    /// Returns `true` if no registered sources are left.
+    pub fn is_empty(&self) -> bool {
+        self.live == 0
+    }
+
+    /// Waits up to `timeout` (or indefinitely for `None`) for readiness
+    /// events and pulls from every source that became readable, passing
+    /// each result to `on_result`.
+    pub fn poll_once(
+        &mut self,
+        timeout: Option<Duration>,
+        mut on_result: impl FnMut(Token, Result<Option<T>, E>),
+    ) -> io::Result<()> {
+        match self.poll.poll(&mut self.events, timeout) {
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => return Ok(()),
+            result => result?,
+        }
+        for event in &self.events {
+            let token = event.token();
+            let Some(slot) = self.sources.get_mut(token.0) else {
+                continue;
+            };
+            let Some(source) = slot else {
+                continue;
+            };
+            let done = loop {
+                match source.try_next_ready() {
+                    Ok(Readiness::Pending) => break false,
+                    Ok(Readiness::Ready(Some(item))) => on_result(token, Ok(Some(item))),
+                    Ok(Readiness::Ready(None)) => {
+                        on_result(token, Ok(None));
+                        break true;
+                    }
+                    Err(e) => {
+                        on_result(token, Err(e));
+                        break true;
+                    }
+                }
+            };
+            if done {
+                if let Some(mut source) = slot.take() {
+                    self.poll.registry().deregister(&mut source)?;
+                }
+                self.live -= 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Polls until every registered source has ended or failed.
+    pub fn run(
+        &mut self,
+        mut on_result: impl FnMut(Token, Result<Option<T>, E>),
+    ) -> io::Result<()> {
+        while !self.is_empty() {
+            self.poll_once(None, &mut on_result)?;
+        }
+        Ok(())
+    }
+}
+
+impl<R: Source> Source for ReadReady<R> {
+    fn register(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        self.get_mut().register(registry, token, interests)
+    }
+
+    fn reregister(
+        &mut self,
+        registry: &Registry,
+        token: Token,
+        interests: Interest,
+    ) -> io::Result<()> {
+        self.get_mut().reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+        self.get_mut().deregister(registry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::Write;
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    use super::Reactor;
+    use crate::sources::read_ready;
+
+    #[test]
+    fn reads_sockets_to_the_end() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut reactor = Reactor::new().unwrap();
+        let mut names = HashMap::new();
+        let mut clients = Vec::new();
+        for name in ["a", "b"] {
+            clients.push(TcpStream::connect(addr).unwrap());
+            let (server, _) = listener.accept().unwrap();
+            server.set_nonblocking(true).unwrap();
+            let src = read_ready(mio::net::TcpStream::from_std(server), 3);
+            names.insert(reactor.register(src).unwrap(), name);
+        }
+        assert_eq!(reactor.len(), 2);
+
+        let writer = thread::spawn(move || {
+            for (i, mut client) in clients.into_iter().enumerate() {
+                client.write_all(&[b'0' + i as u8; 7]).unwrap();
+            }
+        });
+
+        let mut received: HashMap<_, Vec<u8>> = HashMap::new();
+        let mut ended = Vec::new();
+        reactor
+            .run(|token, result| match result.unwrap() {
+                Some(chunk) => received.entry(names[&token]).or_default().extend(chunk),
+                None => ended.push(names[&token]),
+            })
+            .unwrap();
+        writer.join().unwrap();
+
+        assert!(reactor.is_empty());
+        ended.sort();
+        assert_eq!(ended, ["a", "b"]);
+        assert_eq!(received["a"], b"0000000");
+        assert_eq!(received["b"], b"1111111");
+    }
+}
@@ -0,0 +1,94 @@
+//! Sources that can be polled without blocking.
+
+use crate::adapters::{Blocking, WaitStrategy};
+
+/// The outcome of a non-blocking pull.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Readiness<T> {
+    /// The pull completed: `Some(item)`, or `None` at the end of input.
+    Ready(Option<T>),
+    /// Nothing is available yet; poll again later.
+    Pending,
+}
+
+impl<T> Readiness<T> {
+    /// Returns `true` for [`Pending`](Self::Pending).
+    pub fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+
+    /// Converts the item of a [`Ready`](Self::Ready) outcome with `f`.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Readiness<U> {
+        match self {
+            Self::Ready(item) => Readiness::Ready(item.map(f)),
+            Self::Pending => Readiness::Pending,
+        }
+    }
+}
+
+/// A fallible producer that reports when it would block instead of waiting.
+///
+/// The non-blocking counterpart of [`TryNext`](crate::TryNext), for sources
+/// driven from a poll-based event loop. "Not yet" is
+/// [`Readiness::Pending`] rather than an error, so the error type is left
+/// for real failures. The event loop is responsible for knowing when to
+/// poll again; no wake-up is registered.
+///
+/// [`ChannelSource`](crate::sources::ChannelSource) implements it with
+/// [`Receiver::try_recv`](std::sync::mpsc::Receiver::try_recv), and
+/// [`read_ready`](crate::sources::read_ready) adapts a non-blocking reader
+/// such as a socket.
+///
+/// ```rust
+/// use std::sync::mpsc;
+/// use try_next::sources::ChannelSource;
+/// use try_next::{Readiness, TryNextReady};
+///
+/// let (tx, rx) = mpsc::channel();
+/// let mut src = ChannelSource::new(rx);
+///
+/// assert_eq!(src.try_next_ready(), Ok(Readiness::Pending));
+/// tx.send(7).unwrap();
+/// assert_eq!(src.try_next_ready(), Ok(Readiness::Ready(Some(7))));
+/// drop(tx);
+/// assert_eq!(src.try_next_ready(), Ok(Readiness::Ready(None)));
+/// ```
+pub trait TryNextReady {
+    /// The type of items produced.
+    type Item;
+    /// The type of error that may occur.
+    type Error;
+
+    /// Attempts to produce the next item without blocking.
+    fn try_next_ready(&mut self) -> Result<Readiness<Self::Item>, Self::Error>;
+
+    /// Converts this source into a [`TryNext`](crate::TryNext) that waits
+    /// out pending polls using `wait`.
+    ///
+    /// `wait` is a [`Wait`](crate::adapters::Wait) strategy or a closure
+    /// taking the number of pending polls so far.
+    fn blocking<W: WaitStrategy>(self, wait: W) -> Blocking<Self, W>
+    where
+        Self: Sized,
+    {
+        Blocking::new(self, wait)
+    }
+}
+
+impl<S: TryNextReady + ?Sized> TryNextReady for &mut S {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next_ready(&mut self) -> Result<Readiness<Self::Item>, Self::Error> {
+        (**self).try_next_ready()
+    }
+}
+
+impl<S: TryNextReady + ?Sized> TryNextReady for Box<S> {
+    type Item = S::Item;
+    type Error = S::Error;
+
+    fn try_next_ready(&mut self) -> Result<Readiness<Self::Item>, Self::Error> {
+        (**self).try_next_ready()
+    }
+}
@@ -0,0 +1,140 @@
+//! Storage for items that are read back more than once.
+//!
+//! A [`ReplayBuffer`] records items as they are first pulled and hands them
+//! out again by index. It backs [`Replayable`](crate::adapters::Replayable)
+//! and [`Shared`](crate::adapters::Shared):
+//! a `Vec<T>` keeps clones in memory, while `SpillFile` (with the `serde`
+//! feature) serializes them to a temporary file for streams too large to
+//! hold.
+
+#[cfg(feature = "serde")]
+use std::fs::{self, File, OpenOptions};
+#[cfg(feature = "serde")]
+use std::io::{self, Read, Seek, SeekFrom, Write};
+#[cfg(feature = "serde")]
+use std::marker::PhantomData;
+#[cfg(feature = "serde")]
+use std::path::PathBuf;
+#[cfg(feature = "serde")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+
+/// Recorded items that can be read back by index.
+///
+/// Failures are reported in the error type `E` of the source being
+/// recorded, so an in-memory buffer works with any source while a
+/// file-backed one needs `E: From<io::Error>`.
+pub trait ReplayBuffer<T, E> {
+    /// Appends a copy of `item`.
+    fn record(&mut self, item: &T) -> Result<(), E>;
+
+    /// Returns a copy of the item recorded at `index`.
+    ///
+    /// Callers only ask for indices below [`len`](Self::len).
+    fn replay(&mut self, index: usize) -> Result<T, E>;
+
+    /// Returns the number of recorded items.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if nothing has been recorded.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: Clone, E> ReplayBuffer<T, E> for Vec<T> {
+    fn record(&mut self, item: &T) -> Result<(), E> {
+        self.push(item.clone());
+        Ok(())
+    }
+
+    fn replay(&mut self, index: usize) -> Result<T, E> {
+        Ok(self[index].clone())
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+/// A [`ReplayBuffer`] that serializes items as JSON to a temporary file.
+///
+/// The file is created in [`std::env::temp_dir`] and deleted when the
+/// buffer is dropped. Only the offset of each record is kept in memory.
+///
+/// Available with the `serde` feature.
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub struct SpillFile<T> {
+    file: File,
+    path: PathBuf,
+    offsets: Vec<u64>,
+    end: u64,
+    _item: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> SpillFile<T> {
+    /// Creates an empty spill file.
+    pub fn new() -> io::Result<Self> {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        let name = format!(
+            "try-next-spill-{}-{}",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        );
+        let path = std::env::temp_dir().join(name);
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        Ok(Self {
+            file,
+            path,
+            offsets: Vec::new(),
+            end: 0,
+            _item: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, E> ReplayBuffer<T, E> for SpillFile<T>
+where
+    T: Serialize + DeserializeOwned,
+    E: From<io::Error>,
+{
+    fn record(&mut self, item: &T) -> Result<(), E> {
+        let bytes = serde_json::to_vec(item).map_err(io::Error::from)?;
+        self.file.seek(SeekFrom::Start(self.end))?;
+        self.file.write_all(&bytes)?;
+        self.offsets.push(self.end);
+        self.end += bytes.len() as u64;
+        Ok(())
+    }
+
+    fn replay(&mut self, index: usize) -> Result<T, E> {
+        let start = self.offsets[index];
+        let stop = self.offsets.get(index + 1).copied().unwrap_or(self.end);
+        let mut bytes = vec![0; (stop - start) as usize];
+        self.file.seek(SeekFrom::Start(start))?;
+        self.file.read_exact(&mut bytes)?;
+        Ok(serde_json::from_slice(&bytes).map_err(io::Error::from)?)
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> Drop for SpillFile<T> {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
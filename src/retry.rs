@@ -0,0 +1,226 @@
+//! Policies deciding whether and when a failed pull is retried.
+//!
+//! A policy is consulted by the [`Retry`](crate::adapters::Retry) adapter
+//! each time the inner source returns an error. Closures of the form
+//! `FnMut(u32) -> Option<Duration>` are policies too.
+//!
+//! Which errors are retried at all is decided separately by a [`Classify`]
+//! implementation: [`AnyError`] retries everything, while [`Transient`]
+//! asks the error itself through [`RetryableError`].
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::time::Duration;
+
+/// An error that knows whether the operation that produced it may succeed
+/// if repeated.
+pub trait RetryableError {
+    /// Returns `true` if the failure is transient, e.g. a timeout or a
+    /// dropped connection.
+    fn is_retryable(&self) -> bool;
+
+    /// Returns the minimum time to wait before retrying, if the error
+    /// carries one (e.g. a rate limiter's `Retry-After`).
+    fn retry_after(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// Interruptions, timeouts, and connection failures are retryable; every
+/// other kind is treated as permanent.
+impl RetryableError for io::Error {
+    fn is_retryable(&self) -> bool {
+        matches!(
+            self.kind(),
+            io::ErrorKind::Interrupted
+                | io::ErrorKind::WouldBlock
+                | io::ErrorKind::TimedOut
+                | io::ErrorKind::ConnectionRefused
+                | io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::NotConnected
+        )
+    }
+}
+
+/// Decides which errors the [`Retry`](crate::adapters::Retry) adapter
+/// retries.
+pub trait Classify<E: ?Sized> {
+    /// Returns `true` if `error` may be retried.
+    fn is_retryable(&self, error: &E) -> bool;
+
+    /// Returns the minimum delay `error` asks for; the adapter waits for the
+    /// longer of this and the policy's delay.
+    fn retry_after(&self, error: &E) -> Option<Duration> {
+        let _ = error;
+        None
+    }
+}
+
+/// A [`Classify`] that retries every error.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnyError;
+
+impl<E: ?Sized> Classify<E> for AnyError {
+    fn is_retryable(&self, _: &E) -> bool {
+        true
+    }
+}
+
+/// A [`Classify`] that defers to the error's [`RetryableError`]
+/// implementation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Transient;
+
+impl<E: RetryableError + ?Sized> Classify<E> for Transient {
+    fn is_retryable(&self, error: &E) -> bool {
+        error.is_retryable()
+    }
+
+    fn retry_after(&self, error: &E) -> Option<Duration> {
+        error.retry_after()
+    }
+}
+
+/// Decides whether to retry after a failure, and how long to wait first.
+pub trait RetryPolicy {
+    /// Returns the delay before retry number `attempt` (starting at 1), or
+    /// `None` to give up and surface the error.
+    fn delay(&mut self, attempt: u32) -> Option<Duration>;
+}
+
+impl<F> RetryPolicy for F
+where
+    F: FnMut(u32) -> Option<Duration>,
+{
+    fn delay(&mut self, attempt: u32) -> Option<Duration> {
+        self(attempt)
+    }
+}
+
+/// Retries up to a fixed number of times, waiting the same delay each time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fixed {
+    delay: Duration,
+    max_retries: u32,
+}
+
+impl Fixed {
+    /// Creates a policy that retries at most `max_retries` times, waiting
+    /// `delay` before each retry.
+    pub fn new(delay: Duration, max_retries: u32) -> Self {
+        Self { delay, max_retries }
+    }
+}
+
+impl RetryPolicy for Fixed {
+    fn delay(&mut self, attempt: u32) -> Option<Duration> {
+        (attempt <= self.max_retries).then_some(self.delay)
+    }
+}
+
+/// Retries with exponentially growing delays, optionally capped and
+/// randomized.
+///
+/// The delay before retry `n` is `initial * 2^(n - 1)`, capped at the
+/// maximum delay. With jitter enabled, the actual delay is drawn uniformly
+/// from zero up to that value, which spreads out retries from many clients
+/// failing at once.
+#[derive(Debug, Clone)]
+pub struct Exponential {
+    initial: Duration,
+    max_delay: Duration,
+    max_retries: u32,
+    jitter: Option<u64>,
+}
+
+impl Exponential {
+    /// Creates a policy that retries at most `max_retries` times, starting
+    /// with `initial` and doubling the delay each time.
+    pub fn new(initial: Duration, max_retries: u32) -> Self {
+        Self {
+            initial,
+            max_delay: Duration::MAX,
+            max_retries,
+            jitter: None,
+        }
+    }
+
+    /// Caps each delay at `max_delay`.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Draws each delay uniformly from zero up to the computed delay.
+    pub fn with_jitter(mut self) -> Self {
+        let seed = RandomState::new().build_hasher().finish();
+        self.jitter = Some(seed | 1);
+        self
+    }
+}
+
+impl RetryPolicy for Exponential {
+    fn delay(&mut self, attempt: u32) -> Option<Duration> {
+        if attempt > self.max_retries {
+            return None;
+        }
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        let delay = self
+            .initial
+            .checked_mul(factor)
+            .unwrap_or(Duration::MAX)
+            .min(self.max_delay);
+        let Some(state) = &mut self.jitter else {
+            return Some(delay);
+        };
+        // xorshift64; quality is irrelevant, only spread matters.
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        let nanos = u64::try_from(delay.as_nanos()).unwrap_or(u64::MAX);
+        Some(Duration::from_nanos(*state % nanos.saturating_add(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Exponential, Fixed, RetryPolicy, RetryableError};
+    use std::io;
+    use std::time::Duration;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn fixed_gives_up_after_max_retries() {
+        let mut policy = Fixed::new(MS, 2);
+        assert_eq!(policy.delay(1), Some(MS));
+        assert_eq!(policy.delay(2), Some(MS));
+        assert_eq!(policy.delay(3), None);
+    }
+
+    #[test]
+    fn exponential_doubles_up_to_the_cap() {
+        let mut policy = Exponential::new(MS, 10).with_max_delay(5 * MS);
+        let delays: Vec<_> = (1..=5).map(|n| policy.delay(n).unwrap()).collect();
+        assert_eq!(delays, [MS, 2 * MS, 4 * MS, 5 * MS, 5 * MS]);
+        assert_eq!(policy.delay(11), None);
+    }
+
+    #[test]
+    fn jitter_stays_within_the_computed_delay() {
+        let mut policy = Exponential::new(MS, 20).with_jitter();
+        for attempt in 1..=20 {
+            assert!(policy.delay(attempt).unwrap() <= MS * (1 << (attempt - 1)));
+        }
+    }
+
+    #[test]
+    fn io_timeouts_are_retryable() {
+        assert!(io::Error::from(io::ErrorKind::TimedOut).is_retryable());
+        assert!(!io::Error::from(io::ErrorKind::NotFound).is_retryable());
+    }
+}
@@ -0,0 +1,222 @@
+//! Adapters for database-style row cursors.
+//!
+//! Most database drivers expose a cursor with a `next_row`-like method that
+//! returns `Result<Option<Row>, E>`. The shape matches [`TryNext`] exactly,
+//! except that many drivers hand out rows that *borrow* from the cursor and
+//! therefore cannot be returned as owned items.
+//!
+//! [`RowSource`] abstracts over such cursors by passing each row to a caller
+//! supplied closure, and [`MapRows`] turns any [`RowSource`] into a
+//! [`TryNext`] producing the closure's owned results.
+//!
+//! Concrete implementations are provided for:
+//!
+//! - [`rusqlite::Rows`] — behind the `rusqlite` feature,
+//! - [`postgres::RowIter`] — behind the `postgres` feature.
+
+use crate::TryNext;
+
+/// A cursor over database rows.
+///
+/// Each call to [`next_row`](Self::next_row) advances the cursor and passes
+/// the current row to `f`, which converts it into an owned value. The three
+/// outcomes follow the usual [`TryNext`] conventions:
+///
+/// * `Ok(Some(value))` — a row was read and converted,
+/// * `Ok(None)` — the cursor is exhausted,
+/// * `Err(error)` — the driver or the conversion failed.
+pub trait RowSource {
+    /// The row type handed to the conversion closure.
+    type Row: ?Sized;
+
+    /// The error type reported by the driver.
+    type Error;
+
+    fn next_row<T, F>(&mut self, f: F) -> Result<Option<T>, Self::Error>
+    where
+        F: FnOnce(&Self::Row) -> Result<T, Self::Error>;
+}
+
+/// A [`TryNext`] over the rows of a [`RowSource`].
+///
+/// Created by [`map_rows`]. Each row is converted with the supplied closure.
+#[derive(Debug, Clone)]
+pub struct MapRows<R, F> {
+    source: R,
+    f: F,
+}
+
+impl<R, F> MapRows<R, F> {
+    /// Returns the underlying row source.
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+}
+
+/// Wraps a [`RowSource`] into a [`TryNext`] that converts each row with `f`.
+///
+/// ```rust
+/// use try_next::{RowSource, TryNext, map_rows};
+///
+/// struct Cursor { rows: Vec<(u32, &'static str)> }
+///
+/// impl RowSource for Cursor {
+///     type Row = (u32, &'static str);
+///     type Error = ();
+///
+///     fn next_row<T, F>(&mut self, f: F) -> Result<Option<T>, Self::Error>
+///     where
+///         F: FnOnce(&Self::Row) -> Result<T, Self::Error>,
+///     {
+///         if self.rows.is_empty() {
+///             return Ok(None);
+///         }
+///         f(&self.rows.remove(0)).map(Some)
+///     }
+/// }
+///
+/// let cursor = Cursor { rows: vec![(1, "one"), (2, "two")] };
+/// let mut names = map_rows(cursor, |row: &(u32, &str)| Ok(row.1.to_string()));
+///
+/// assert_eq!(names.try_next(), Ok(Some("one".to_string())));
+/// assert_eq!(names.try_next(), Ok(Some("two".to_string())));
+/// assert_eq!(names.try_next(), Ok(None));
+/// ```
+pub fn map_rows<R, F, T>(source: R, f: F) -> MapRows<R, F>
+where
+    R: RowSource,
+    F: FnMut(&R::Row) -> Result<T, R::Error>,
+{
+    MapRows { source, f }
+}
+
+impl<R, F, T> TryNext for MapRows<R, F>
+where
+    R: RowSource,
+    F: FnMut(&R::Row) -> Result<T, R::Error>,
+{
+    type Item = T;
+    type Error = R::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let f = &mut self.f;
+        self.source.next_row(|row| f(row))
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+impl<'stmt> RowSource for rusqlite::Rows<'stmt> {
+    type Row = rusqlite::Row<'stmt>;
+    type Error = rusqlite::Error;
+
+    fn next_row<T, F>(&mut self, f: F) -> Result<Option<T>, Self::Error>
+    where
+        F: FnOnce(&Self::Row) -> Result<T, Self::Error>,
+    {
+        match self.next()? {
+            Some(row) => f(row).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl RowSource for postgres::RowIter<'_> {
+    type Row = postgres::Row;
+    type Error = postgres::Error;
+
+    fn next_row<T, F>(&mut self, f: F) -> Result<Option<T>, Self::Error>
+    where
+        F: FnOnce(&Self::Row) -> Result<T, Self::Error>,
+    {
+        use postgres::fallible_iterator::FallibleIterator;
+
+        match self.next()? {
+            Some(row) => f(&row).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RowSource, map_rows};
+    use crate::TryNext;
+
+    /// An in-memory cursor that fails once it reaches `fail_at`.
+    struct Cursor {
+        rows: Vec<i64>,
+        pos: usize,
+        fail_at: Option<usize>,
+    }
+
+    impl RowSource for Cursor {
+        type Row = i64;
+        type Error = &'static str;
+
+        fn next_row<T, F>(&mut self, f: F) -> Result<Option<T>, Self::Error>
+        where
+            F: FnOnce(&Self::Row) -> Result<T, Self::Error>,
+        {
+            if Some(self.pos) == self.fail_at {
+                return Err("driver");
+            }
+            match self.rows.get(self.pos) {
+                Some(row) => {
+                    self.pos += 1;
+                    f(row).map(Some)
+                }
+                None => Ok(None),
+            }
+        }
+    }
+
+    #[test]
+    fn maps_rows_until_exhausted() {
+        let cursor = Cursor {
+            rows: vec![1, 2, 3],
+            pos: 0,
+            fail_at: None,
+        };
+        let mut src = map_rows(cursor, |row: &i64| Ok(row * 10));
+
+        assert_eq!(src.try_next(), Ok(Some(10)));
+        assert_eq!(src.try_next(), Ok(Some(20)));
+        assert_eq!(src.try_next(), Ok(Some(30)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn propagates_driver_and_conversion_errors() {
+        let cursor = Cursor {
+            rows: vec![1, -1, 3],
+            pos: 0,
+            fail_at: Some(2),
+        };
+        let mut src = map_rows(
+            cursor,
+            |row: &i64| {
+                if *row < 0 { Err("negative") } else { Ok(*row) }
+            },
+        );
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err("negative"));
+        assert_eq!(src.try_next(), Err("driver"));
+    }
+
+    #[cfg(feature = "rusqlite")]
+    #[test]
+    fn reads_rusqlite_rows() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE t (x INTEGER); INSERT INTO t VALUES (1), (2);")
+            .unwrap();
+        let mut stmt = conn.prepare("SELECT x FROM t ORDER BY x").unwrap();
+        let rows = stmt.query([]).unwrap();
+        let mut src = map_rows(rows, |row: &rusqlite::Row<'_>| row.get::<_, i64>(0));
+
+        assert_eq!(src.try_next().unwrap(), Some(1));
+        assert_eq!(src.try_next().unwrap(), Some(2));
+        assert_eq!(src.try_next().unwrap(), None);
+    }
+}
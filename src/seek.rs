@@ -0,0 +1,131 @@
+//! Sources that can be repositioned.
+
+use crate::TryNext;
+
+/// A [`TryNext`] that can be moved to an earlier or later position.
+///
+/// Multi-pass algorithms (e.g. inferring a schema on a first pass and
+/// decoding on a second) use this trait to restart a source, or to return to
+/// a position recorded with [`try_position`](Self::try_position).
+///
+/// ```rust
+/// use try_next::{TryNext, TrySeek};
+///
+/// struct Items { data: Vec<char>, pos: usize }
+///
+/// impl TryNext for Items {
+///     type Item = char;
+///     type Error = ();
+///
+///     fn try_next(&mut self) -> Result<Option<char>, ()> {
+///         let item = self.data.get(self.pos).copied();
+///         self.pos += item.is_some() as usize;
+///         Ok(item)
+///     }
+/// }
+///
+/// impl TrySeek for Items {
+///     type Position = usize;
+///
+///     fn try_position(&mut self) -> Result<usize, ()> {
+///         Ok(self.pos)
+///     }
+///
+///     fn try_seek(&mut self, position: usize) -> Result<(), ()> {
+///         if position > self.data.len() {
+///             return Err(());
+///         }
+///         self.pos = position;
+///         Ok(())
+///     }
+///
+///     fn try_rewind(&mut self) -> Result<(), ()> {
+///         self.try_seek(0)
+///     }
+/// }
+///
+/// let mut src = Items { data: vec!['a', 'b'], pos: 0 };
+/// assert_eq!(src.try_next(), Ok(Some('a')));
+/// let mark = src.try_position().unwrap();
+/// assert_eq!(src.try_next(), Ok(Some('b')));
+/// src.try_seek(mark).unwrap();
+/// assert_eq!(src.try_next(), Ok(Some('b')));
+/// src.try_rewind().unwrap();
+/// assert_eq!(src.try_next(), Ok(Some('a')));
+/// ```
+pub trait TrySeek: TryNext {
+    /// An opaque or concrete position within the source, such as an item
+    /// index or a byte offset.
+    type Position;
+
+    /// Returns the position of the next item to be produced.
+    fn try_position(&mut self) -> Result<Self::Position, Self::Error>;
+
+    /// Moves the source so that the next item produced is the one at
+    /// `position`.
+    fn try_seek(&mut self, position: Self::Position) -> Result<(), Self::Error>;
+
+    /// Moves the source back to its first item.
+    fn try_rewind(&mut self) -> Result<(), Self::Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrySeek;
+    use crate::{TryNext, TryNextExt};
+
+    struct Items {
+        data: Vec<u8>,
+        pos: usize,
+    }
+
+    impl TryNext for Items {
+        type Item = u8;
+        type Error = ();
+
+        fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+            let item = self.data.get(self.pos).copied();
+            if item.is_some() {
+                self.pos += 1;
+            }
+            Ok(item)
+        }
+    }
+
+    impl TrySeek for Items {
+        type Position = usize;
+
+        fn try_position(&mut self) -> Result<Self::Position, Self::Error> {
+            Ok(self.pos)
+        }
+
+        fn try_seek(&mut self, position: Self::Position) -> Result<(), Self::Error> {
+            self.pos = position;
+            Ok(())
+        }
+
+        fn try_rewind(&mut self) -> Result<(), Self::Error> {
+            self.try_seek(0)
+        }
+    }
+
+    #[test]
+    fn rewinding_a_fused_source_restarts_it() {
+        let mut src = Items {
+            data: vec![1, 2],
+            pos: 0,
+        }
+        .fuse();
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+
+        src.try_rewind().unwrap();
+        assert_eq!(src.try_next(), Ok(Some(1)));
+
+        src.try_seek(1).unwrap();
+        assert_eq!(src.try_position(), Ok(1));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+    }
+}
@@ -0,0 +1,107 @@
+//! Contexts whose state can be saved and rolled back.
+
+/// A context that can record its state and later return to it.
+///
+/// Backtracking parsers keep symbol tables, scopes, or counters in the
+/// context; when a speculative pull fails, those changes must be undone
+/// before another alternative is tried. The [`Backtrack`](crate::adapters::Backtrack)
+/// adapter does this automatically around every pull.
+///
+/// ```rust
+/// use try_next::SnapshotContext;
+///
+/// struct Scopes { names: Vec<String> }
+///
+/// impl SnapshotContext for Scopes {
+///     type Snapshot = usize;
+///
+///     fn snapshot(&self) -> usize {
+///         self.names.len()
+///     }
+///
+///     fn restore(&mut self, len: usize) {
+///         self.names.truncate(len);
+///     }
+/// }
+///
+/// let mut scopes = Scopes { names: vec!["x".into()] };
+/// let mark = scopes.snapshot();
+/// scopes.names.push("y".into());
+/// scopes.restore(mark);
+/// assert_eq!(scopes.names, ["x"]);
+/// ```
+pub trait SnapshotContext {
+    /// The saved state, as cheap as the context allows (e.g. a length for an
+    /// append-only log, or a full clone).
+    type Snapshot;
+
+    /// Records the current state.
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Returns to a state previously recorded by [`snapshot`](Self::snapshot).
+    fn restore(&mut self, snapshot: Self::Snapshot);
+}
+
+impl SnapshotContext for () {
+    type Snapshot = ();
+
+    fn snapshot(&self) {}
+
+    fn restore(&mut self, (): ()) {}
+}
+
+/// Snapshots by cloning the whole collection, which is O(len) per snapshot.
+impl<T: Clone> SnapshotContext for Vec<T> {
+    type Snapshot = Vec<T>;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.clone()
+    }
+
+    fn restore(&mut self, snapshot: Self::Snapshot) {
+        *self = snapshot;
+    }
+}
+
+/// Snapshots by cloning the whole collection, which is O(len) per snapshot.
+impl SnapshotContext for String {
+    type Snapshot = String;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.clone()
+    }
+
+    fn restore(&mut self, snapshot: Self::Snapshot) {
+        *self = snapshot;
+    }
+}
+
+/// Snapshots both halves of the tuple contexts used by
+/// [`chain`](crate::TryNextWithContextExt::chain) and friends.
+impl<A: SnapshotContext, B: SnapshotContext> SnapshotContext for (A, B) {
+    type Snapshot = (A::Snapshot, B::Snapshot);
+
+    fn snapshot(&self) -> Self::Snapshot {
+        (self.0.snapshot(), self.1.snapshot())
+    }
+
+    fn restore(&mut self, (a, b): Self::Snapshot) {
+        self.0.restore(a);
+        self.1.restore(b);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SnapshotContext;
+
+    #[test]
+    fn tuple_restores_both_halves() {
+        let mut ctx = (vec![1], String::from("a"));
+        let mark = ctx.snapshot();
+        ctx.0.push(2);
+        ctx.1.push('b');
+        ctx.restore(mark);
+        assert_eq!(ctx, (vec![1], String::from("a")));
+    }
+}
@@ -0,0 +1,220 @@
+use std::convert::Infallible;
+use std::sync::mpsc::{Receiver, TryRecvError};
+
+use crate::cancel::{self, CancelToken, Cancelled, CheckCancel};
+use crate::{FusedTryNext, Readiness, TryNext, TryNextReady};
+
+/// A source that receives items from an [`mpsc`](std::sync::mpsc) channel.
+///
+/// Each call to [`try_next`](TryNext::try_next) blocks until an item arrives.
+/// Once every sender has been dropped and the channel is drained, the source
+/// returns `Ok(None)`. A source given a token with
+/// [`with_cancel`](Self::with_cancel) stops waiting and returns
+/// [`Cancelled`] once the token is cancelled, unless it has already
+/// returned `Ok(None)`.
+///
+/// As a [`TryNextReady`], the source never waits: an empty channel is
+/// [`Readiness::Pending`].
+///
+/// Created by converting a [`Receiver`] with
+/// [`IntoTryNext`](crate::IntoTryNext) or [`ChannelSource::new`].
+#[derive(Debug)]
+pub struct ChannelSource<T, C = ()> {
+    receiver: Receiver<T>,
+    cancel: C,
+    ended: bool,
+}
+
+impl<T> ChannelSource<T> {
+    /// Creates a source receiving from `receiver`.
+    pub fn new(receiver: Receiver<T>) -> Self {
+        Self {
+            receiver,
+            cancel: (),
+            ended: false,
+        }
+    }
+
+    /// Makes the source give up waiting once `token` is cancelled.
+    pub fn with_cancel(self, token: CancelToken) -> ChannelSource<T, CancelToken> {
+        ChannelSource {
+            receiver: self.receiver,
+            cancel: token,
+            ended: self.ended,
+        }
+    }
+}
+
+impl<T, C> ChannelSource<T, C> {
+    /// Returns the underlying receiver.
+    pub fn into_inner(self) -> Receiver<T> {
+        self.receiver
+    }
+
+    fn next<E>(&mut self) -> Result<Option<T>, E>
+    where
+        C: CheckCancel<E>,
+    {
+        if self.ended {
+            return Ok(None);
+        }
+        let item = cancel::recv(&self.receiver, &self.cancel)?.ok();
+        self.ended = item.is_none();
+        Ok(item)
+    }
+
+    fn next_ready<E>(&mut self) -> Result<Readiness<T>, E>
+    where
+        C: CheckCancel<E>,
+    {
+        if self.ended {
+            return Ok(Readiness::Ready(None));
+        }
+        self.cancel.check()?;
+        match self.receiver.try_recv() {
+            Ok(item) => Ok(Readiness::Ready(Some(item))),
+            Err(TryRecvError::Empty) => Ok(Readiness::Pending),
+            Err(TryRecvError::Disconnected) => {
+                self.ended = true;
+                Ok(Readiness::Ready(None))
+            }
+        }
+    }
+
+    fn next_chunk<E>(&mut self, max: usize) -> Result<Vec<T>, E>
+    where
+        C: CheckCancel<E>,
+    {
+        let mut chunk = Vec::new();
+        if max == 0 {
+            return Ok(chunk);
+        }
+        let Some(first) = self.next()? else {
+            return Ok(chunk);
+        };
+        chunk.push(first);
+        chunk.extend(self.receiver.try_iter().take(max - 1));
+        Ok(chunk)
+    }
+}
+
+impl<T> TryNext for ChannelSource<T> {
+    type Item = T;
+    type Error = Infallible;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.next()
+    }
+
+    /// Blocks for the first item only, then takes whatever else is already
+    /// queued, up to `max` items in total.
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        self.next_chunk(max)
+    }
+}
+
+impl<T> TryNext for ChannelSource<T, CancelToken> {
+    type Item = T;
+    type Error = Cancelled;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.next()
+    }
+
+    /// Blocks for the first item only, then takes whatever else is already
+    /// queued, up to `max` items in total.
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        self.next_chunk(max)
+    }
+}
+
+impl<T> TryNextReady for ChannelSource<T> {
+    type Item = T;
+    type Error = Infallible;
+
+    fn try_next_ready(&mut self) -> Result<Readiness<Self::Item>, Self::Error> {
+        self.next_ready()
+    }
+}
+
+impl<T> TryNextReady for ChannelSource<T, CancelToken> {
+    type Item = T;
+    type Error = Cancelled;
+
+    fn try_next_ready(&mut self) -> Result<Readiness<Self::Item>, Self::Error> {
+        self.next_ready()
+    }
+}
+
+impl<T> FusedTryNext for ChannelSource<T> {}
+
+impl<T> FusedTryNext for ChannelSource<T, CancelToken> {}
+
+#[cfg(test)]
+mod tests {
+    use super::ChannelSource;
+    use crate::TryNext;
+    use crate::cancel::{CancelToken, Cancelled};
+    use std::sync::mpsc;
+    use std::thread;
+
+    #[test]
+    fn ends_when_senders_are_dropped() {
+        let (tx, rx) = mpsc::channel();
+        let producer = thread::spawn(move || {
+            for i in 0..3 {
+                tx.send(i).unwrap();
+            }
+        });
+
+        let mut src = ChannelSource::new(rx);
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+        producer.join().unwrap();
+    }
+
+    #[test]
+    fn chunk_drains_queued_items_without_blocking() {
+        let (tx, rx) = mpsc::channel();
+        for i in 0..5 {
+            tx.send(i).unwrap();
+        }
+
+        let mut src = ChannelSource::new(rx);
+        assert_eq!(src.try_next_chunk(3), Ok(vec![0, 1, 2]));
+        // Only two items are queued; the sender is still alive, so this
+        // must return them rather than wait for a third.
+        assert_eq!(src.try_next_chunk(3), Ok(vec![3, 4]));
+
+        drop(tx);
+        assert_eq!(src.try_next_chunk(3), Ok(vec![]));
+    }
+
+    #[test]
+    fn cancelled_sources_stop_waiting() {
+        let (_tx, rx) = mpsc::channel::<u32>();
+        let token = CancelToken::new();
+        token.cancel();
+
+        let mut src = ChannelSource::new(rx).with_cancel(token);
+        assert_eq!(src.try_next(), Err(Cancelled));
+        assert_eq!(src.try_next_chunk(2), Err(Cancelled));
+    }
+
+    #[test]
+    fn cancelling_after_the_end_keeps_returning_none() {
+        let (tx, rx) = mpsc::channel();
+        let token = CancelToken::new();
+        tx.send(1).unwrap();
+        drop(tx);
+
+        let mut src = ChannelSource::new(rx).with_cancel(token.clone());
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(None));
+        token.cancel();
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.try_next_chunk(2), Ok(vec![]));
+    }
+}
@@ -0,0 +1,100 @@
+use std::ops::{Coroutine, CoroutineState};
+use std::pin::Pin;
+
+use crate::{FusedTryNext, TryNext};
+
+/// A source driven by a coroutine.
+///
+/// Created by [`from_coroutine`].
+#[derive(Debug, Clone)]
+pub struct FromCoroutine<G> {
+    coroutine: G,
+    done: bool,
+}
+
+/// Creates a source that resumes `coroutine` once per pull.
+///
+/// Each value the coroutine yields is returned as is: `Ok(item)` as an item
+/// and `Err(e)` as an error that does not end the sequence. Returning
+/// `Ok(())` ends the sequence and returning `Err(e)` yields `e` as the final
+/// result, as with [`generate`](crate::generate). Unlike `generate`, the
+/// coroutine runs on the consuming thread, with no thread or channel behind
+/// it.
+///
+/// The coroutine must be [`Unpin`]; pass a `static` coroutine, which may
+/// borrow across yields, through [`Box::pin`].
+///
+/// ```rust
+/// #![feature(coroutines, coroutine_trait, stmt_expr_attributes)]
+/// use try_next::{TryNext, from_coroutine};
+///
+/// let mut src = from_coroutine(
+///     #[coroutine]
+///     || {
+///         yield Ok("header");
+///         for line in ["a", "", "b"] {
+///             if line.is_empty() {
+///                 yield Err("empty line");
+///                 continue;
+///             }
+///             yield Ok(line);
+///         }
+///         Ok(())
+///     },
+/// );
+///
+/// assert_eq!(src.try_next(), Ok(Some("header")));
+/// assert_eq!(src.try_next(), Ok(Some("a")));
+/// assert_eq!(src.try_next(), Err("empty line"));
+/// assert_eq!(src.try_next(), Ok(Some("b")));
+/// assert_eq!(src.try_next(), Ok(None));
+/// ```
+///
+/// Available with the `nightly` feature, which requires a nightly compiler.
+pub fn from_coroutine<G, T, E>(coroutine: G) -> FromCoroutine<G>
+where
+    G: Coroutine<Yield = Result<T, E>, Return = Result<(), E>> + Unpin,
+{
+    FromCoroutine {
+        coroutine,
+        done: false,
+    }
+}
+
+impl<G> FromCoroutine<G> {
+    /// Returns the underlying coroutine.
+    pub fn into_inner(self) -> G {
+        self.coroutine
+    }
+}
+
+impl<G, T, E> TryNext for FromCoroutine<G>
+where
+    G: Coroutine<Yield = Result<T, E>, Return = Result<(), E>> + Unpin,
+{
+    type Item = T;
+    type Error = E;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        if self.done {
+            return Ok(None);
+        }
+        match Pin::new(&mut self.coroutine).resume(()) {
+            CoroutineState::Yielded(result) => result.map(Some),
+            CoroutineState::Complete(result) => {
+                self.done = true;
+                result.map(|()| None)
+            }
+        }
+    }
+}
+
+impl<G, T, E> FusedTryNext for FromCoroutine<G> where
+    G: Coroutine<Yield = Result<T, E>, Return = Result<(), E>> + Unpin
+{
+}
+
+// Coroutine syntax is feature-gated while parsing, before `cfg(test)` is
+// applied, so the tests live in a file that is only loaded for test builds.
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,38 @@
+use super::from_coroutine;
+use crate::TryNext;
+
+#[test]
+fn returned_error_is_final() {
+    let mut src = from_coroutine(
+        #[coroutine]
+        || {
+            yield Ok(1);
+            Err("truncated")?;
+            yield Ok(2);
+            Ok(())
+        },
+    );
+
+    assert_eq!(src.try_next(), Ok(Some(1)));
+    assert_eq!(src.try_next(), Err("truncated"));
+    assert_eq!(src.try_next(), Ok(None));
+    assert_eq!(src.try_next(), Ok(None));
+}
+
+#[test]
+fn static_coroutines_can_borrow_across_yields() {
+    let mut src = from_coroutine(Box::pin(
+        #[coroutine]
+        static || {
+            let words = vec!["x", "y"];
+            for word in &words {
+                yield Ok::<_, ()>(*word);
+            }
+            Ok(())
+        },
+    ));
+
+    assert_eq!(src.try_next(), Ok(Some("x")));
+    assert_eq!(src.try_next(), Ok(Some("y")));
+    assert_eq!(src.try_next(), Ok(None));
+}
@@ -0,0 +1,343 @@
+use std::fmt;
+use std::panic;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::thread::{self, JoinHandle};
+
+use crate::{FusedTryNext, TryNext};
+
+/// Writes a source as straight-line code.
+///
+/// The body runs like a closure given a [`Yielder`] named by the first
+/// token; it hands out results with [`Yielder::yield_item`] and
+/// [`Yielder::yield_err`], may use `?` and `return Err(..)` to fail, and
+/// ends the sequence by falling off the end. It must be `Send + 'static`,
+/// since it runs on its own thread; see [`generate`] for details and costs.
+///
+/// ```rust
+/// use try_next::{TryNext, try_gen};
+///
+/// let mut src = try_gen!(|co| {
+///     if !co.yield_item("header") {
+///         return Ok(());
+///     }
+///     for line in ["a", "b"] {
+///         if line.is_empty() {
+///             return Err("empty line");
+///         }
+///         if !co.yield_item(line) {
+///             return Ok(());
+///         }
+///     }
+/// });
+///
+/// assert_eq!(src.try_next(), Ok(Some("header")));
+/// assert_eq!(src.try_next(), Ok(Some("a")));
+/// assert_eq!(src.try_next(), Ok(Some("b")));
+/// assert_eq!(src.try_next(), Ok(None));
+/// ```
+#[macro_export]
+macro_rules! try_gen {
+    (|$co:ident| $body:block) => {
+        $crate::generate(move |$co: &mut $crate::sources::Yielder<_, _>| {
+            #[allow(unreachable_code)]
+            let result = {
+                $body;
+                ::std::result::Result::Ok(())
+            };
+            result
+        })
+    };
+}
+
+/// Creates a source from a generator function.
+///
+/// `f` runs on a dedicated thread, one step per pull: each
+/// [`try_next`](TryNext::try_next) resumes it until it yields through the
+/// [`Yielder`] or returns. Returning `Ok(())` ends the sequence and
+/// returning `Err(e)` yields `e` as the final result.
+///
+/// Dropping the source does not interrupt the generator: its pending yield,
+/// and every later one, returns `false` at once, and the generator must then
+/// return. One that ignores the result and keeps looping spins on its thread
+/// forever, which is why the yield methods are `#[must_use]`.
+///
+/// If `f` panics, the panic is resumed on the consuming thread.
+///
+/// This is a stopgap until coroutines are stable, not a compiled state
+/// machine: every source spawns an OS thread, and every item takes two
+/// channel handoffs between it and the consumer. For the same reason `f`,
+/// `T` and `E` must be `Send + 'static`, so the generator cannot borrow the
+/// caller's locals. For hot paths, implement [`TryNext`] directly, or use a
+/// coroutine with `from_coroutine` on nightly, which runs on the consuming
+/// thread.
+///
+/// See [`try_gen!`](crate::try_gen) for a shorter way to write the
+/// generator.
+///
+/// ```rust
+/// use try_next::{TryNext, generate};
+///
+/// let mut src = generate(|co| {
+///     for n in 1..=2 {
+///         if !co.yield_item(n) {
+///             return Ok(());
+///         }
+///     }
+///     Err("out of numbers")
+/// });
+/// assert_eq!(src.try_next(), Ok(Some(1)));
+/// assert_eq!(src.try_next(), Ok(Some(2)));
+/// assert_eq!(src.try_next(), Err("out of numbers"));
+/// assert_eq!(src.try_next(), Ok(None));
+///
+/// // An endless generator stops once its source is dropped.
+/// let mut naturals = generate(|co| {
+///     for n in 0.. {
+///         if !co.yield_item(n) {
+///             break;
+///         }
+///     }
+///     Ok::<_, ()>(())
+/// });
+/// assert_eq!(naturals.try_next(), Ok(Some(0)));
+/// drop(naturals);
+/// ```
+pub fn generate<T, E, F>(f: F) -> Generated<T, E>
+where
+    F: FnOnce(&mut Yielder<T, E>) -> Result<(), E> + Send + 'static,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    Generated {
+        state: State::Pending(Box::new(f)),
+    }
+}
+
+type Body<T, E> = Box<dyn FnOnce(&mut Yielder<T, E>) -> Result<(), E> + Send>;
+
+enum Step<T, E> {
+    Yielded(Result<T, E>),
+    Returned(Result<(), E>),
+}
+
+/// The handle a generator yields results through.
+///
+/// Passed to the function given to [`generate`] or [`try_gen!`](crate::try_gen).
+pub struct Yielder<T, E> {
+    steps: SyncSender<Step<T, E>>,
+    resume: Receiver<()>,
+}
+
+impl<T, E> Yielder<T, E> {
+    /// Yields `item` and waits until the consumer pulls again.
+    ///
+    /// Returns `false` if the source has been dropped instead; the generator
+    /// must then return, and whatever it returns is discarded.
+    #[must_use = "the generator must return once a yield returns `false`"]
+    pub fn yield_item(&mut self, item: T) -> bool {
+        self.yield_step(Ok(item))
+    }
+
+    /// Yields `error` without ending the sequence, and waits until the
+    /// consumer pulls again.
+    ///
+    /// Returns `false` if the source has been dropped instead, like
+    /// [`yield_item`](Self::yield_item).
+    #[must_use = "the generator must return once a yield returns `false`"]
+    pub fn yield_err(&mut self, error: E) -> bool {
+        self.yield_step(Err(error))
+    }
+
+    fn yield_step(&mut self, result: Result<T, E>) -> bool {
+        self.steps.send(Step::Yielded(result)).is_ok() && self.resume.recv().is_ok()
+    }
+}
+
+impl<T, E> fmt::Debug for Yielder<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Yielder").finish_non_exhaustive()
+    }
+}
+
+enum State<T, E> {
+    Pending(Body<T, E>),
+    Running {
+        steps: Receiver<Step<T, E>>,
+        resume: SyncSender<()>,
+        worker: JoinHandle<()>,
+    },
+    Done,
+}
+
+/// A source driven by a generator function.
+///
+/// Created by [`generate`] and [`try_gen!`](crate::try_gen).
+pub struct Generated<T, E> {
+    state: State<T, E>,
+}
+
+impl<T, E> Generated<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    fn start(body: Body<T, E>) -> State<T, E> {
+        let (step_tx, steps) = mpsc::sync_channel(1);
+        let (resume, resume_rx) = mpsc::sync_channel(1);
+        let worker = thread::spawn(move || {
+            let mut yielder = Yielder {
+                steps: step_tx,
+                resume: resume_rx,
+            };
+            let result = body(&mut yielder);
+            let _ = yielder.steps.send(Step::Returned(result));
+        });
+        State::Running {
+            steps,
+            resume,
+            worker,
+        }
+    }
+}
+
+impl<T, E> TryNext for Generated<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    type Item = T;
+    type Error = E;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let step = match &mut self.state {
+            State::Done => return Ok(None),
+            State::Pending(_) => {
+                let State::Pending(body) = std::mem::replace(&mut self.state, State::Done) else {
+                    unreachable!();
+                };
+                self.state = Self::start(body);
+                let State::Running { steps, .. } = &self.state else {
+                    unreachable!();
+                };
+                steps.recv()
+            }
+            State::Running { steps, resume, .. } => {
+                // The generator is parked in its last yield until resumed.
+                let _ = resume.send(());
+                steps.recv()
+            }
+        };
+        match step {
+            Ok(Step::Yielded(result)) => result.map(Some),
+            Ok(Step::Returned(result)) => {
+                self.state = State::Done;
+                result.map(|()| None)
+            }
+            Err(_) => {
+                // The generator panicked before returning.
+                if let State::Running { worker, .. } =
+                    std::mem::replace(&mut self.state, State::Done)
+                    && let Err(payload) = worker.join()
+                {
+                    panic::resume_unwind(payload);
+                }
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl<T, E> FusedTryNext for Generated<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+}
+
+impl<T, E> fmt::Debug for Generated<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = match self.state {
+            State::Pending(_) => "Pending",
+            State::Running { .. } => "Running",
+            State::Done => "Done",
+        };
+        f.debug_struct("Generated").field("state", &state).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use crate::{TryNext, generate};
+
+    #[test]
+    fn yields_errors_mid_stream_and_at_the_end() {
+        let mut src = try_gen!(|co| {
+            if !(co.yield_item(1) && co.yield_err('x') && co.yield_item(2)) {
+                return Ok(());
+            }
+            Err('y')?;
+        });
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Err('y'));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn runs_lazily_one_step_per_pull() {
+        let (tx, rx) = mpsc::channel();
+        let mut src = generate(move |co| {
+            for n in 0..3 {
+                tx.send(n).unwrap();
+                if !co.yield_item(n) {
+                    break;
+                }
+            }
+            Ok::<_, ()>(())
+        });
+
+        assert!(rx.try_recv().is_err());
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), [0, 1]);
+    }
+
+    #[test]
+    fn dropping_the_source_stops_the_generator() {
+        struct Guard(mpsc::Sender<()>);
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                let _ = self.0.send(());
+            }
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let mut src = generate(move |co| {
+            let _guard = Guard(tx);
+            while co.yield_item(()) {}
+            Ok(())
+        });
+        assert_eq!(src.try_next(), Ok::<_, ()>(Some(())));
+        drop(src);
+
+        assert!(rx.recv_timeout(Duration::from_secs(5)).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "generator failed")]
+    fn resumes_generator_panics() {
+        let mut src = generate(|co| {
+            let _ = co.yield_item(1);
+            panic!("generator failed");
+        });
+
+        assert_eq!(src.try_next(), Ok::<_, ()>(Some(1)));
+        let _ = src.try_next();
+    }
+}
@@ -0,0 +1,222 @@
+use std::convert::Infallible;
+use std::iter::FusedIterator;
+use std::num::NonZeroUsize;
+
+use crate::{DoubleEndedTryNext, ExactSizeTryNext, FusedTryNext, TryNext};
+
+/// A source over an iterator of `Result`s.
+///
+/// Created by [`from_iter`].
+#[derive(Debug, Clone)]
+pub struct FromIter<I> {
+    iter: I,
+}
+
+/// Creates a source from an iterator of `Result<T, E>`.
+///
+/// Each `Ok(item)` becomes `Ok(Some(item))`, each `Err(e)` becomes `Err(e)`,
+/// and the end of the iterator becomes `Ok(None)`.
+///
+/// ```rust
+/// use try_next::{TryNext, from_iter};
+///
+/// let mut src = from_iter(vec![Ok(1), Err("bad"), Ok(3)]);
+/// assert_eq!(src.try_next(), Ok(Some(1)));
+/// assert_eq!(src.try_next(), Err("bad"));
+/// assert_eq!(src.try_next(), Ok(Some(3)));
+/// assert_eq!(src.try_next(), Ok(None));
+/// ```
+pub fn from_iter<I, T, E>(iter: I) -> FromIter<I::IntoIter>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    FromIter {
+        iter: iter.into_iter(),
+    }
+}
+
+impl<I> FromIter<I> {
+    /// Returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, T, E> TryNext for FromIter<I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+    type Error = E;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.iter.next().transpose()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Errors are not items, so only the upper bound carries over.
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, T, E> DoubleEndedTryNext for FromIter<I>
+where
+    I: DoubleEndedIterator<Item = Result<T, E>>,
+{
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.iter.next_back().transpose()
+    }
+}
+
+impl<I, T, E> FusedTryNext for FromIter<I> where I: FusedIterator<Item = Result<T, E>> {}
+
+/// An infallible source over an iterator of plain items.
+///
+/// Created by [`from_items`].
+#[derive(Debug, Clone)]
+pub struct FromItems<I> {
+    iter: I,
+}
+
+/// Creates an infallible source from an iterator of items.
+///
+/// ```rust
+/// use try_next::{TryNext, from_items};
+///
+/// let mut src = from_items("ab".chars());
+/// assert_eq!(src.try_next(), Ok(Some('a')));
+/// assert_eq!(src.try_next(), Ok(Some('b')));
+/// assert_eq!(src.try_next(), Ok(None));
+/// ```
+pub fn from_items<I>(iter: I) -> FromItems<I::IntoIter>
+where
+    I: IntoIterator,
+{
+    FromItems {
+        iter: iter.into_iter(),
+    }
+}
+
+impl<I> FromItems<I> {
+    /// Returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> TryNext for FromItems<I> {
+    type Item = I::Item;
+    type Error = Infallible;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.iter.next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        Ok(self.iter.by_ref().take(max).collect())
+    }
+
+    fn try_fill(&mut self, buf: &mut [Self::Item]) -> Result<usize, Self::Error>
+    where
+        Self::Item: Copy,
+    {
+        let mut filled = 0;
+        for (slot, item) in buf.iter_mut().zip(self.iter.by_ref()) {
+            *slot = item;
+            filled += 1;
+        }
+        Ok(filled)
+    }
+
+    /// Skips through the iterator's internal iteration, counting what was
+    /// actually skipped rather than trusting its size hint.
+    fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        let skipped = self.iter.by_ref().take(n).count();
+        Ok(NonZeroUsize::new(n - skipped).map_or(Ok(()), Err))
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedTryNext for FromItems<I> {
+    fn try_next_back(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.iter.next_back())
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeTryNext for FromItems<I> {}
+
+impl<I: FusedIterator> FusedTryNext for FromItems<I> {}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::{from_items, from_iter};
+    use crate::{DoubleEndedTryNext, ExactSizeTryNext, TryNext};
+
+    #[test]
+    fn from_iter_is_double_ended() {
+        let mut src = from_iter(vec![Ok(1), Err('e'), Ok(3)]);
+
+        assert_eq!(src.size_hint(), (0, Some(3)));
+        assert_eq!(src.try_next_back(), Ok(Some(3)));
+        assert_eq!(src.try_next_back(), Err('e'));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn from_items_is_exact_size() {
+        let mut src = from_items([1, 2, 3]);
+
+        assert_eq!(src.len(), 3);
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next_back(), Ok(Some(3)));
+        assert_eq!(src.len(), 1);
+    }
+
+    #[test]
+    fn from_items_fills_without_overrunning() {
+        let mut src = from_items(b"abcde".iter().copied());
+        let mut buf = [0u8; 2];
+
+        assert_eq!(src.try_fill(&mut buf), Ok(2));
+        assert_eq!(&buf, b"ab");
+        assert_eq!(src.try_fill(&mut buf), Ok(2));
+        assert_eq!(src.try_fill(&mut buf), Ok(1));
+        assert_eq!(buf[0], b'e');
+        assert_eq!(src.try_fill(&mut buf), Ok(0));
+    }
+
+    #[test]
+    fn from_items_advance_ignores_a_lying_size_hint() {
+        /// Claims ten items but has only two.
+        struct Liar(u8);
+
+        impl Iterator for Liar {
+            type Item = u8;
+
+            fn next(&mut self) -> Option<u8> {
+                (self.0 < 2).then(|| {
+                    self.0 += 1;
+                    self.0
+                })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (10, Some(10))
+            }
+        }
+
+        let mut src = from_items(Liar(0));
+        assert_eq!(src.try_advance_by(1), Ok(Ok(())));
+        assert_eq!(
+            src.try_advance_by(4),
+            Ok(Err(NonZeroUsize::new(3).unwrap()))
+        );
+        assert_eq!(src.try_next(), Ok(None));
+    }
+}
@@ -0,0 +1,101 @@
+use std::vec;
+
+use crate::TryNext;
+
+/// Creates a source from a literal list of steps.
+///
+/// Each step is `Ok(item)`, `Err(error)`, or `end` for an `Ok(None)`; after
+/// the last step the source returns `Ok(None)` forever, so a trailing `end`
+/// is optional. An `end` before other steps makes a source that resumes
+/// after reporting its end, which is handy for testing how adapters treat
+/// such sources.
+///
+/// ```rust
+/// use try_next::{TryNext, source};
+///
+/// let mut src = source![Ok(1), Err("bad"), Ok(2), end, Ok(3)];
+/// assert_eq!(src.try_next(), Ok(Some(1)));
+/// assert_eq!(src.try_next(), Err("bad"));
+/// assert_eq!(src.try_next(), Ok(Some(2)));
+/// assert_eq!(src.try_next(), Ok(None));
+/// assert_eq!(src.try_next(), Ok(Some(3)));
+/// assert_eq!(src.try_next(), Ok(None));
+/// ```
+///
+/// Without an `Err` step the error type must be inferred from the use site
+/// or spelled out, e.g. `let src: sources::LiteralSource<u8, ()> = source![Ok(1)];`.
+#[macro_export]
+macro_rules! source {
+    (@steps [$($out:expr),*]) => {
+        ::std::vec![$($out),*]
+    };
+    (@steps [$($out:expr),*] Ok($item:expr) $(, $($rest:tt)*)?) => {
+        $crate::source!(
+            @steps [$($out,)* ::std::result::Result::Ok(::std::option::Option::Some($item))]
+            $($($rest)*)?
+        )
+    };
+    (@steps [$($out:expr),*] Err($error:expr) $(, $($rest:tt)*)?) => {
+        $crate::source!(
+            @steps [$($out,)* ::std::result::Result::Err($error)]
+            $($($rest)*)?
+        )
+    };
+    (@steps [$($out:expr),*] end $(, $($rest:tt)*)?) => {
+        $crate::source!(
+            @steps [$($out,)* ::std::result::Result::Ok(::std::option::Option::None)]
+            $($($rest)*)?
+        )
+    };
+    ($($steps:tt)*) => {
+        $crate::sources::LiteralSource::new($crate::source!(@steps [] $($steps)*))
+    };
+}
+
+/// A source replaying a fixed list of steps, then returning `Ok(None)`.
+///
+/// Created by [`source!`](crate::source).
+#[derive(Debug, Clone)]
+pub struct LiteralSource<T, E> {
+    steps: vec::IntoIter<Result<Option<T>, E>>,
+}
+
+impl<T, E> LiteralSource<T, E> {
+    /// Creates a source returning each of `steps` in turn.
+    pub fn new(steps: Vec<Result<Option<T>, E>>) -> Self {
+        Self {
+            steps: steps.into_iter(),
+        }
+    }
+}
+
+impl<T, E> TryNext for LiteralSource<T, E> {
+    type Item = T;
+    type Error = E;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.steps.next().unwrap_or(Ok(None))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.steps.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LiteralSource;
+    use crate::TryNext;
+
+    #[test]
+    fn accepts_trailing_commas_and_empty_lists() {
+        let mut src = source![Ok('a'), end,];
+        assert_eq!(src.try_next(), Ok::<_, ()>(Some('a')));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.try_next(), Ok(None));
+
+        let mut empty: LiteralSource<u8, ()> = source![];
+        assert_eq!(empty.size_hint(), (0, Some(0)));
+        assert_eq!(empty.try_next(), Ok(None));
+    }
+}
@@ -0,0 +1,255 @@
+use std::error::Error;
+use std::fmt;
+use std::panic;
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+
+use crate::cancel::{CancelToken, Cancelled, CheckCancel};
+use crate::{FusedTryNext, TryNext};
+
+/// Merges several sources, each pulled on its own thread, into one.
+///
+/// Items are yielded in whatever order the threads produce them, through a
+/// channel holding at most one waiting result per source, so a fast source
+/// cannot run far ahead of the consumer. Each error is wrapped in a
+/// [`MergeError`] naming the source it came from, and does not stop that
+/// source; a source's thread ends at its first `Ok(None)`, and the merged
+/// source ends once all of them have. Dropping the merged source stops each
+/// thread after its current pull.
+///
+/// If a source panics, the panic is resumed on the consuming thread once
+/// the other sources have finished.
+///
+/// ```rust
+/// use try_next::{TryNext, from_iter, merge_unordered};
+///
+/// let parts = vec![
+///     from_iter([Ok(1), Ok(2)]),
+///     from_iter([Ok(3), Err("bad row")]),
+/// ];
+/// let mut merged = merge_unordered(parts);
+///
+/// let mut items = Vec::new();
+/// let mut errors = Vec::new();
+/// while let Some(result) = merged.try_next().transpose() {
+///     match result {
+///         Ok(item) => items.push(item),
+///         Err(err) => errors.push((err.index, err.error)),
+///     }
+/// }
+/// items.sort();
+/// assert_eq!(items, [1, 2, 3]);
+/// assert_eq!(errors, [(1, "bad row")]);
+/// ```
+pub fn merge_unordered<I, S>(sources: I) -> MergeUnordered<S::Item, S::Error>
+where
+    I: IntoIterator<Item = S>,
+    S: TryNext + Send + 'static,
+    S::Item: Send + 'static,
+    S::Error: Send + 'static,
+{
+    spawn(sources, ())
+}
+
+/// Like [`merge_unordered`], but stops each source once `token` is
+/// cancelled.
+///
+/// Every source still running checks the token before each pull; once it
+/// is cancelled, the source reports [`Cancelled`], converted into its error
+/// type and attributed to it, and its thread ends. A source blocked in a
+/// pull notices after that pull returns.
+pub fn merge_unordered_with_cancel<I, S>(
+    sources: I,
+    token: CancelToken,
+) -> MergeUnordered<S::Item, S::Error>
+where
+    I: IntoIterator<Item = S>,
+    S: TryNext + Send + 'static,
+    S::Item: Send + 'static,
+    S::Error: From<Cancelled> + Send + 'static,
+{
+    spawn(sources, token)
+}
+
+fn spawn<I, S, C>(sources: I, cancel: C) -> MergeUnordered<S::Item, S::Error>
+where
+    I: IntoIterator<Item = S>,
+    S: TryNext + Send + 'static,
+    S::Item: Send + 'static,
+    S::Error: Send + 'static,
+    C: CheckCancel<S::Error> + Clone + Send + 'static,
+{
+    let sources: Vec<S> = sources.into_iter().collect();
+    let (sender, receiver) = mpsc::sync_channel(sources.len().max(1));
+    let workers = sources
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut source)| {
+            let sender = sender.clone();
+            let cancel = cancel.clone();
+            thread::spawn(move || {
+                loop {
+                    if let Err(error) = cancel.check() {
+                        let _ = sender.send(Err(MergeError { index, error }));
+                        return;
+                    }
+                    let result = match source.try_next() {
+                        Ok(Some(item)) => Ok(item),
+                        Ok(None) => return,
+                        Err(error) => Err(MergeError { index, error }),
+                    };
+                    if sender.send(result).is_err() {
+                        return;
+                    }
+                }
+            })
+        })
+        .collect();
+    MergeUnordered { receiver, workers }
+}
+
+/// An error from one of the sources of a [`MergeUnordered`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MergeError<E> {
+    /// The position of the failing source among those passed to
+    /// [`merge_unordered`].
+    pub index: usize,
+    /// The error it returned.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for MergeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "source {}: {}", self.index, self.error)
+    }
+}
+
+impl<E: Error + 'static> Error for MergeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// A source yielding the items of several sources as their threads produce
+/// them.
+///
+/// Created by [`merge_unordered`] and [`merge_unordered_with_cancel`].
+#[derive(Debug)]
+pub struct MergeUnordered<T, E> {
+    receiver: Receiver<Result<T, MergeError<E>>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl<T, E> MergeUnordered<T, E> {
+    fn finish(&mut self) {
+        let mut panicked = None;
+        for worker in self.workers.drain(..) {
+            if let Err(payload) = worker.join() {
+                panicked.get_or_insert(payload);
+            }
+        }
+        if let Some(payload) = panicked {
+            panic::resume_unwind(payload);
+        }
+    }
+}
+
+impl<T, E> TryNext for MergeUnordered<T, E> {
+    type Item = T;
+    type Error = MergeError<E>;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        match self.receiver.recv() {
+            Ok(result) => result.map(Some),
+            Err(_) => {
+                self.finish();
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl<T, E> FusedTryNext for MergeUnordered<T, E> {}
+
+#[cfg(test)]
+mod tests {
+    use std::vec;
+
+    use super::{MergeError, merge_unordered, merge_unordered_with_cancel};
+    use crate::cancel::{CancelToken, Cancelled};
+    use crate::sources::FromItems;
+    use crate::{TryNext, TryNextExt, from_items, from_iter};
+
+    #[test]
+    fn yields_every_item_and_attributes_errors() {
+        let mut merged = merge_unordered([
+            from_iter(vec![Ok(1), Err('a'), Ok(2)]),
+            from_iter(vec![]),
+            from_iter(vec![Err('c'), Ok(3)]),
+        ]);
+
+        let mut items = Vec::new();
+        let mut errors = Vec::new();
+        while let Some(result) = merged.try_next().transpose() {
+            match result {
+                Ok(item) => items.push(item),
+                Err(err) => errors.push(err),
+            }
+        }
+        items.sort();
+        errors.sort_by_key(|err| err.index);
+        assert_eq!(items, [1, 2, 3]);
+        assert_eq!(
+            errors,
+            [
+                MergeError {
+                    index: 0,
+                    error: 'a'
+                },
+                MergeError {
+                    index: 2,
+                    error: 'c'
+                },
+            ]
+        );
+        assert_eq!(merged.try_next(), Ok(None));
+    }
+
+    #[test]
+    fn merging_nothing_ends_immediately() {
+        let mut merged = merge_unordered(Vec::<FromItems<vec::IntoIter<u8>>>::new());
+        assert_eq!(merged.try_next(), Ok(None));
+    }
+
+    #[test]
+    #[should_panic(expected = "partition exploded")]
+    fn resumes_source_panics() {
+        let mut merged =
+            merge_unordered([from_items(vec![1]).inspect(|_| panic!("partition exploded"))]);
+        while merged.try_next().unwrap().is_some() {}
+    }
+
+    #[test]
+    fn cancelling_stops_every_source() {
+        let token = CancelToken::new();
+        let mut merged = merge_unordered_with_cancel(
+            [
+                from_iter((0..).map(Ok::<u64, Cancelled>)),
+                from_iter((0..).map(Ok)),
+            ],
+            token.clone(),
+        );
+        assert!(merged.try_next().unwrap().is_some());
+        token.cancel();
+
+        let mut cancelled = Vec::new();
+        while let Some(result) = merged.try_next().transpose() {
+            if let Err(err) = result {
+                assert_eq!(err.error, Cancelled);
+                cancelled.push(err.index);
+            }
+        }
+        cancelled.sort();
+        assert_eq!(cancelled, [0, 1]);
+    }
+}
@@ -0,0 +1,39 @@
+//! Built-in source types.
+//!
+//! Sources are usually created through the constructor functions at the
+//! crate root (such as [`from_iter`]) or through
+//! [`IntoTryNext`](crate::IntoTryNext).
+
+mod channel;
+#[cfg(feature = "nightly")]
+mod coroutine;
+mod generate;
+mod iter;
+mod literal;
+mod merge;
+#[cfg(feature = "rand")]
+mod random;
+mod read;
+mod read_ready;
+#[cfg(feature = "test-util")]
+mod scripted;
+mod slice;
+#[cfg(feature = "tokio")]
+mod tokio_channel;
+
+pub use channel::ChannelSource;
+#[cfg(feature = "nightly")]
+pub use coroutine::{FromCoroutine, from_coroutine};
+pub use generate::{Generated, Yielder, generate};
+pub use iter::{FromItems, FromIter, from_items, from_iter};
+pub use literal::LiteralSource;
+pub use merge::{MergeError, MergeUnordered, merge_unordered, merge_unordered_with_cancel};
+#[cfg(feature = "rand")]
+pub use random::{RandomError, RandomSource};
+pub use read::{ReadSlices, read_slices};
+pub use read_ready::{ReadReady, read_ready};
+#[cfg(feature = "test-util")]
+pub use scripted::ScriptedSource;
+pub use slice::{FromSlice, from_slice};
+#[cfg(feature = "tokio")]
+pub use tokio_channel::TokioChannelSource;
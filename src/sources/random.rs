@@ -0,0 +1,143 @@
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use rand::distr::{Distribution, StandardUniform};
+use rand::rngs::Xoshiro256PlusPlus;
+use rand::{RngExt, SeedableRng};
+
+use crate::TryNext;
+
+/// An endless source of seeded pseudo-random items, for benchmarks and
+/// stress tests.
+///
+/// Items are drawn from a [`Distribution`], [`StandardUniform`] by default,
+/// and each call fails with probability [`error_rate`](Self::error_rate),
+/// returning a [`RandomError`]. The generator is
+/// [`Xoshiro256PlusPlus`], so a seed yields the same sequence on every
+/// platform and run. Bound the sequence with
+/// [`take`](crate::TryNextExt::take).
+///
+/// Available with the `rand` feature.
+///
+/// ```rust
+/// use try_next::sources::RandomSource;
+/// use try_next::{TryNext, TryNextExt};
+///
+/// let mut a = RandomSource::<u32>::new(7).error_rate(0.1).take(100);
+/// let mut b = RandomSource::<u32>::new(7).error_rate(0.1).take(100);
+/// for _ in 0..100 {
+///     assert_eq!(a.try_next(), b.try_next());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RandomSource<T, D = StandardUniform> {
+    rng: Xoshiro256PlusPlus,
+    distribution: D,
+    error_rate: f64,
+    calls: usize,
+    _item: PhantomData<fn() -> T>,
+}
+
+impl<T> RandomSource<T> {
+    /// Creates a source of uniformly random items seeded with `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self::with_distribution(seed, StandardUniform)
+    }
+}
+
+impl<T, D> RandomSource<T, D> {
+    /// Creates a source drawing items from `distribution`, seeded with
+    /// `seed`.
+    pub fn with_distribution(seed: u64, distribution: D) -> Self {
+        Self {
+            rng: Xoshiro256PlusPlus::seed_from_u64(seed),
+            distribution,
+            error_rate: 0.0,
+            calls: 0,
+            _item: PhantomData,
+        }
+    }
+
+    /// Makes each call fail with probability `rate`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is outside `0.0..=1.0`.
+    pub fn error_rate(mut self, rate: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&rate),
+            "error rate {rate} is outside 0.0..=1.0"
+        );
+        self.error_rate = rate;
+        self
+    }
+
+    /// Returns the number of calls made so far.
+    pub fn calls(&self) -> usize {
+        self.calls
+    }
+}
+
+impl<T, D: Distribution<T>> TryNext for RandomSource<T, D> {
+    type Item = T;
+    type Error = RandomError;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let call = self.calls;
+        self.calls += 1;
+        if self.error_rate > 0.0 && self.rng.random_bool(self.error_rate) {
+            return Err(RandomError { call });
+        }
+        Ok(Some(self.distribution.sample(&mut self.rng)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// An error injected by a [`RandomSource`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomError {
+    /// The zero-based index of the failing call.
+    pub call: usize,
+}
+
+impl fmt::Display for RandomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "random error injected at call {}", self.call)
+    }
+}
+
+impl Error for RandomError {}
+
+#[cfg(test)]
+mod tests {
+    use rand::distr::Uniform;
+
+    use super::{RandomError, RandomSource};
+    use crate::TryNext;
+
+    #[test]
+    fn draws_from_the_given_distribution() {
+        let range = Uniform::new(10, 20).unwrap();
+        let mut src = RandomSource::with_distribution(1, range);
+
+        for _ in 0..100 {
+            let item = src.try_next().unwrap().unwrap();
+            assert!((10..20).contains(&item));
+        }
+    }
+
+    #[test]
+    fn injects_errors_at_the_configured_rate() {
+        let mut src = RandomSource::<u8>::new(3).error_rate(0.25);
+        let errors = (0..10_000).filter(|_| src.try_next().is_err()).count();
+        assert!((2_000..3_000).contains(&errors), "{errors} errors");
+
+        let mut all = RandomSource::<u8>::new(3).error_rate(1.0);
+        assert_eq!(all.try_next(), Err(RandomError { call: 0 }));
+        assert_eq!(all.try_next(), Err(RandomError { call: 1 }));
+    }
+}
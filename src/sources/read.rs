@@ -0,0 +1,183 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use crate::{ByteOffset, Owned, TryNextLending, TrySeek};
+
+/// A lending source that reads byte chunks into a reusable buffer.
+///
+/// Each call performs one successful [`Read::read`] into an internal buffer
+/// and lends the filled part as a `&[u8]`, so scanning a stream allocates
+/// nothing per chunk. Chunks are at most the buffer's capacity long; their
+/// exact length depends on the reader. Reads interrupted with
+/// [`ErrorKind::Interrupted`](io::ErrorKind::Interrupted) are retried.
+///
+/// Created by [`read_slices`].
+#[derive(Debug)]
+pub struct ReadSlices<R> {
+    reader: R,
+    buf: Box<[u8]>,
+    offset: u64,
+}
+
+/// Creates a lending source reading `reader` in chunks of up to `capacity`
+/// bytes.
+///
+/// ```rust
+/// use try_next::TryNextLending;
+/// use try_next::sources::read_slices;
+///
+/// let mut src = read_slices(&b"hello world"[..], 4);
+/// let mut total = 0;
+/// while let Some(chunk) = src.try_next().unwrap() {
+///     total += chunk.len();
+/// }
+/// assert_eq!(total, 11);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn read_slices<R: Read>(reader: R, capacity: usize) -> ReadSlices<R> {
+    assert!(capacity > 0, "buffer capacity must be non-zero");
+    ReadSlices {
+        reader,
+        buf: vec![0; capacity].into_boxed_slice(),
+        offset: 0,
+    }
+}
+
+impl<R> ReadSlices<R> {
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> TryNextLending for ReadSlices<R> {
+    type Item<'a>
+        = &'a [u8]
+    where
+        Self: 'a;
+    type Error = io::Error;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item<'_>>, Self::Error> {
+        let n = loop {
+            match self.reader.read(&mut self.buf) {
+                Ok(n) => break n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        };
+        self.offset += n as u64;
+        Ok((n > 0).then(|| &self.buf[..n]))
+    }
+}
+
+/// Reports the number of bytes read so far.
+impl<R> ByteOffset for ReadSlices<R> {
+    fn byte_offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+/// Seeks the underlying reader, using the [byte offset](ByteOffset) from where
+/// reading started as the position.
+///
+/// Positions are byte offsets rather than chunk indices: the chunk read after
+/// a seek starts at that byte, whatever chunk boundaries the reader produced
+/// before.
+impl<R: Read + Seek + 'static> TrySeek for Owned<ReadSlices<R>> {
+    type Position = u64;
+
+    fn try_position(&mut self) -> Result<Self::Position, Self::Error> {
+        Ok(self.get_ref().offset)
+    }
+
+    fn try_seek(&mut self, position: Self::Position) -> Result<(), Self::Error> {
+        let src = self.get_mut();
+        let delta = position as i64 - src.offset as i64;
+        src.reader.seek(SeekFrom::Current(delta))?;
+        src.offset = position;
+        Ok(())
+    }
+
+    fn try_rewind(&mut self) -> Result<(), Self::Error> {
+        self.try_seek(0)
+    }
+}
+
+/// Saves the [byte offset](ByteOffset) and restores it by seeking, as with
+/// [`TrySeek`].
+#[cfg(feature = "serde")]
+impl<R: Read + Seek + 'static> crate::Checkpoint for Owned<ReadSlices<R>> {
+    type State = u64;
+
+    fn save_state(&self) -> Self::State {
+        self.get_ref().offset
+    }
+
+    fn restore_state(&mut self, state: Self::State) -> Result<(), Self::Error> {
+        self.try_seek(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read, Seek};
+
+    use super::read_slices;
+    use crate::{ByteOffset, TryNext, TryNextLending, TrySeek};
+
+    #[test]
+    fn lends_chunks_and_tracks_offset() {
+        let mut src = read_slices(&b"abcde"[..], 2);
+
+        assert_eq!(src.try_next().unwrap(), Some(&b"ab"[..]));
+        assert_eq!(src.try_next().unwrap(), Some(&b"cd"[..]));
+        assert_eq!(src.byte_offset(), 4);
+        assert_eq!(src.try_next().unwrap(), Some(&b"e"[..]));
+        assert_eq!(src.try_next().unwrap(), None);
+        assert_eq!(src.byte_offset(), 5);
+    }
+
+    #[test]
+    fn converts_to_owned_chunks() {
+        let mut src = read_slices(Cursor::new(vec![1, 2, 3]), 8).owned();
+
+        assert_eq!(TryNext::try_next(&mut src).unwrap(), Some(vec![1, 2, 3]));
+        assert_eq!(TryNext::try_next(&mut src).unwrap(), None);
+    }
+
+    #[test]
+    fn seeks_by_byte_offset_from_the_start_of_reading() {
+        let mut cursor = Cursor::new(b"skip:abcdef".to_vec());
+        cursor.read_exact(&mut [0; 5]).unwrap();
+        let mut src = read_slices(cursor, 4).owned();
+
+        assert_eq!(TryNext::try_next(&mut src).unwrap(), Some(b"abcd".to_vec()));
+        assert_eq!(src.try_position().unwrap(), 4);
+        src.try_seek(2).unwrap();
+        assert_eq!(TryNext::try_next(&mut src).unwrap(), Some(b"cdef".to_vec()));
+        src.try_rewind().unwrap();
+        assert_eq!(TryNext::try_next(&mut src).unwrap(), Some(b"abcd".to_vec()));
+        assert_eq!(src.into_inner().into_inner().stream_position().unwrap(), 9);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn restores_a_saved_offset_into_a_fresh_reader() {
+        use crate::Checkpoint;
+
+        let data = b"0123456789".to_vec();
+        let mut first = read_slices(Cursor::new(data.clone()), 3).owned();
+        TryNext::try_next(&mut first).unwrap();
+        let state = first.save_state();
+
+        let mut resumed = read_slices(Cursor::new(data), 3).owned();
+        resumed.restore_state(state).unwrap();
+        assert_eq!(
+            TryNext::try_next(&mut resumed).unwrap(),
+            Some(b"345".to_vec())
+        );
+        assert_eq!(resumed.into_inner().byte_offset(), 6);
+    }
+}
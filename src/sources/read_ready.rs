@@ -0,0 +1,138 @@
+use std::io::{self, Read};
+
+use crate::{Readiness, TryNextReady};
+
+/// A non-blocking source of byte chunks read from a reader.
+///
+/// Meant for readers in non-blocking mode, such as a
+/// [`TcpStream`](std::net::TcpStream) after
+/// [`set_nonblocking(true)`](std::net::TcpStream::set_nonblocking). A read
+/// failing with [`ErrorKind::WouldBlock`](io::ErrorKind::WouldBlock) is
+/// reported as [`Readiness::Pending`], a read of zero bytes as the end of
+/// input, and interrupted reads are retried.
+///
+/// Created by [`read_ready`].
+#[derive(Debug)]
+pub struct ReadReady<R> {
+    reader: R,
+    capacity: usize,
+}
+
+/// Creates a non-blocking source reading `reader` in chunks of up to
+/// `capacity` bytes.
+///
+/// ```rust
+/// use try_next::sources::read_ready;
+/// use try_next::{Readiness, TryNextReady};
+///
+/// let mut src = read_ready(&b"hello"[..], 4);
+/// assert_eq!(src.try_next_ready().unwrap(), Readiness::Ready(Some(b"hell".to_vec())));
+/// assert_eq!(src.try_next_ready().unwrap(), Readiness::Ready(Some(b"o".to_vec())));
+/// assert_eq!(src.try_next_ready().unwrap(), Readiness::Ready(None));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `capacity` is zero.
+pub fn read_ready<R: Read>(reader: R, capacity: usize) -> ReadReady<R> {
+    assert!(capacity > 0, "buffer capacity must be non-zero");
+    ReadReady { reader, capacity }
+}
+
+impl<R> ReadReady<R> {
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> TryNextReady for ReadReady<R> {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn try_next_ready(&mut self) -> Result<Readiness<Self::Item>, Self::Error> {
+        let mut buf = vec![0; self.capacity];
+        loop {
+            match self.reader.read(&mut buf) {
+                Ok(0) => return Ok(Readiness::Ready(None)),
+                Ok(n) => {
+                    buf.truncate(n);
+                    return Ok(Readiness::Ready(Some(buf)));
+                }
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {
+                    return Ok(Readiness::Pending);
+                }
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+
+    use super::read_ready;
+    use crate::{Readiness, TryNextReady};
+
+    /// Fails each read with the next queued error kind, then reads `data`.
+    struct Flaky {
+        errors: Vec<io::ErrorKind>,
+        data: &'static [u8],
+    }
+
+    impl Read for Flaky {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if !self.errors.is_empty() {
+                return Err(self.errors.remove(0).into());
+            }
+            self.data.read(buf)
+        }
+    }
+
+    #[test]
+    fn would_block_is_pending_and_interrupts_are_retried() {
+        let reader = Flaky {
+            errors: vec![io::ErrorKind::WouldBlock, io::ErrorKind::Interrupted],
+            data: b"ok",
+        };
+        let mut src = read_ready(reader, 8);
+
+        assert_eq!(src.try_next_ready().unwrap(), Readiness::Pending);
+        assert_eq!(
+            src.try_next_ready().unwrap(),
+            Readiness::Ready(Some(b"ok".to_vec()))
+        );
+        assert_eq!(src.try_next_ready().unwrap(), Readiness::Ready(None));
+    }
+
+    #[test]
+    fn polls_a_nonblocking_socket() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        server.set_nonblocking(true).unwrap();
+        let mut src = read_ready(server, 16);
+
+        assert!(src.try_next_ready().unwrap().is_pending());
+        client.write_all(b"ping").unwrap();
+        drop(client);
+
+        let mut received = Vec::new();
+        loop {
+            match src.try_next_ready().unwrap() {
+                Readiness::Ready(Some(chunk)) => received.extend(chunk),
+                Readiness::Ready(None) => break,
+                Readiness::Pending => std::thread::yield_now(),
+            }
+        }
+        assert_eq!(received, b"ping");
+    }
+}
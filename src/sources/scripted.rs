@@ -0,0 +1,257 @@
+use std::collections::VecDeque;
+
+use crate::TryNext;
+
+/// A source that replays a fixed script of results, for testing consumers.
+///
+/// Each call returns the next step verbatim, so a test can place errors and
+/// `Ok(None)` anywhere in the sequence. What happens once the script runs
+/// out is configurable: by default the source panics, flagging a consumer
+/// that pulls more than expected; [`repeat_last`](Self::repeat_last) makes
+/// it return the final step forever instead.
+///
+/// Available with the `test-util` feature.
+///
+/// ```rust
+/// use try_next::TryNext;
+/// use try_next::sources::ScriptedSource;
+///
+/// let mut src = ScriptedSource::new([Ok(Some(1)), Err("timeout"), Ok(Some(2)), Ok(None)]);
+/// assert_eq!(src.try_next(), Ok(Some(1)));
+/// assert_eq!(src.try_next(), Err("timeout"));
+/// assert_eq!(src.try_next(), Ok(Some(2)));
+/// assert_eq!(src.try_next(), Ok(None));
+/// assert!(src.is_finished());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScriptedSource<T, E> {
+    steps: VecDeque<Result<Option<T>, E>>,
+    last: Option<Result<Option<T>, E>>,
+    repeat: bool,
+    calls: usize,
+}
+
+impl<T, E> ScriptedSource<T, E> {
+    /// Creates a source replaying `steps` in order, then panicking.
+    pub fn new(steps: impl IntoIterator<Item = Result<Option<T>, E>>) -> Self {
+        Self {
+            steps: steps.into_iter().collect(),
+            last: None,
+            repeat: false,
+            calls: 0,
+        }
+    }
+
+    /// Makes the source return its final step forever instead of panicking
+    /// once the script runs out.
+    ///
+    /// # Panics
+    ///
+    /// [`try_next`](TryNext::try_next) still panics if the script was empty.
+    pub fn repeat_last(mut self) -> Self {
+        self.repeat = true;
+        self
+    }
+
+    /// Returns the number of calls made so far.
+    pub fn calls(&self) -> usize {
+        self.calls
+    }
+
+    /// Returns `true` once every step has been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl<T: Clone, E: Clone> TryNext for ScriptedSource<T, E> {
+    type Item = T;
+    type Error = E;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        self.calls += 1;
+        if let Some(step) = self.steps.pop_front() {
+            if self.repeat {
+                self.last = Some(step.clone());
+            }
+            return step;
+        }
+        match &self.last {
+            Some(step) if self.repeat => step.clone(),
+            _ => panic!(
+                "scripted source polled {} times, but the script has only {} steps",
+                self.calls,
+                self.calls - 1
+            ),
+        }
+    }
+}
+
+/// Assembles a generated script: `head`, then `Ok(None)`, then optionally a
+/// resumed `tail` ending in `Ok(None)` again.
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+fn generated<T, E>(
+    head: Vec<Result<T, E>>,
+    tail: Option<Vec<Result<T, E>>>,
+    repeat: bool,
+) -> ScriptedSource<T, E> {
+    let mut steps: Vec<_> = head.into_iter().map(|step| step.map(Some)).collect();
+    steps.push(Ok(None));
+    if let Some(tail) = tail {
+        steps.extend(tail.into_iter().map(|step| step.map(Some)));
+        steps.push(Ok(None));
+    }
+    let source = ScriptedSource::new(steps);
+    if repeat { source.repeat_last() } else { source }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use std::fmt::Debug;
+
+    use proptest::prelude::*;
+
+    use super::{ScriptedSource, generated};
+
+    impl<T, E> ScriptedSource<T, E>
+    where
+        T: Clone + Debug + 'static,
+        E: Clone + Debug + 'static,
+    {
+        /// Returns a strategy generating scripts from `item` and `error`.
+        ///
+        /// Scripts hold up to 16 steps, about one in five of them errors,
+        /// and end with `Ok(None)`. Some resume with more steps after that
+        /// `Ok(None)`, and some repeat their last step instead of panicking
+        /// once exhausted, so adapters meet every post-termination behavior
+        /// the trait allows.
+        ///
+        /// Available with the `proptest` feature.
+        pub fn strategy(
+            item: impl Strategy<Value = T> + 'static,
+            error: impl Strategy<Value = E> + 'static,
+        ) -> BoxedStrategy<Self> {
+            let step = prop_oneof![4 => item.prop_map(Ok), 1 => error.prop_map(Err)].boxed();
+            let steps = proptest::collection::vec(step, 0..=16);
+            (steps.clone(), proptest::option::of(steps), any::<bool>())
+                .prop_map(|(head, tail, repeat)| generated(head, tail, repeat))
+                .boxed()
+        }
+    }
+
+    /// Generates scripts with [`ScriptedSource::strategy`].
+    impl<T, E> Arbitrary for ScriptedSource<T, E>
+    where
+        T: Arbitrary + Clone + 'static,
+        E: Arbitrary + Clone + 'static,
+    {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with((): ()) -> Self::Strategy {
+            Self::strategy(any::<T>(), any::<E>())
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl {
+    use quickcheck::{Arbitrary, Gen};
+
+    use super::{ScriptedSource, generated};
+
+    /// Generates scripts shaped like those of `ScriptedSource::strategy`
+    /// under the `proptest` feature, sized by the generator.
+    ///
+    /// Shrinking drops and shrinks steps while keeping the repeat setting.
+    impl<T, E> Arbitrary for ScriptedSource<T, E>
+    where
+        T: Arbitrary,
+        E: Arbitrary,
+    {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let tail = bool::arbitrary(g).then(|| Vec::arbitrary(g));
+            generated(Vec::arbitrary(g), tail, bool::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let steps: Vec<_> = self.steps.iter().cloned().collect();
+            let repeat = self.repeat;
+            Box::new(steps.shrink().map(move |steps| {
+                let source = ScriptedSource::new(steps);
+                if repeat { source.repeat_last() } else { source }
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScriptedSource;
+    use crate::TryNext;
+
+    #[test]
+    fn repeats_the_last_step() {
+        let mut src = ScriptedSource::new([Ok(Some(1)), Err('x')]).repeat_last();
+
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.try_next(), Err('x'));
+        assert_eq!(src.calls(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "polled 2 times, but the script has only 1 steps")]
+    fn panics_when_script_runs_out() {
+        let mut src = ScriptedSource::<u8, ()>::new([Ok(None)]);
+
+        assert_eq!(src.try_next(), Ok(None));
+        let _ = src.try_next();
+    }
+}
+
+#[cfg(all(test, feature = "proptest"))]
+mod proptests {
+    use proptest::prelude::*;
+
+    use super::ScriptedSource;
+    use crate::{TryNext, TryNextExt};
+
+    proptest! {
+        #[test]
+        fn fuse_never_pulls_after_none(src in any::<ScriptedSource<u8, char>>()) {
+            let mut fused = src.fuse();
+            let mut pulls = 1;
+            while fused.try_next() != Ok(None) {
+                pulls += 1;
+            }
+            for _ in 0..3 {
+                prop_assert_eq!(fused.try_next(), Ok(None));
+            }
+            prop_assert_eq!(fused.into_inner().calls(), pulls);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "quickcheck"))]
+mod quickchecks {
+    use quickcheck::quickcheck;
+
+    use super::ScriptedSource;
+    use crate::{TryNext, TryNextExt};
+
+    quickcheck! {
+        fn take_yields_at_most_its_limit(src: ScriptedSource<u8, char>, n: u8) -> bool {
+            let n = usize::from(n % 8);
+            let mut taken = src.take(n);
+            let mut items = 0;
+            loop {
+                match taken.try_next() {
+                    Ok(Some(_)) => items += 1,
+                    Ok(None) => return items <= n,
+                    Err(_) => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::convert::Infallible;
+use std::num::NonZeroUsize;
+
+use crate::{ExactSizeTryNext, FusedTryNext, TryNext, TrySeek};
+
+/// A rewindable source over the items of a slice.
+///
+/// Created by [`from_slice`].
+#[derive(Debug, Clone)]
+pub struct FromSlice<'a, T> {
+    items: &'a [T],
+    pos: usize,
+}
+
+/// Creates an infallible source lending the items of `items` by reference.
+///
+/// Unlike [`from_items`](super::from_items), the source keeps the whole
+/// slice, so it implements [`TrySeek`] with the item index as its position,
+/// for multi-pass algorithms over in-memory data. Seeking past the end is
+/// allowed and leaves the source exhausted.
+///
+/// ```rust
+/// use try_next::sources::from_slice;
+/// use try_next::{TryNext, TrySeek};
+///
+/// let rows = vec!["id,name", "1,ada", "2,grace"];
+/// let mut src = from_slice(&rows);
+///
+/// let columns = src.try_next().unwrap().unwrap().split(',').count();
+/// src.try_rewind().unwrap();
+/// src.try_seek(1).unwrap();
+/// assert_eq!(src.try_next(), Ok(Some(&"1,ada")));
+/// assert_eq!(columns, 2);
+/// ```
+pub fn from_slice<T>(items: &[T]) -> FromSlice<'_, T> {
+    FromSlice { items, pos: 0 }
+}
+
+impl<'a, T> FromSlice<'a, T> {
+    /// Returns the items not yet yielded.
+    pub fn as_slice(&self) -> &'a [T] {
+        &self.items[self.pos.min(self.items.len())..]
+    }
+}
+
+impl<'a, T> TryNext for FromSlice<'a, T> {
+    type Item = &'a T;
+    type Error = Infallible;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        let item = self.items.get(self.pos);
+        self.pos += item.is_some() as usize;
+        Ok(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.as_slice().len();
+        (left, Some(left))
+    }
+
+    fn try_advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        let left = self.as_slice().len();
+        self.pos += n.min(left);
+        Ok(NonZeroUsize::new(n.saturating_sub(left)).map_or(Ok(()), Err))
+    }
+}
+
+impl<T> ExactSizeTryNext for FromSlice<'_, T> {}
+
+impl<T> FusedTryNext for FromSlice<'_, T> {}
+
+impl<T> TrySeek for FromSlice<'_, T> {
+    type Position = usize;
+
+    fn try_position(&mut self) -> Result<Self::Position, Self::Error> {
+        Ok(self.pos)
+    }
+
+    fn try_seek(&mut self, position: Self::Position) -> Result<(), Self::Error> {
+        self.pos = position;
+        Ok(())
+    }
+
+    fn try_rewind(&mut self) -> Result<(), Self::Error> {
+        self.try_seek(0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> crate::Checkpoint for FromSlice<'_, T> {
+    type State = usize;
+
+    fn save_state(&self) -> Self::State {
+        self.pos
+    }
+
+    fn restore_state(&mut self, state: Self::State) -> Result<(), Self::Error> {
+        self.try_seek(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::from_slice;
+    use crate::{TryNext, TrySeek};
+
+    #[test]
+    fn seeking_past_the_end_exhausts_the_source() {
+        let mut src = from_slice(&[1, 2, 3]);
+
+        src.try_seek(7).unwrap();
+        assert_eq!(src.size_hint(), (0, Some(0)));
+        assert_eq!(src.try_next(), Ok(None));
+        assert_eq!(src.try_position(), Ok(7));
+
+        src.try_seek(2).unwrap();
+        assert_eq!(src.try_next(), Ok(Some(&3)));
+    }
+
+    #[test]
+    fn advances_by_moving_the_index() {
+        let mut src = from_slice(&[1, 2, 3, 4]);
+
+        assert_eq!(src.try_advance_by(2), Ok(Ok(())));
+        assert_eq!(src.try_next(), Ok(Some(&3)));
+        assert_eq!(
+            src.try_advance_by(3),
+            Ok(Err(NonZeroUsize::new(2).unwrap()))
+        );
+        assert_eq!(src.try_position(), Ok(4));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn restores_a_saved_index() {
+        use crate::Checkpoint;
+
+        let mut src = from_slice(&['a', 'b', 'c']);
+        src.try_next().unwrap();
+        let state = src.save_state();
+        src.try_next().unwrap();
+
+        src.restore_state(state).unwrap();
+        assert_eq!(src.try_next(), Ok(Some(&'b')));
+    }
+}
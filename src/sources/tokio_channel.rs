@@ -0,0 +1,169 @@
+use std::convert::Infallible;
+
+use tokio::sync::mpsc::Receiver;
+use tokio::sync::mpsc::error::TryRecvError;
+
+use crate::{FusedTryNext, Readiness, TryNext, TryNextReady};
+
+/// A source that receives items from a Tokio [`mpsc`](tokio::sync::mpsc)
+/// channel.
+///
+/// Each call to [`try_next`](TryNext::try_next) blocks the current thread
+/// with [`Receiver::blocking_recv`] until an item arrives, so async tasks can
+/// feed a dedicated synchronous worker thread. Once every sender has been
+/// dropped and the channel is drained, the source returns `Ok(None)`.
+///
+/// As a [`TryNextReady`], the source never waits: an empty channel is
+/// [`Readiness::Pending`].
+///
+/// ```rust
+/// use std::thread;
+/// use try_next::TryNextExt;
+/// use try_next::sources::TokioChannelSource;
+///
+/// let (tx, rx) = tokio::sync::mpsc::channel(8);
+/// let worker = thread::spawn(move || TokioChannelSource::new(rx).try_collect::<Vec<u32>>());
+///
+/// let rt = tokio::runtime::Builder::new_current_thread().build()?;
+/// rt.block_on(async move {
+///     for i in 0..3 {
+///         tx.send(i).await.unwrap();
+///     }
+/// });
+/// assert_eq!(worker.join().unwrap(), Ok(vec![0, 1, 2]));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Panics
+///
+/// [`try_next`](TryNext::try_next) and
+/// [`try_next_chunk`](TryNext::try_next_chunk) panic if called from within
+/// an async execution context, like [`Receiver::blocking_recv`].
+///
+/// Available with the `tokio` feature.
+///
+/// Created by converting a Tokio [`Receiver`] with
+/// [`IntoTryNext`](crate::IntoTryNext) or [`TokioChannelSource::new`].
+#[derive(Debug)]
+pub struct TokioChannelSource<T> {
+    receiver: Receiver<T>,
+}
+
+impl<T> TokioChannelSource<T> {
+    /// Creates a source receiving from `receiver`.
+    pub fn new(receiver: Receiver<T>) -> Self {
+        Self { receiver }
+    }
+
+    /// Returns the underlying receiver.
+    pub fn into_inner(self) -> Receiver<T> {
+        self.receiver
+    }
+}
+
+impl<T> TryNext for TokioChannelSource<T> {
+    type Item = T;
+    type Error = Infallible;
+
+    fn try_next(&mut self) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(self.receiver.blocking_recv())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.receiver.len(), None)
+    }
+
+    /// Blocks for the first item only, then takes whatever else is already
+    /// queued, up to `max` items in total. The chunk is therefore often
+    /// short while senders are alive; only an empty chunk means the channel
+    /// is closed and drained.
+    fn try_next_chunk(&mut self, max: usize) -> Result<Vec<Self::Item>, Self::Error> {
+        let mut chunk = Vec::new();
+        if max == 0 {
+            return Ok(chunk);
+        }
+        let Some(first) = self.receiver.blocking_recv() else {
+            return Ok(chunk);
+        };
+        chunk.push(first);
+        while chunk.len() < max {
+            match self.receiver.try_recv() {
+                Ok(item) => chunk.push(item),
+                Err(_) => break,
+            }
+        }
+        Ok(chunk)
+    }
+}
+
+impl<T> TryNextReady for TokioChannelSource<T> {
+    type Item = T;
+    type Error = Infallible;
+
+    fn try_next_ready(&mut self) -> Result<Readiness<Self::Item>, Self::Error> {
+        match self.receiver.try_recv() {
+            Ok(item) => Ok(Readiness::Ready(Some(item))),
+            Err(TryRecvError::Empty) => Ok(Readiness::Pending),
+            Err(TryRecvError::Disconnected) => Ok(Readiness::Ready(None)),
+        }
+    }
+}
+
+impl<T> FusedTryNext for TokioChannelSource<T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use tokio::sync::mpsc;
+
+    use super::TokioChannelSource;
+    use crate::{Readiness, TryNext, TryNextExt, TryNextReady};
+
+    #[test]
+    fn ends_when_senders_are_dropped() {
+        let (tx, rx) = mpsc::channel(1);
+        let producer = thread::spawn(move || {
+            for i in 0..3 {
+                tx.blocking_send(i).unwrap();
+            }
+        });
+
+        let mut src = TokioChannelSource::new(rx);
+        assert_eq!(src.try_next(), Ok(Some(0)));
+        assert_eq!(src.try_next(), Ok(Some(1)));
+        assert_eq!(src.try_next(), Ok(Some(2)));
+        assert_eq!(src.try_next(), Ok(None));
+        producer.join().unwrap();
+    }
+
+    #[test]
+    fn chunk_drains_queued_items_without_blocking() {
+        let (tx, rx) = mpsc::channel(8);
+        for i in 0..5 {
+            tx.try_send(i).unwrap();
+        }
+
+        let mut src = TokioChannelSource::new(rx);
+        assert_eq!(src.size_hint(), (5, None));
+        assert_eq!(src.try_next_chunk(3), Ok(vec![0, 1, 2]));
+        assert_eq!(src.try_next_chunk(3), Ok(vec![3, 4]));
+        assert_eq!(src.try_next_ready(), Ok(Readiness::Pending));
+
+        drop(tx);
+        assert_eq!(src.try_next_chunk(3), Ok(vec![]));
+        assert_eq!(src.try_next_ready(), Ok(Readiness::Ready(None)));
+    }
+
+    #[test]
+    fn short_chunks_do_not_end_a_fused_source() {
+        let (tx, rx) = mpsc::channel(8);
+        let mut src = TokioChannelSource::new(rx).fuse();
+
+        tx.try_send(1).unwrap();
+        assert_eq!(src.try_next_chunk(3), Ok(vec![1]));
+        tx.try_send(2).unwrap();
+        drop(tx);
+        assert_eq!(src.try_collect::<Vec<_>>(), Ok(vec![2]));
+    }
+}